@@ -1,19 +1,19 @@
 // Specified in Figure 6.2 on page 107
 
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Assembly {
     A(AInstruction),
     C(CInstruction),
     Label(String),
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AInstruction {
     Constant(u16),
     Symbol(String),
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CInstruction {
     pub comp: Comp,
     pub o_dest: Option<Dest>,
@@ -21,7 +21,7 @@ pub struct CInstruction {
 }
 
 #[repr(u8)]
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Comp {
     // a == 0
     Zero,
@@ -57,7 +57,7 @@ pub enum Comp {
 }
 
 #[repr(u8)]
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Dest {
     M,
     D,
@@ -67,10 +67,11 @@ pub enum Dest {
     AM,
     AD,
     ADM,
+    AMD,
 }
 
 #[repr(u8)]
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Jump {
     JGT,
     JEQ,
@@ -1,30 +1,37 @@
+use crate::ast::span::Span;
+
 // Program Structure (Figure 10.5):
 //      A Jack program is a a collection of classes, each appearing in a separate file.
 //      The compilation unit is a class. A class is a sequence of tokens, as follows:
+#[derive(Debug, Clone)]
 pub struct Class {
     pub class_name: String,
     pub class_dec: ClassDec,
+    pub span: Span,
 }
 
+#[derive(Debug, Clone)]
 pub struct ClassDec {
     pub class_var_dec: Vec<ClassVarDec>,
     pub subroutine_dec: Vec<SubroutineDec>,
 }
 
+#[derive(Debug, Clone)]
 pub struct ClassVarDec {
     pub kind: Kind,
     pub r#type: Type,
     pub vars: Vec<String>,
+    pub span: Span,
 }
 
 #[repr(u8)]
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub enum Kind {
     Static,
     Field,
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Type {
     Int,
     Char,
@@ -32,47 +39,59 @@ pub enum Type {
     ClassName(String),
 }
 
+#[derive(Debug, Clone)]
 pub struct SubroutineDec {
     pub subroutine_type: SubroutineType,
     pub subroutine_return_type: SubroutineReturnType,
     pub subroutine_name: String,
     pub parameter_list: Vec<Parameter>,
     pub subroutine_body: SubroutineBody,
+    pub span: Span,
 }
 
 #[repr(u8)]
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub enum SubroutineType {
     Constructor,
     Function,
     Method,
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub enum SubroutineReturnType {
     Void,
     Type(Type),
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct Parameter {
     pub r#type: Type,
     pub var_name: String,
+    pub span: Span,
 }
 
+#[derive(Debug, Clone)]
 pub struct SubroutineBody {
     pub var_decs: Vec<VarDec>,
     pub stmts: Vec<Statement>,
 }
 
+#[derive(Debug, Clone)]
 pub struct VarDec {
     pub r#type: Type,
     pub var_name: Vec<String>,
+    pub span: Span,
 }
 
 // Statements:
-#[derive(Clone)]
-pub enum Statement {
+#[derive(Debug, Clone)]
+pub struct Statement {
+    pub kind: StatementKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub enum StatementKind {
     Let(String, Option<Expression>, Expression),
     If(Expression, Vec<Statement>, Option<Vec<Statement>>),
     While(Expression, Vec<Statement>),
@@ -81,14 +100,21 @@ pub enum Statement {
 }
 
 // Expressions:
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct Expression {
     pub term: Box<Term>,
     pub bin: Vec<(BinaryOp, Box<Term>)>,
+    pub span: Span,
 }
 
-#[derive(Clone)]
-pub enum Term {
+#[derive(Debug, Clone)]
+pub struct Term {
+    pub kind: TermKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub enum TermKind {
     IntegerConstant(i16),
     StringConstant(String),
     KeywordConstant(KeywordConstant),
@@ -98,14 +124,14 @@ pub enum Term {
     SubroutineCall(SubroutineCall),
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub enum SubroutineCall {
-    Call(String, Vec<Box<Expression>>),
-    ClassCall(String, String, Vec<Box<Expression>>),
+    Call(String, Vec<Expression>),
+    ClassCall(String, String, Vec<Expression>),
 }
 
 #[repr(u8)]
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub enum BinaryOp {
     Plus,
     Minus,
@@ -119,17 +145,107 @@ pub enum BinaryOp {
 }
 
 #[repr(u8)]
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub enum UnaryOp {
     Negation,
     Tilde,
 }
 
 #[repr(u8)]
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub enum KeywordConstant {
     True,
     False,
     Null,
     This,
 }
+
+// Constructors for building an AST outside the parser. The nodes they create
+//  have no source location.
+impl Class {
+    pub fn new(class_name: &str, class_dec: ClassDec) -> Self {
+        Class {
+            class_name: class_name.to_string(),
+            class_dec,
+            span: Span::default(),
+        }
+    }
+}
+
+impl ClassVarDec {
+    pub fn new(kind: Kind, r#type: Type, vars: Vec<String>) -> Self {
+        ClassVarDec {
+            kind,
+            r#type,
+            vars,
+            span: Span::default(),
+        }
+    }
+}
+
+impl SubroutineDec {
+    pub fn new(
+        subroutine_type: SubroutineType,
+        subroutine_return_type: SubroutineReturnType,
+        subroutine_name: &str,
+        parameter_list: Vec<Parameter>,
+        subroutine_body: SubroutineBody,
+    ) -> Self {
+        SubroutineDec {
+            subroutine_type,
+            subroutine_return_type,
+            subroutine_name: subroutine_name.to_string(),
+            parameter_list,
+            subroutine_body,
+            span: Span::default(),
+        }
+    }
+}
+
+impl Parameter {
+    pub fn new(r#type: Type, var_name: &str) -> Self {
+        Parameter {
+            r#type,
+            var_name: var_name.to_string(),
+            span: Span::default(),
+        }
+    }
+}
+
+impl VarDec {
+    pub fn new(r#type: Type, var_name: Vec<String>) -> Self {
+        VarDec {
+            r#type,
+            var_name,
+            span: Span::default(),
+        }
+    }
+}
+
+impl Statement {
+    pub fn new(kind: StatementKind) -> Self {
+        Statement {
+            kind,
+            span: Span::default(),
+        }
+    }
+}
+
+impl Expression {
+    pub fn new(term: Term, bin: Vec<(BinaryOp, Term)>) -> Self {
+        Expression {
+            term: Box::new(term),
+            bin: bin.into_iter().map(|(op, t)| (op, Box::new(t))).collect(),
+            span: Span::default(),
+        }
+    }
+}
+
+impl Term {
+    pub fn new(kind: TermKind) -> Self {
+        Term {
+            kind,
+            span: Span::default(),
+        }
+    }
+}
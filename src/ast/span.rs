@@ -0,0 +1,92 @@
+use chumsky::Stream;
+
+use std::ops::Range;
+
+// Source locations attached to tokens and AST nodes.
+//  `offset` counts characters from the start of the file,
+//  `line` and `column` are 1-based.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Position {
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub start: Position,
+    pub end: Position,
+}
+
+pub type Spanned<T> = (T, Span);
+
+const START: Position = Position {
+    offset: 0,
+    line: 1,
+    column: 1,
+};
+
+impl chumsky::Span for Span {
+    type Context = ();
+    type Offset = Position;
+
+    fn new(_: (), range: Range<Position>) -> Self {
+        Span {
+            start: range.start,
+            end: range.end,
+        }
+    }
+
+    fn context(&self) {}
+
+    fn start(&self) -> Position {
+        self.start
+    }
+
+    fn end(&self) -> Position {
+        self.end
+    }
+}
+
+// Pair every character of `source` with its span so that the lexer
+//  reports line and column information instead of raw offsets.
+pub fn char_stream(source: &str) -> Stream<'static, char, Span, std::vec::IntoIter<(char, Span)>> {
+    let mut chars = Vec::new();
+    let mut position = START;
+    for c in source.chars() {
+        let start = position;
+        position.offset += 1;
+        if c == '\n' {
+            position.line += 1;
+            position.column = 1;
+        } else {
+            position.column += 1;
+        }
+        chars.push((
+            c,
+            Span {
+                start,
+                end: position,
+            },
+        ));
+    }
+    let eoi = Span {
+        start: position,
+        end: position,
+    };
+    Stream::from_iter(eoi, chars.into_iter())
+}
+
+// The span just past the last token, used as end-of-input by the parser
+pub fn end_of_input<T>(tokens: &[Spanned<T>]) -> Span {
+    tokens
+        .last()
+        .map(|(_, span)| Span {
+            start: span.end,
+            end: span.end,
+        })
+        .unwrap_or(Span {
+            start: START,
+            end: START,
+        })
+}
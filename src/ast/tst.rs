@@ -0,0 +1,41 @@
+// Test scripts (Appendix 3): the `.tst` files that drive the CPU and VM
+//  emulators and record the state of chosen variables in an `.out` file.
+#[derive(Debug, Clone)]
+pub enum ScriptCommand {
+    Load(Option<String>),
+    OutputFile(String),
+    CompareTo(String),
+    OutputList(Vec<OutputColumn>),
+    Output,
+    Set(Variable, i16),
+    Repeat(Option<u32>, Vec<ScriptCommand>),
+    TickTock,
+    VmStep,
+    Echo(String),
+}
+
+// A column of an output list, e.g. `RAM[0]%D1.6.1`
+#[derive(Debug, Clone)]
+pub struct OutputColumn {
+    pub variable: Variable,
+    pub format: Format,
+    pub left: usize,
+    pub width: usize,
+    pub right: usize,
+}
+
+#[derive(Debug, Clone)]
+pub enum Format {
+    Decimal,
+    Hexadecimal,
+    Binary,
+}
+
+// `RAM[256]`, a register such as `sp` or `PC`, or an indexed segment
+//  such as `argument[0]`
+#[derive(Debug, Clone)]
+pub enum Variable {
+    Ram(u16),
+    Register(String),
+    Segment(String, u16),
+}
@@ -1,7 +1,7 @@
 use crate::ast::jack::Type;
 
 // Virtual machine commands
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Command {
     Stack(Stack),
     ACL(ACL),
@@ -10,14 +10,14 @@ pub enum Command {
 }
 
 // Commands that move data
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Stack {
     Push(Segment, u16),
     Pop(Segment, u16),
 }
 
 #[repr(u8)]
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Segment {
     Argument,
     Local,
@@ -31,7 +31,7 @@ pub enum Segment {
 
 // Arithemtic-Logical commands
 #[repr(u8)]
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ACL {
     Arithmetic(Arithmetic),
     Comparison(Comparison),
@@ -39,7 +39,7 @@ pub enum ACL {
 }
 
 #[repr(u8)]
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Arithmetic {
     Add,
     Sub,
@@ -47,15 +47,29 @@ pub enum Arithmetic {
 }
 
 #[repr(u8)]
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Comparison {
     Eq,
     Gt,
     Lt,
 }
 
+impl Comparison {
+    // Comparisons are made the way the Hack translation makes them: `x - y`
+    //  is computed in 16 bits, wrapping around, and compared with zero. So
+    //  `32767 > -1` is false. The VM emulator and the VM optimizer follow this.
+    pub fn holds(&self, x: i16, y: i16) -> bool {
+        let difference = x.wrapping_sub(y);
+        match self {
+            Comparison::Eq => difference == 0,
+            Comparison::Gt => difference > 0,
+            Comparison::Lt => difference < 0,
+        }
+    }
+}
+
 #[repr(u8)]
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Logical {
     And,
     Or,
@@ -63,7 +77,7 @@ pub enum Logical {
 }
 
 // Branch commands:
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Branch {
     Label(String),
     Goto(String),
@@ -71,14 +85,14 @@ pub enum Branch {
 }
 
 // Function commands:
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Function {
     Body(String, u16),
     Call(String, u16),
     Return,
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Var {
     pub r#type: Type,
     pub var_kind: VarKind,
@@ -86,21 +100,21 @@ pub struct Var {
 }
 
 #[repr(u8)]
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum VarKind {
     Global(GlobalKind),
     Local(LocalKind),
 }
 
 #[repr(u8)]
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum GlobalKind {
     Static,
     Field,
 }
 
 #[repr(u8)]
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum LocalKind {
     Arg,
     Var,
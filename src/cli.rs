@@ -0,0 +1,693 @@
+use crate::ast::vm::Command as VmCommand;
+use crate::compiler::diagnostic::Diagnostic;
+use crate::compiler::inliner::InlineLimits;
+use crate::compiler::precedence::Precedence;
+use crate::compiler::runtime::RuntimeProfile;
+use crate::compiler::source_map::{JackSource, SourceMap, VmSource};
+use crate::compiler::type_checker::Strictness;
+use crate::pretty_printer::diagnostic::print_diagnostic;
+use crate::pretty_printer::jack::PrettyPrint;
+
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+pub const USAGE: &str = "\
+usage: jack-compiler-rust <command> [options] <path>
+
+commands:
+  tokenize  print the tokens of a Jack file as XML
+  xml       print the parse tree of a Jack file as XML
+  parse     check the syntax and semantics of a Jack file or directory
+  fmt       print a Jack file in canonical form
+  vm        compile a Jack file or directory to VM files
+  asm       compile Jack or VM code to a single assembly file
+  hack      compile Jack, VM or assembly code to a Hack binary (the default)
+  run       run a program on the VM or CPU emulator, or a `.tst` script
+
+options:
+  -o <path>          write the output to <path>; for `vm` on a directory,
+                     the directory to write the VM files to
+  --emit <stages>    also write intermediate artifacts next to the sources,
+                     a comma separated list of `tokens`, `xml`, `vm`, `asm`
+                     and `map`, the source map from ROM addresses back to
+                     the assembly, VM and Jack lines
+  --optimize <passes>
+                     optimize the generated code, a comma separated list
+                     of `inline`, `dead-code`, `vm`, `peephole` and
+                     `shared-routines`
+  --inline-size <n>  inline functions of at most <n> VM commands (12)
+  --inline-caller-size <n>
+                     stop inlining into functions of <n> commands (1000)
+  --strict           type check Jack code strictly
+  --lenient          type check Jack code leniently (the default)
+  --precedence       give `*` and `/` precedence over `+` and `-`, over
+                     comparisons, over `&` and `|`, instead of evaluating
+                     Jack expressions from left to right
+  --steps <n>        the number of steps `run` executes at most
+  --no-bootstrap     start VM programs at their first command, without
+                     setting SP or calling the entry function
+  --stack-base <n>   the address the stack starts at (256)
+  --lcl <n>, --arg <n>, --this <n>, --that <n>
+                     the initial value of LCL, ARG, THIS or THAT
+  --entry <function> the function the bootstrap calls (Sys.init)
+  --temp <a>..<b>    the RAM addresses of the temp segment (5..13)
+  --static <a>..<b>  the RAM addresses of the static variables (16..256)
+  -h, --help         print this message
+  -V, --version      print the version of the compiler";
+
+const DEFAULT_STEPS: usize = 10_000_000;
+
+// The VM files of a program, named after their classes
+type VmFiles = Vec<(String, Vec<VmCommand>)>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Tokenize,
+    Xml,
+    Parse,
+    Fmt,
+    Vm,
+    Asm,
+    Hack,
+    Run,
+}
+
+// Intermediate artifacts that can be written alongside a command's output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Emit {
+    Tokens,
+    Xml,
+    Vm,
+    Asm,
+    Map,
+}
+
+// Optimizations that can be enabled on the generated code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Optimization {
+    Inline,
+    DeadCode,
+    Vm,
+    Peephole,
+    SharedRoutines,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Options {
+    pub command: Command,
+    pub path: String,
+    pub output: Option<String>,
+    pub emit: Vec<Emit>,
+    pub optimizations: Vec<Optimization>,
+    pub inline: InlineLimits,
+    pub strictness: Strictness,
+    pub precedence: Precedence,
+    pub steps: usize,
+    pub runtime: RuntimeProfile,
+}
+
+// What the command line asks for: help, the version or a command to execute
+#[derive(Debug, PartialEq, Eq)]
+pub enum Invocation {
+    Help,
+    Version,
+    Execute(Options),
+}
+
+impl Options {
+    // Parse the arguments following the program name. A path without a
+    //  command compiles it to hack, as the compiler always did.
+    pub fn parse(args: &[String]) -> Result<Invocation, String> {
+        let mut command = None;
+        let mut paths = Vec::new();
+        let mut output = None;
+        let mut emit = Vec::new();
+        let mut optimizations = Vec::new();
+        let mut inline = InlineLimits::default();
+        let mut strictness = Strictness::Lenient;
+        let mut precedence = Precedence::LeftToRight;
+        let mut steps = DEFAULT_STEPS;
+        let mut runtime = RuntimeProfile::default();
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = |option: &str| {
+                args.next()
+                    .ok_or_else(|| format!("`{}` expects a value", option))
+            };
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(Invocation::Help),
+                "-V" | "--version" => return Ok(Invocation::Version),
+                "-o" => output = Some(value(arg)?.to_string()),
+                "--emit" => {
+                    for stage in value(arg)?.split(',') {
+                        emit.push(parse_emit(stage)?);
+                    }
+                }
+                "--optimize" => {
+                    for pass in value(arg)?.split(',') {
+                        optimizations.push(parse_optimization(pass)?);
+                    }
+                }
+                "--strict" => strictness = Strictness::Strict,
+                "--lenient" => strictness = Strictness::Lenient,
+                "--precedence" => precedence = Precedence::Climbing,
+                "--inline-size" => inline.max_size = parse_number(arg, value(arg)?)?,
+                "--inline-caller-size" => inline.max_caller_size = parse_number(arg, value(arg)?)?,
+                "--steps" => steps = parse_number(arg, value(arg)?)?,
+                "--no-bootstrap" => runtime.bootstrap = false,
+                "--stack-base" => runtime.stack_base = parse_address(arg, value(arg)?)?,
+                "--lcl" => runtime.lcl = Some(parse_address(arg, value(arg)?)?),
+                "--arg" => runtime.arg = Some(parse_address(arg, value(arg)?)?),
+                "--this" => runtime.this = Some(parse_address(arg, value(arg)?)?),
+                "--that" => runtime.that = Some(parse_address(arg, value(arg)?)?),
+                "--entry" => runtime.entry = value(arg)?.to_string(),
+                "--temp" => runtime.temp = parse_range(arg, value(arg)?)?,
+                "--static" => runtime.statics = parse_range(arg, value(arg)?)?,
+                option if option.starts_with('-') => {
+                    return Err(format!("unknown option `{}`", option))
+                }
+                word => match (command, paths.is_empty(), parse_command(word)) {
+                    (None, true, Some(c)) => command = Some(c),
+                    _ => paths.push(word.to_string()),
+                },
+            }
+        }
+
+        let path = match paths.as_slice() {
+            [path] => path.to_string(),
+            [] => return Err("missing path".to_string()),
+            [word, ..] if command.is_none() => return Err(format!("unknown command `{}`", word)),
+            _ => return Err(format!("expected one path, found {}", paths.len())),
+        };
+        // A source map refers to the code as it was compiled, which these
+        //  passes rewrite
+        if emit.contains(&Emit::Map) {
+            let rewriting = optimizations
+                .iter()
+                .find(|pass| !matches!(pass, Optimization::SharedRoutines));
+            if let Some(pass) = rewriting {
+                return Err(format!(
+                    "`--emit map` cannot be combined with the `{}` optimization",
+                    pass.as_str()
+                ));
+            }
+        }
+        Ok(Invocation::Execute(Self {
+            command: command.unwrap_or(Command::Hack),
+            path,
+            output,
+            emit,
+            optimizations,
+            inline,
+            strictness,
+            precedence,
+            steps,
+            runtime,
+        }))
+    }
+}
+
+impl Command {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Command::Tokenize => "tokenize",
+            Command::Xml => "xml",
+            Command::Parse => "parse",
+            Command::Fmt => "fmt",
+            Command::Vm => "vm",
+            Command::Asm => "asm",
+            Command::Hack => "hack",
+            Command::Run => "run",
+        }
+    }
+}
+
+impl Optimization {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Optimization::Inline => "inline",
+            Optimization::DeadCode => "dead-code",
+            Optimization::Vm => "vm",
+            Optimization::Peephole => "peephole",
+            Optimization::SharedRoutines => "shared-routines",
+        }
+    }
+}
+
+fn parse_command(word: &str) -> Option<Command> {
+    match word {
+        "tokenize" => Some(Command::Tokenize),
+        "xml" => Some(Command::Xml),
+        "parse" => Some(Command::Parse),
+        "fmt" => Some(Command::Fmt),
+        "vm" => Some(Command::Vm),
+        "asm" => Some(Command::Asm),
+        "hack" => Some(Command::Hack),
+        "run" => Some(Command::Run),
+        _ => None,
+    }
+}
+
+fn parse_emit(stage: &str) -> Result<Emit, String> {
+    match stage {
+        "tokens" => Ok(Emit::Tokens),
+        "xml" => Ok(Emit::Xml),
+        "vm" => Ok(Emit::Vm),
+        "asm" => Ok(Emit::Asm),
+        "map" => Ok(Emit::Map),
+        _ => Err(format!(
+            "unknown stage `{}`, expected `tokens`, `xml`, `vm`, `asm` or `map`",
+            stage
+        )),
+    }
+}
+
+fn parse_number(option: &str, n: &str) -> Result<usize, String> {
+    n.parse()
+        .map_err(|_| format!("`{}` expects a number, found `{}`", option, n))
+}
+
+fn parse_address(option: &str, n: &str) -> Result<u16, String> {
+    n.parse()
+        .map_err(|_| format!("`{}` expects an address, found `{}`", option, n))
+}
+
+// A range of RAM addresses, as in `16..256`
+fn parse_range(option: &str, range: &str) -> Result<Range<u16>, String> {
+    let invalid = || {
+        format!(
+            "`{}` expects a range of addresses like `16..256`, found `{}`",
+            option, range
+        )
+    };
+    let (start, end) = range.split_once("..").ok_or_else(invalid)?;
+    match (start.parse(), end.parse()) {
+        (Ok(start), Ok(end)) if start <= end => Ok(start..end),
+        _ => Err(invalid()),
+    }
+}
+
+fn parse_optimization(pass: &str) -> Result<Optimization, String> {
+    match pass {
+        "inline" => Ok(Optimization::Inline),
+        "dead-code" => Ok(Optimization::DeadCode),
+        "vm" => Ok(Optimization::Vm),
+        "peephole" => Ok(Optimization::Peephole),
+        "shared-routines" => Ok(Optimization::SharedRoutines),
+        _ => Err(format!(
+            "unknown optimization `{}`, expected `inline`, `dead-code`, `vm`, `peephole` or `shared-routines`",
+            pass
+        )),
+    }
+}
+
+// The kinds of input a path can hold. A directory is a Jack program if it
+//  contains Jack files and a VM program if it contains VM files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Input {
+    Jack,
+    Vm,
+    Asm,
+    Hack,
+    Script,
+}
+
+fn input_kind(path: &str) -> Result<Input, Vec<Diagnostic>> {
+    let metadata = fs::metadata(path).map_err(|e| vec![Diagnostic::from_io(path, e)])?;
+    if metadata.is_dir() {
+        if !crate::files_with_extension(path, "jack")?.is_empty() {
+            return Ok(Input::Jack);
+        }
+        if !crate::files_with_extension(path, "vm")?.is_empty() {
+            return Ok(Input::Vm);
+        }
+        return Err(error(path, "directory contains no `.jack` or `.vm` files"));
+    }
+    match Path::new(path).extension().and_then(|s| s.to_str()) {
+        Some("jack") => Ok(Input::Jack),
+        Some("vm") => Ok(Input::Vm),
+        Some("asm") => Ok(Input::Asm),
+        Some("hack") => Ok(Input::Hack),
+        Some("tst") => Ok(Input::Script),
+        _ => Err(error(
+            path,
+            "expected a `.jack`, `.vm`, `.asm`, `.hack` or `.tst` file",
+        )),
+    }
+}
+
+fn error(path: &str, message: &str) -> Vec<Diagnostic> {
+    vec![Diagnostic::error(path, None, message.to_string())]
+}
+
+// Print every diagnostic to stderr, quoting the offending source line
+pub fn report(diagnostics: &[Diagnostic]) {
+    diagnostics.iter().for_each(|diagnostic| {
+        let source = fs::read_to_string(&diagnostic.file).ok();
+        eprintln!("{}", print_diagnostic(diagnostic, source.as_deref()));
+    });
+}
+
+// Execution:
+pub fn execute(options: &Options) -> Result<(), Vec<Diagnostic>> {
+    let input = input_kind(&options.path)?;
+    match (options.command, input) {
+        (Command::Tokenize | Command::Xml | Command::Fmt, Input::Jack) if is_dir(&options.path) => {
+            Err(error(
+                &options.path,
+                "expected a `.jack` file rather than a directory",
+            ))
+        }
+        (Command::Tokenize, Input::Jack) => {
+            let tokens = crate::tokenize_jack_file(&options.path)?;
+            let xml = crate::pretty_printer::lexer::print_tokens(
+                tokens.into_iter().map(|(token, _)| token).collect(),
+            );
+            write_or_print(options.output.as_deref(), xml)
+        }
+        (Command::Xml, Input::Jack) => {
+            let class = crate::parse_jack_file(&options.path)?;
+            let xml = crate::pretty_printer::xml::print_class(&class);
+            write_or_print(options.output.as_deref(), xml)
+        }
+        (Command::Fmt, Input::Jack) => {
+            let class = crate::parse_jack_file(&options.path)?;
+            write_or_print(options.output.as_deref(), class.pretty_print(0))
+        }
+        (Command::Parse, Input::Jack) => {
+            if options.output.is_some() {
+                return Err(error(&options.path, "`parse` does not write any output"));
+            }
+            jack_program(options).map(|_| ())
+        }
+        (Command::Vm, Input::Jack) => {
+            let (sources, files, _) = jack_program(options)?;
+            let dir = match &options.output {
+                Some(output) if is_dir(&options.path) => PathBuf::from(output),
+                Some(output) => return write_vm_file(Path::new(output), &files[0].1),
+                None => source_dir(&options.path),
+            };
+            write_vm_files(&dir, &sources, &files)
+        }
+        (Command::Asm | Command::Hack | Command::Run, Input::Jack) => {
+            let (sources, files, jack) = jack_program(options)?;
+            if options.emit.contains(&Emit::Vm) {
+                write_vm_files(&source_dir(&options.path), &sources, &files)?;
+            }
+            let vm: Vec<VmSource> = files
+                .iter()
+                .map(|(name, commands)| VmSource::printed(name, commands))
+                .collect();
+            match options.command {
+                Command::Run => run_vm(options, files),
+                _ => write_assembly(options, translate(options, files, &vm, &jack)?),
+            }
+        }
+        (Command::Asm | Command::Hack, Input::Vm) => {
+            let (files, vm) = vm_program(options)?;
+            write_assembly(options, translate(options, files, &vm, &[])?)
+        }
+        (Command::Run, Input::Vm) => run_vm(options, vm_program(options)?.0),
+        (Command::Hack, Input::Asm) => {
+            let hack = crate::assembler(&options.path)?;
+            write_output(options, "hack", hack.join("\n"))
+        }
+        (Command::Run, Input::Asm) => {
+            let hack = crate::assembler(&options.path)?;
+            let cpu = crate::emulator::hack::Hack::from_binary(&hack)
+                .map_err(|e| error(&options.path, &e))?;
+            run_cpu(options, cpu)
+        }
+        (Command::Run, Input::Hack) => run_cpu(options, crate::load_hack_file(&options.path)?),
+        (Command::Run, Input::Script) => {
+            crate::run_test_script(&options.path)?;
+            println!("{}: comparison ended successfully", options.path);
+            Ok(())
+        }
+        (command, _) => Err(error(
+            &options.path,
+            &format!("`{}` cannot be applied to this input", command.as_str()),
+        )),
+    }
+}
+
+// Check and compile a Jack file or directory, linked with the OS. Returns the
+//  names of the user's classes along with the VM files of the whole program
+//  and their Jack sources.
+fn jack_program(
+    options: &Options,
+) -> Result<(Vec<String>, VmFiles, Vec<JackSource>), Vec<Diagnostic>> {
+    let sources = if is_dir(&options.path) {
+        crate::files_with_extension(&options.path, "jack")?
+    } else {
+        vec![PathBuf::from(&options.path)]
+    };
+    let (files, jack, warnings) = crate::Compiler::new()
+        .strictness(options.strictness)
+        .precedence(options.precedence)
+        .optimize_vm(options.optimizations.contains(&Optimization::Vm))
+        .compile_vm_with_origins(&options.path)?;
+    report(&warnings);
+    if options.emit.contains(&Emit::Tokens) {
+        for source in &sources {
+            let source = source.to_str().unwrap_or_default();
+            let tokens = crate::tokenize_jack_file(source)?;
+            let xml = crate::pretty_printer::lexer::print_tokens(
+                tokens.into_iter().map(|(token, _)| token).collect(),
+            );
+            crate::write_file(&format!("{}T.xml", source.trim_end_matches(".jack")), xml)?;
+        }
+    }
+    if options.emit.contains(&Emit::Xml) {
+        for source in &sources {
+            let source = source.to_str().unwrap_or_default();
+            let xml = crate::pretty_printer::xml::print_class(&crate::parse_jack_file(source)?);
+            crate::write_file(&format!("{}.xml", source.trim_end_matches(".jack")), xml)?;
+        }
+    }
+    let names = sources
+        .iter()
+        .map(|source| crate::file_stem(source.to_str().unwrap_or_default()))
+        .collect();
+    Ok((names, files, jack))
+}
+
+// Parse a VM file or directory, linked with the OS classes it calls but does
+//  not define, optimizing it if asked to. The line of every command is kept
+//  for source maps.
+fn vm_program(options: &Options) -> Result<(VmFiles, Vec<VmSource>), Vec<Diagnostic>> {
+    let (files, vm) = crate::parse_vm_path_with_origins(&options.path)?;
+    let files = crate::Compiler::new()
+        .strictness(options.strictness)
+        .link_vm(files)?;
+    report(&crate::compiler::vm_to_asm::VmToAsm::verify(
+        &files,
+        &options.runtime,
+    )?);
+    Ok(match options.optimizations.contains(&Optimization::Vm) {
+        true => (
+            crate::compiler::vm_optimizer::VmOptimizer::optimize_program(files),
+            vm,
+        ),
+        false => (files, vm),
+    })
+}
+
+// Only the user's classes are written, not the OS they are linked with
+fn write_vm_files(
+    dir: &Path,
+    names: &[String],
+    files: &[(String, Vec<VmCommand>)],
+) -> Result<(), Vec<Diagnostic>> {
+    files
+        .iter()
+        .filter(|(name, _)| names.contains(name))
+        .try_for_each(|(name, commands)| write_vm_file(&dir.join(format!("{}.vm", name)), commands))
+}
+
+fn write_vm_file(path: &Path, commands: &[VmCommand]) -> Result<(), Vec<Diagnostic>> {
+    let vm = crate::pretty_printer::vm::print_vm(commands.to_vec());
+    crate::write_file(path.to_str().unwrap_or_default(), vm)
+}
+
+// Translate VM files to assembly, reporting what the optimizations saved.
+//  The program is verified again once the optimizations have rewritten it,
+//  and the source map is only made when it is emitted.
+fn translate(
+    options: &Options,
+    files: VmFiles,
+    vm: &[VmSource],
+    jack: &[JackSource],
+) -> Result<(Vec<crate::ast::asm::Assembly>, Option<SourceMap>), Vec<Diagnostic>> {
+    let files = match options.optimizations.contains(&Optimization::Inline) {
+        true => {
+            let (files, report) = crate::compiler::inliner::Inliner::inline(files, options.inline);
+            println!("inline: {} calls inlined", report.inlined);
+            files
+        }
+        false => files,
+    };
+    let files = match options.optimizations.contains(&Optimization::DeadCode) {
+        true => {
+            let (files, report) =
+                crate::compiler::dead_code::DeadCode::eliminate(files, &options.runtime.entry);
+            println!(
+                "dead code: removed {} of {} subroutines",
+                report.removed.len(),
+                report.kept + report.removed.len()
+            );
+            for function in &report.removed {
+                println!("  {}", function);
+            }
+            files
+        }
+        false => files,
+    };
+    crate::compiler::vm_to_asm::VmToAsm::verify(&files, &options.runtime)?;
+    let peephole = options.optimizations.contains(&Optimization::Peephole);
+    let routines = match options
+        .optimizations
+        .contains(&Optimization::SharedRoutines)
+    {
+        true => crate::compiler::vm_to_asm::Routines::Shared,
+        false => crate::compiler::vm_to_asm::Routines::Inline,
+    };
+    let mut compiler = crate::Compiler::new();
+    compiler
+        .peephole(peephole)
+        .routines(routines)
+        .runtime(options.runtime.clone());
+    if options.emit.contains(&Emit::Map) {
+        let (asm, origins) = compiler.vm_to_asm_with_origins(files);
+        let map = SourceMap::new(vm, jack, &asm, &origins);
+        return Ok((asm, Some(map)));
+    }
+    let (asm, report) = compiler.vm_to_asm_with_report(files);
+    if peephole {
+        println!(
+            "peephole: {} -> {} instructions, {} saved",
+            report.before,
+            report.after,
+            report.saved()
+        );
+    }
+    Ok((asm, None))
+}
+
+fn write_assembly(
+    options: &Options,
+    (asm, map): (Vec<crate::ast::asm::Assembly>, Option<SourceMap>),
+) -> Result<(), Vec<Diagnostic>> {
+    if let Some(map) = map {
+        let path = default_output(&options.path, "map");
+        crate::write_file(
+            path.to_str().unwrap_or_default(),
+            crate::pretty_printer::source_map::print_source_map(&map),
+        )?;
+    }
+    match options.command {
+        Command::Asm => write_output(options, "asm", crate::pretty_printer::asm::print_asm(asm)),
+        _ => {
+            if options.emit.contains(&Emit::Asm) {
+                let path = default_output(&options.path, "asm");
+                crate::write_file(
+                    path.to_str().unwrap_or_default(),
+                    crate::pretty_printer::asm::print_asm(asm.clone()),
+                )?;
+            }
+            let hack = crate::compiler::assembler::Assembler::assemble_with(
+                asm,
+                options.runtime.statics.start,
+            );
+            write_output(options, "hack", hack.join("\n"))
+        }
+    }
+}
+
+// Write to `-o` or next to the input: `Prog.jack` becomes `Prog.hack` and the
+//  directory `Prog` becomes `Prog/Prog.hack`
+fn write_output(
+    options: &Options,
+    extension: &str,
+    contents: String,
+) -> Result<(), Vec<Diagnostic>> {
+    let path = match &options.output {
+        Some(output) => PathBuf::from(output),
+        None => default_output(&options.path, extension),
+    };
+    crate::write_file(path.to_str().unwrap_or_default(), contents)
+}
+
+fn default_output(path: &str, extension: &str) -> PathBuf {
+    let path = Path::new(path);
+    if path.is_dir() {
+        let name = path.file_name().and_then(|s| s.to_str()).unwrap_or("Main");
+        path.join(format!("{}.{}", name, extension))
+    } else {
+        path.with_extension(extension)
+    }
+}
+
+fn write_or_print(output: Option<&str>, contents: String) -> Result<(), Vec<Diagnostic>> {
+    match output {
+        Some(output) => crate::write_file(output, contents),
+        None => {
+            print!("{}", contents);
+            Ok(())
+        }
+    }
+}
+
+fn is_dir(path: &str) -> bool {
+    Path::new(path).is_dir()
+}
+
+// The directory holding a file, or the directory itself
+fn source_dir(path: &str) -> PathBuf {
+    let path = Path::new(path);
+    if path.is_dir() {
+        path.to_path_buf()
+    } else {
+        path.parent().map_or(PathBuf::new(), |p| p.to_path_buf())
+    }
+}
+
+// Running:
+// Jack programs end in the endless loop of `Sys.halt`, which counts as halting
+fn run_vm(options: &Options, files: VmFiles) -> Result<(), Vec<Diagnostic>> {
+    let runtime_error = |e: String| error(&options.path, &e);
+    let mut vm = crate::emulator::vm::Vm::with_profile(files, options.runtime.clone())
+        .map_err(runtime_error)?;
+    if !options.runtime.bootstrap || vm.current_function() == Some(&options.runtime.entry) {
+        vm.bootstrap().map_err(runtime_error)?;
+    }
+    let mut halted = false;
+    while !halted && vm.steps() < options.steps {
+        vm.step().map_err(runtime_error)?;
+        halted = vm.is_halted() || vm.current_function() == Some("Sys.halt");
+    }
+    report_run(halted, vm.steps(), "steps");
+    Ok(())
+}
+
+// Programs without bootstrap code rely on the registers being set for them
+fn run_cpu(options: &Options, mut cpu: crate::emulator::hack::Hack) -> Result<(), Vec<Diagnostic>> {
+    if !options.runtime.bootstrap {
+        for (register, value) in options.runtime.registers() {
+            cpu.set_ram(register, value as i16);
+        }
+    }
+    let halted = cpu.run_until_halt(options.steps);
+    report_run(halted, cpu.cycles(), "cycles");
+    Ok(())
+}
+
+fn report_run(halted: bool, count: usize, unit: &str) {
+    if halted {
+        println!("halted after {} {}", count, unit);
+    } else {
+        println!("stopped after {} {} without halting", count, unit);
+    }
+}
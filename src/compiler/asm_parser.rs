@@ -0,0 +1,183 @@
+use crate::ast::asm::*;
+use crate::ast::span::*;
+
+use chumsky::prelude::*;
+
+// Hack assembly language (chapter 6):
+//  At most one instruction or label per line, optionally followed by a `//`
+//  comment. As in the nand2tetris assembler, whitespace inside a C-instruction
+//  is ignored.
+pub fn parse_assembly() -> impl Parser<char, Vec<Assembly>, Error = Simple<char, Span>> {
+    parse_line()
+        .separated_by(just('\n'))
+        .then_ignore(end())
+        .map(|lines| lines.into_iter().flatten().collect())
+}
+
+fn parse_comment() -> impl Parser<char, (), Error = Simple<char, Span>> {
+    just("//")
+        .then_ignore(filter(|&c| c != '\n').repeated())
+        .ignored()
+}
+
+// `/` never appears in an instruction, so it can only start a comment
+fn parse_line() -> impl Parser<char, Option<Assembly>, Error = Simple<char, Span>> {
+    filter(|&c| c != '\n' && c != '/')
+        .repeated()
+        .collect::<String>()
+        .map_with_span(|text, span| (text, span))
+        .then_ignore(parse_comment().or_not())
+        .try_map(|(text, span), _| match text.trim() {
+            "" => Ok(None),
+            instruction => parse_instruction(instruction, trimmed_span(&text, span)).map(Some),
+        })
+}
+
+// The span of a line without its leading and trailing whitespace
+fn trimmed_span(text: &str, span: Span) -> Span {
+    let leading = text.chars().take_while(|c| c.is_whitespace()).count();
+    let length = text.trim().chars().count();
+    let mut start = span.start;
+    start.offset += leading;
+    start.column += leading;
+    let mut end = start;
+    end.offset += length;
+    end.column += length;
+    Span { start, end }
+}
+
+fn parse_instruction(instruction: &str, span: Span) -> Result<Assembly, Simple<char, Span>> {
+    if let Some(value) = instruction.strip_prefix('@') {
+        return parse_a_instruction(value)
+            .map(Assembly::A)
+            .map_err(|message| Simple::custom(span, message));
+    }
+    if let Some(label) = instruction.strip_prefix('(') {
+        return match label.strip_suffix(')') {
+            Some(label) => parse_symbol(label)
+                .map(Assembly::Label)
+                .map_err(|message| Simple::custom(span, message)),
+            None => Err(Simple::custom(span, "unclosed label".to_string())),
+        };
+    }
+    let c_instruction: String = instruction.chars().filter(|c| !c.is_whitespace()).collect();
+    parse_c_instruction(&c_instruction)
+        .map(Assembly::C)
+        .map_err(|message| {
+            Simple::custom(
+                span,
+                format!("invalid C-instruction `{}`: {}", instruction, message),
+            )
+        })
+}
+
+// A-instructions hold a 15-bit constant, since the top bit tells them apart
+//  from C-instructions
+fn parse_a_instruction(value: &str) -> Result<AInstruction, String> {
+    if value.starts_with(|c: char| c.is_ascii_digit()) {
+        if !value.chars().all(|c| c.is_ascii_digit()) {
+            return Err(format!("invalid constant `{}`", value));
+        }
+        return match value.parse::<u16>() {
+            Ok(i) if i <= 32767 => Ok(AInstruction::Constant(i)),
+            _ => Err(format!("constant {} is out of range 0..=32767", value)),
+        };
+    }
+    parse_symbol(value).map(AInstruction::Symbol)
+}
+
+// Symbols are sequences of letters, digits, `_`, `.`, `$` and `:` that do not
+//  begin with a digit
+fn parse_symbol(symbol: &str) -> Result<String, String> {
+    let valid = !symbol.is_empty()
+        && !symbol.starts_with(|c: char| c.is_ascii_digit())
+        && symbol
+            .chars()
+            .all(|c| c.is_alphanumeric() || "_.$:".contains(c));
+    match valid {
+        true => Ok(symbol.to_string()),
+        false => Err(format!("invalid symbol `{}`", symbol)),
+    }
+}
+
+// dest=comp;jump, where both `dest=` and `;jump` are optional
+fn parse_c_instruction(instruction: &str) -> Result<CInstruction, String> {
+    let (o_dest, rest) = match instruction.split_once('=') {
+        Some((dest, rest)) => (Some(parse_dest(dest)?), rest),
+        None => (None, instruction),
+    };
+    let (comp, o_jump) = match rest.split_once(';') {
+        Some((comp, jump)) => (comp, Some(parse_jump(jump)?)),
+        None => (rest, None),
+    };
+    Ok(CInstruction {
+        comp: parse_comp(comp)?,
+        o_dest,
+        o_jump,
+    })
+}
+
+fn parse_comp(comp: &str) -> Result<Comp, String> {
+    Ok(match comp {
+        "0" => Comp::Zero,
+        "1" => Comp::One,
+        "-1" => Comp::NegOne,
+        "D" => Comp::D,
+        "A" => Comp::A,
+        "!D" => Comp::NotD,
+        "!A" => Comp::NotA,
+        "-D" => Comp::NegD,
+        "-A" => Comp::NegA,
+        "D+1" => Comp::DPlusOne,
+        "A+1" => Comp::APlusOne,
+        "D-1" => Comp::DMinusOne,
+        "A-1" => Comp::AMinusOne,
+        "D+A" => Comp::DPlusA,
+        "D-A" => Comp::DMinusA,
+        "A-D" => Comp::AMinusD,
+        "D&A" => Comp::DAndA,
+        "D|A" => Comp::DOrA,
+        "M" => Comp::M,
+        "!M" => Comp::NotM,
+        "-M" => Comp::NegM,
+        "M+1" => Comp::MPlusOne,
+        "M-1" => Comp::MMinusOne,
+        "D+M" => Comp::DPlusM,
+        "D-M" => Comp::DMinusM,
+        "M-D" => Comp::MMinusD,
+        "D&M" => Comp::DAndM,
+        "D|M" => Comp::DOrM,
+        "" => return Err("missing computation".to_string()),
+        _ => return Err(format!("unknown computation `{}`", comp)),
+    })
+}
+
+// Both editions of the book are accepted: `MD` and `AMD` from the first,
+//  `DM` and `ADM` from the second
+fn parse_dest(dest: &str) -> Result<Dest, String> {
+    Ok(match dest {
+        "M" => Dest::M,
+        "D" => Dest::D,
+        "DM" => Dest::DM,
+        "MD" => Dest::MD,
+        "A" => Dest::A,
+        "AM" => Dest::AM,
+        "AD" => Dest::AD,
+        "ADM" => Dest::ADM,
+        "AMD" => Dest::AMD,
+        _ => return Err(format!("unknown destination `{}`", dest)),
+    })
+}
+
+fn parse_jump(jump: &str) -> Result<Jump, String> {
+    Ok(match jump {
+        "JGT" => Jump::JGT,
+        "JEQ" => Jump::JEQ,
+        "JGE" => Jump::JGE,
+        "JLT" => Jump::JLT,
+        "JNE" => Jump::JNE,
+        "JLE" => Jump::JLE,
+        "JMP" => Jump::JMP,
+        _ => return Err(format!("unknown jump `{}`", jump)),
+    })
+}
@@ -11,9 +11,15 @@ pub struct Assembler {
 
 impl Assembler {
     pub fn assemble(assembly_stack: Vec<Assembly>) -> Vec<String> {
+        Self::assemble_with(assembly_stack, 16)
+    }
+
+    // Variables, such as the statics of VM files, are allocated in order
+    //  from `variable_base`
+    pub fn assemble_with(assembly_stack: Vec<Assembly>, variable_base: u16) -> Vec<String> {
         let mut assembler = Self {
             ctx: HashMap::new(),
-            index: 16,
+            index: variable_base,
             hack_stack: Vec::new(),
         };
         assembler.set_labels(&assembly_stack);
@@ -21,6 +27,21 @@ impl Assembler {
         take(&mut assembler.hack_stack)
     }
 
+    // The ROM address of each instruction, labels taking none
+    pub fn addresses(assembly_stack: &[Assembly]) -> Vec<Option<u16>> {
+        let mut i = 0;
+        assembly_stack
+            .iter()
+            .map(|assembly| match assembly {
+                Assembly::Label(_) => None,
+                _ => {
+                    i += 1;
+                    Some(i - 1)
+                }
+            })
+            .collect()
+    }
+
     // Functions to modify `Assembler` struct
     fn set_labels(&mut self, assembly_stack: &Vec<Assembly>) {
         let mut i = 0;
@@ -52,12 +73,6 @@ impl Assembler {
         self.hack_stack.push(hack);
     }
 
-    fn label(&mut self) -> u16 {
-        let i = self.index;
-        self.index += 1;
-        i
-    }
-
     // Functions for assembly translation
     fn translate_assembly_stack(&mut self, assembly_stack: Vec<Assembly>) {
         assembly_stack
@@ -176,6 +191,7 @@ fn compile_dest(o_dest: Option<Dest>) -> String {
             Dest::AM => "101".to_string(),
             Dest::AD => "110".to_string(),
             Dest::ADM => "111".to_string(),
+            Dest::AMD => "111".to_string(),
         },
     }
 }
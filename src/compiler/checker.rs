@@ -0,0 +1,288 @@
+use crate::ast::jack::*;
+use crate::ast::span::Span;
+use crate::compiler::diagnostic::Diagnostic;
+
+use std::collections::HashMap;
+use std::mem::take;
+
+// Semantic analysis of a single class, run between the parser and `JackToVm`
+//  so that malformed programs are rejected with diagnostics instead of
+//  panicking or silently generating wrong VM code.
+pub struct Checker {
+    file_name: String,
+    class_name: String,
+    subroutines: HashMap<String, (SubroutineType, usize)>,
+    global_ctx: HashMap<String, (Kind, Span)>,
+    local_ctx: HashMap<String, Span>,
+    subroutine_type: SubroutineType,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Checker {
+    pub fn check(file_name: &str, class: &Class) -> Vec<Diagnostic> {
+        let mut checker = Self {
+            file_name: file_name.to_string(),
+            class_name: class.class_name.to_string(),
+            subroutines: HashMap::new(),
+            global_ctx: HashMap::new(),
+            local_ctx: HashMap::new(),
+            subroutine_type: SubroutineType::Function,
+            diagnostics: Vec::new(),
+        };
+        let checked = checker.check_class_dec(&class.class_dec);
+        take(&mut checked.diagnostics)
+    }
+
+    fn error(&mut self, span: Span, message: String) -> &mut Self {
+        self.diagnostics
+            .push(Diagnostic::error(&self.file_name, Some(span), message));
+        self
+    }
+
+    fn duplicate(&mut self, name: &str, span: Span, previous: Span) -> &mut Self {
+        let diagnostic = Diagnostic::error(
+            &self.file_name,
+            Some(span),
+            format!("duplicate declaration of `{}`", name),
+        )
+        .with_note(format!(
+            "`{}` was first declared at {}:{}",
+            name, previous.start.line, previous.start.column
+        ));
+        self.diagnostics.push(diagnostic);
+        self
+    }
+
+    fn declare_local(&mut self, name: &str, span: Span) -> &mut Self {
+        match self.local_ctx.get(name) {
+            Some(previous) => {
+                let previous = *previous;
+                self.duplicate(name, span, previous)
+            }
+            None => {
+                self.local_ctx.insert(name.to_string(), span);
+                self
+            }
+        }
+    }
+
+    fn in_function(&self) -> bool {
+        matches!(self.subroutine_type, SubroutineType::Function)
+    }
+
+    // Checking functions:
+    fn check_class_dec(&mut self, class_dec: &ClassDec) -> &mut Self {
+        class_dec.class_var_dec.iter().for_each(|cvd| {
+            cvd.vars.iter().for_each(|var| {
+                match self.global_ctx.get(var) {
+                    Some((_, previous)) => {
+                        let previous = *previous;
+                        self.duplicate(var, cvd.span, previous);
+                    }
+                    None => {
+                        self.global_ctx
+                            .insert(var.to_string(), (cvd.kind.clone(), cvd.span));
+                    }
+                };
+            });
+        });
+
+        let mut declared: HashMap<String, Span> = HashMap::new();
+        class_dec.subroutine_dec.iter().for_each(|sd| {
+            match declared.get(&sd.subroutine_name) {
+                Some(previous) => {
+                    let previous = *previous;
+                    self.duplicate(&sd.subroutine_name, sd.span, previous);
+                }
+                None => {
+                    declared.insert(sd.subroutine_name.to_string(), sd.span);
+                    self.subroutines.insert(
+                        sd.subroutine_name.to_string(),
+                        (sd.subroutine_type.clone(), sd.parameter_list.len()),
+                    );
+                }
+            };
+        });
+
+        class_dec.subroutine_dec.iter().for_each(|sd| {
+            self.check_subroutine_dec(sd);
+        });
+        self
+    }
+
+    fn check_subroutine_dec(&mut self, subroutine_dec: &SubroutineDec) -> &mut Self {
+        self.local_ctx = HashMap::new();
+        self.subroutine_type = subroutine_dec.subroutine_type.clone();
+        subroutine_dec.parameter_list.iter().for_each(|p| {
+            self.declare_local(&p.var_name, p.span);
+        });
+        subroutine_dec
+            .subroutine_body
+            .var_decs
+            .iter()
+            .for_each(|vd| {
+                vd.var_name.iter().for_each(|var| {
+                    self.declare_local(var, vd.span);
+                });
+            });
+        self.check_statements(&subroutine_dec.subroutine_body.stmts);
+        if !always_returns(&subroutine_dec.subroutine_body.stmts) {
+            self.error(
+                subroutine_dec.span,
+                format!(
+                    "subroutine `{}` does not end with a `return` statement",
+                    subroutine_dec.subroutine_name
+                ),
+            );
+        }
+        self
+    }
+
+    fn check_statements(&mut self, statements: &[Statement]) -> &mut Self {
+        statements.iter().for_each(|stmt| {
+            self.check_statement(stmt);
+        });
+        self
+    }
+
+    fn check_statement(&mut self, statement: &Statement) -> &mut Self {
+        match &statement.kind {
+            StatementKind::Let(ident, array, e) => {
+                self.check_var(ident, statement.span);
+                if let Some(e2) = array {
+                    self.check_expression(e2);
+                }
+                self.check_expression(e)
+            }
+            StatementKind::If(e, s1, o_s2) => {
+                self.check_expression(e).check_statements(s1);
+                match o_s2 {
+                    Some(s2) => self.check_statements(s2),
+                    None => self,
+                }
+            }
+            StatementKind::While(e, stmts) => self.check_expression(e).check_statements(stmts),
+            StatementKind::Do(sc) => self.check_subroutine_call(sc, statement.span),
+            StatementKind::Return(oe) => match oe {
+                Some(e) => self.check_expression(e),
+                None => self,
+            },
+        }
+    }
+
+    // A variable must be declared, and fields only exist inside an object
+    fn check_var(&mut self, name: &str, span: Span) -> &mut Self {
+        if self.local_ctx.contains_key(name) {
+            return self;
+        }
+        match self.global_ctx.get(name) {
+            None => self.error(span, format!("undeclared variable `{}`", name)),
+            Some((Kind::Field, _)) if self.in_function() => self.error(
+                span,
+                format!("field `{}` cannot be accessed from a function", name),
+            ),
+            Some(_) => self,
+        }
+    }
+
+    fn check_subroutine_call(&mut self, subroutine_call: &SubroutineCall, span: Span) -> &mut Self {
+        let (target, subroutine_name, exprs) = match subroutine_call {
+            SubroutineCall::Call(s, es) => (None, s, es),
+            SubroutineCall::ClassCall(c, s, es) => (Some(c), s, es),
+        };
+        exprs.iter().for_each(|e| {
+            self.check_expression(e);
+        });
+        if let Some(c) = target {
+            if self.global_ctx.contains_key(c) {
+                self.check_var(c, span);
+            }
+        }
+
+        // Only calls resolved within this class can be checked here
+        let local = match target {
+            None => true,
+            Some(c) => {
+                *c == self.class_name
+                    && !self.local_ctx.contains_key(c)
+                    && !self.global_ctx.contains_key(c)
+            }
+        };
+        if !local {
+            return self;
+        }
+        match self.subroutines.get(subroutine_name).cloned() {
+            None => self.error(
+                span,
+                format!(
+                    "class `{}` has no subroutine `{}`",
+                    self.class_name, subroutine_name
+                ),
+            ),
+            Some((subroutine_type, arity)) => {
+                if target.is_none()
+                    && self.in_function()
+                    && matches!(subroutine_type, SubroutineType::Method)
+                {
+                    self.error(
+                        span,
+                        format!(
+                            "method `{}` cannot be called from a function without an object",
+                            subroutine_name
+                        ),
+                    );
+                }
+                if arity != exprs.len() {
+                    self.error(
+                        span,
+                        format!(
+                            "`{}.{}` expects {} argument(s), found {}",
+                            self.class_name,
+                            subroutine_name,
+                            arity,
+                            exprs.len()
+                        ),
+                    );
+                }
+                self
+            }
+        }
+    }
+
+    fn check_expression(&mut self, expression: &Expression) -> &mut Self {
+        self.check_term(&expression.term);
+        expression.bin.iter().for_each(|(_, t)| {
+            self.check_term(t);
+        });
+        self
+    }
+
+    fn check_term(&mut self, term: &Term) -> &mut Self {
+        match &term.kind {
+            TermKind::IntegerConstant(_) | TermKind::StringConstant(_) => self,
+            TermKind::KeywordConstant(KeywordConstant::This) if self.in_function() => {
+                self.error(term.span, "`this` cannot be used in a function".to_string())
+            }
+            TermKind::KeywordConstant(_) => self,
+            TermKind::VarName(s, oe) => {
+                self.check_var(s, term.span);
+                match oe {
+                    Some(e) => self.check_expression(e),
+                    None => self,
+                }
+            }
+            TermKind::Unary(_, t) => self.check_term(t),
+            TermKind::ParensExpr(e) => self.check_expression(e),
+            TermKind::SubroutineCall(sc) => self.check_subroutine_call(sc, term.span),
+        }
+    }
+}
+
+// Whether every path through `statements` ends in a `return`
+fn always_returns(statements: &[Statement]) -> bool {
+    match statements.last().map(|stmt| &stmt.kind) {
+        Some(StatementKind::Return(_)) => true,
+        Some(StatementKind::If(_, s1, Some(s2))) => always_returns(s1) && always_returns(s2),
+        _ => false,
+    }
+}
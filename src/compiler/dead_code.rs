@@ -0,0 +1,90 @@
+use crate::ast::vm::*;
+
+use std::collections::{HashMap, HashSet};
+
+// The subroutines of a program that were kept and removed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeadCodeReport {
+    pub kept: usize,
+    pub removed: Vec<String>,
+}
+
+// Dead subroutine elimination:
+//  Keeps only the functions reachable from the entry function through `call`
+//  commands. A linked program holds the whole OS, most of which a given program
+//  never calls. Commands before the first function of a file are kept, and a
+//  program that does not define its entry function is left as it is.
+pub struct DeadCode;
+
+impl DeadCode {
+    pub fn eliminate(
+        files: Vec<(String, Vec<Command>)>,
+        entry: &str,
+    ) -> (Vec<(String, Vec<Command>)>, DeadCodeReport) {
+        let calls = call_graph(&files);
+        if !calls.contains_key(entry) {
+            let report = DeadCodeReport {
+                kept: calls.len(),
+                removed: Vec::new(),
+            };
+            return (files, report);
+        }
+
+        let mut reachable = HashSet::from([entry]);
+        let mut pending = vec![entry];
+        while let Some(function) = pending.pop() {
+            for callee in calls.get(function).into_iter().flatten() {
+                if reachable.insert(callee) {
+                    pending.push(callee);
+                }
+            }
+        }
+
+        let mut report = DeadCodeReport::default();
+        let files = files
+            .iter()
+            .filter_map(|(file_name, commands)| {
+                let mut kept = Vec::with_capacity(commands.len());
+                let mut keep = true;
+                for command in commands {
+                    if let Command::Function(Function::Body(name, _)) = command {
+                        keep = reachable.contains(name.as_str());
+                        match keep {
+                            true => report.kept += 1,
+                            false => report.removed.push(name.clone()),
+                        }
+                    }
+                    if keep {
+                        kept.push(command.clone());
+                    }
+                }
+                // A file whose functions were all removed is dropped with them
+                (!kept.is_empty()).then(|| (file_name.clone(), kept))
+            })
+            .collect();
+        (files, report)
+    }
+}
+
+// The functions each function calls
+pub(crate) fn call_graph(files: &[(String, Vec<Command>)]) -> HashMap<&str, Vec<&str>> {
+    let mut calls: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (_, commands) in files {
+        let mut function = None;
+        for command in commands {
+            match command {
+                Command::Function(Function::Body(name, _)) => {
+                    function = Some(name.as_str());
+                    calls.entry(name).or_default();
+                }
+                Command::Function(Function::Call(callee, _)) => {
+                    if let Some(function) = function {
+                        calls.entry(function).or_default().push(callee);
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+    calls
+}
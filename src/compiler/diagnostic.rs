@@ -0,0 +1,106 @@
+use crate::ast::span::Span;
+
+use chumsky::error::{Simple, SimpleReason};
+
+// Diagnostics reported by every stage of the pipeline. Parse errors from
+//  chumsky and semantic errors from later passes are converted into this
+//  type so that they can be rendered uniformly (see `pretty_printer::diagnostic`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub file: String,
+    pub span: Option<Span>,
+    pub message: String,
+    pub expected: Vec<String>,
+    pub notes: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn error(file: &str, span: Option<Span>, message: String) -> Self {
+        Self {
+            severity: Severity::Error,
+            file: file.to_string(),
+            span,
+            message,
+            expected: Vec::new(),
+            notes: Vec::new(),
+        }
+    }
+
+    pub fn warning(file: &str, span: Option<Span>, message: String) -> Self {
+        Self {
+            severity: Severity::Warning,
+            ..Self::error(file, span, message)
+        }
+    }
+
+    pub fn with_note(mut self, note: String) -> Self {
+        self.notes.push(note);
+        self
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+
+    // Errors raised while reading or writing files carry no span
+    pub fn from_io(file: &str, error: std::io::Error) -> Self {
+        Self::error(file, None, error.to_string())
+    }
+
+    // Convert a chumsky error whose inputs have already been shown as source text,
+    //  e.g. `error.map(|c| c.to_string())` for the lexer.
+    pub fn from_simple(file: &str, error: Simple<String, Span>) -> Self {
+        let quote = |input: &String| format!("`{}`", input);
+        Self::from_simple_with(file, error, quote, quote)
+    }
+
+    // Convert a chumsky error, showing the input that was found with `found`
+    //  and each input that was expected with `expected`. The two differ when
+    //  an expected input stands for a whole class of them, e.g. identifiers.
+    pub fn from_simple_with<I: std::hash::Hash + Eq>(
+        file: &str,
+        error: Simple<I, Span>,
+        found: impl Fn(&I) -> String,
+        expected: impl Fn(&I) -> String,
+    ) -> Self {
+        let found_input = error.found().map_or("end of input".to_string(), &found);
+
+        let mut expected_inputs: Vec<String> = error
+            .expected()
+            .map(|e| e.as_ref().map_or("end of input".to_string(), &expected))
+            .collect();
+        expected_inputs.sort();
+        expected_inputs.dedup();
+
+        let (message, notes) = match error.reason() {
+            SimpleReason::Unexpected => match error.label() {
+                Some(label) => (
+                    format!("unexpected {} while parsing {}", found_input, label),
+                    vec![],
+                ),
+                None => (format!("unexpected {}", found_input), vec![]),
+            },
+            SimpleReason::Unclosed { span, delimiter } => (
+                format!("unclosed delimiter {}", found(delimiter)),
+                vec![format!(
+                    "delimiter opened at {}:{}",
+                    span.start.line, span.start.column
+                )],
+            ),
+            SimpleReason::Custom(message) => (message.to_string(), vec![]),
+        };
+
+        Self {
+            expected: expected_inputs,
+            notes,
+            ..Self::error(file, Some(error.span()), message)
+        }
+    }
+}
@@ -0,0 +1,386 @@
+use crate::ast::vm::*;
+use crate::compiler::dead_code::call_graph;
+
+use std::collections::{HashMap, HashSet};
+
+// Which functions are inlined, and into which callers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InlineLimits {
+    // The most commands a function may have to be inlined, not counting its
+    //  `function` command
+    pub max_size: usize,
+    // Calls are no longer inlined into a function once it has grown to this
+    //  many commands
+    pub max_caller_size: usize,
+}
+
+impl Default for InlineLimits {
+    fn default() -> Self {
+        Self {
+            max_size: 12,
+            max_caller_size: 1000,
+        }
+    }
+}
+
+// The number of calls that were replaced by the body of the function
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InlineReport {
+    pub inlined: usize,
+}
+
+// Inliner:
+//  Replaces calls to small functions with their bodies. The arguments and
+//  locals of the callee become new locals of the caller, its labels are
+//  renamed, and its returns jump to the end of the inlined body with the
+//  return value on the stack, as a real return would leave it. A callee that
+//  sets THIS or THAT has the caller's pointers saved and restored around it,
+//  unless the caller sets them again before reading them.
+//
+//  Functions are only inlined when that cannot change what the program does:
+//  they may not be recursive, their statics must belong to the caller's file,
+//  and their stack must hold exactly the return value at each `return`.
+pub struct Inliner;
+
+// A function that can be inlined
+struct Callee {
+    file: usize,
+    // One more than the highest argument the body uses
+    n_args: u16,
+    n_locals: u16,
+    body: Vec<Command>,
+    uses_statics: bool,
+    sets_this: bool,
+    sets_that: bool,
+}
+
+impl Inliner {
+    pub fn inline(
+        files: Vec<(String, Vec<Command>)>,
+        limits: InlineLimits,
+    ) -> (Vec<(String, Vec<Command>)>, InlineReport) {
+        let callees = callees(&files, limits);
+        let mut report = InlineReport::default();
+        let mut labels = 0;
+        let files = files
+            .iter()
+            .enumerate()
+            .map(|(file, (file_name, commands))| {
+                // Hand-written VM code may already use the labels an inlined
+                //  body would be given
+                let declared: HashSet<&str> = commands
+                    .iter()
+                    .filter_map(|command| match command {
+                        Command::Branch(Branch::Label(label)) => Some(label.as_str()),
+                        _ => None,
+                    })
+                    .collect();
+                let mut inlined = Vec::with_capacity(commands.len());
+                // The index of the caller's `function` command and the first
+                //  of the locals that inlined bodies use
+                let mut caller = None;
+                for (i, command) in commands.iter().enumerate() {
+                    match command {
+                        Command::Function(Function::Body(_, n_locals)) => {
+                            caller = Some((inlined.len(), *n_locals));
+                        }
+                        Command::Function(Function::Call(name, n_args)) => {
+                            let callee = callees.get(name.as_str()).filter(|callee| {
+                                (!callee.uses_statics || callee.file == file)
+                                    && callee.n_args <= *n_args
+                            });
+                            let caller = caller.filter(|(start, _)| {
+                                inlined.len() - start < limits.max_caller_size
+                            });
+                            if let (Some(callee), Some((start, first_local))) = (callee, caller) {
+                                let rest = &commands[i + 1..];
+                                let restore = [
+                                    callee.sets_this && is_pointer_live(rest, 0),
+                                    callee.sets_that && is_pointer_live(rest, 1),
+                                ];
+                                while clashes(callee, labels, &declared) {
+                                    labels += 1;
+                                }
+                                let (body, n_locals) =
+                                    expand(callee, *n_args, restore, first_local, labels);
+                                inlined.extend(body);
+                                // Every inlined body starts at the same locals,
+                                //  which it no longer needs once it is done
+                                if let Command::Function(Function::Body(_, locals)) =
+                                    &mut inlined[start]
+                                {
+                                    *locals = (*locals).max(n_locals);
+                                }
+                                report.inlined += 1;
+                                labels += 1;
+                                continue;
+                            }
+                        }
+                        _ => (),
+                    }
+                    inlined.push(command.clone());
+                }
+                (file_name.clone(), inlined)
+            })
+            .collect();
+        (files, report)
+    }
+}
+
+// The functions that are small enough to inline and safe to inline anywhere
+fn callees(files: &[(String, Vec<Command>)], limits: InlineLimits) -> HashMap<&str, Callee> {
+    let calls = call_graph(files);
+    let mut callees = HashMap::new();
+    for (file, (_, commands)) in files.iter().enumerate() {
+        for (start, command) in commands.iter().enumerate() {
+            let Command::Function(Function::Body(name, n_locals)) = command else {
+                continue;
+            };
+            let body: Vec<Command> = commands[start + 1..]
+                .iter()
+                .take_while(|command| !matches!(command, Command::Function(Function::Body(..))))
+                .cloned()
+                .collect();
+            if body.len() > limits.max_size || is_recursive(&calls, name) {
+                continue;
+            }
+            let Some(n_args) = n_args(&body) else {
+                continue;
+            };
+            let uses_statics = body.iter().any(|command| {
+                matches!(
+                    command,
+                    Command::Stack(
+                        Stack::Push(Segment::Static, _) | Stack::Pop(Segment::Static, _)
+                    )
+                )
+            });
+            let pops = |index| body.contains(&Command::Stack(Stack::Pop(Segment::Pointer, index)));
+            callees.insert(
+                name.as_str(),
+                Callee {
+                    file,
+                    n_args,
+                    n_locals: *n_locals,
+                    uses_statics,
+                    sets_this: pops(0),
+                    sets_that: pops(1),
+                    body,
+                },
+            );
+        }
+    }
+    callees
+}
+
+// Whether a function can call itself, directly or through other functions
+fn is_recursive(calls: &HashMap<&str, Vec<&str>>, function: &str) -> bool {
+    let mut reached = HashSet::new();
+    let mut pending = vec![function];
+    while let Some(caller) = pending.pop() {
+        for callee in calls.get(caller).into_iter().flatten() {
+            if *callee == function {
+                return true;
+            }
+            if reached.insert(*callee) {
+                pending.push(callee);
+            }
+        }
+    }
+    false
+}
+
+// The number of arguments a function body uses, if it can be inlined. This
+//  follows the height of its stack: the body may not pop below where it
+//  started, and each `return` must find only the return value on the stack.
+//  Bodies whose height cannot be followed, or that never return, are not
+//  inlined.
+fn n_args(body: &[Command]) -> Option<u16> {
+    let mut heights: HashMap<&str, i32> = HashMap::new();
+    let mut height = Some(0);
+    let mut returns = false;
+    for command in body {
+        let h = match (command, height) {
+            (Command::Branch(Branch::Label(label)), _) => {
+                let h = match (height, heights.get(label.as_str())) {
+                    (Some(h), Some(&other)) if h != other => return None,
+                    (Some(h), _) | (None, Some(&h)) => h,
+                    (None, None) => return None,
+                };
+                heights.insert(label, h);
+                height = Some(h);
+                continue;
+            }
+            // Unreachable until the next label
+            (_, None) => continue,
+            (_, Some(h)) => h,
+        };
+        let (pops, pushes) = match command {
+            Command::Stack(Stack::Push(..)) => (0, 1),
+            Command::Stack(Stack::Pop(..)) => (1, 0),
+            Command::ACL(ACL::Arithmetic(Arithmetic::Neg) | ACL::Logical(Logical::Not)) => (1, 1),
+            Command::ACL(_) => (2, 1),
+            Command::Branch(Branch::IfGoto(_)) => (1, 0),
+            Command::Function(Function::Call(_, n)) => (*n as i32, 1),
+            Command::Function(Function::Return) => (1, 0),
+            _ => (0, 0),
+        };
+        if h < pops {
+            return None;
+        }
+        height = Some(h - pops + pushes);
+        match command {
+            Command::Branch(Branch::Goto(label) | Branch::IfGoto(label)) => {
+                let target = h - pops;
+                match heights.insert(label, target) {
+                    Some(other) if other != target => return None,
+                    _ => (),
+                }
+                if let Command::Branch(Branch::Goto(_)) = command {
+                    height = None;
+                }
+            }
+            Command::Function(Function::Return) => {
+                if h != 1 {
+                    return None;
+                }
+                returns = true;
+                height = None;
+            }
+            _ => (),
+        }
+    }
+    // Falling off the end of the body would run into the next function
+    if height.is_some() || !returns {
+        return None;
+    }
+    Some(
+        body.iter()
+            .filter_map(|command| match command {
+                Command::Stack(
+                    Stack::Push(Segment::Argument, i) | Stack::Pop(Segment::Argument, i),
+                ) => Some(i + 1),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0),
+    )
+}
+
+// Whether the caller may read pointer 0 or 1, or THIS or THAT through it,
+//  before setting it again. Anything past the straight-line code that follows
+//  the call is assumed to read it.
+fn is_pointer_live(rest: &[Command], pointer: u16) -> bool {
+    let segment = match pointer {
+        0 => Segment::This,
+        _ => Segment::That,
+    };
+    for command in rest {
+        match command {
+            Command::Stack(Stack::Pop(Segment::Pointer, i)) if *i == pointer => return false,
+            Command::Stack(Stack::Push(s, i) | Stack::Pop(s, i))
+                if *s == segment || (*s == Segment::Pointer && *i == pointer) =>
+            {
+                return true
+            }
+            Command::Stack(_) | Command::ACL(_) => (),
+            // A return restores the pointers of the function it returns to
+            Command::Function(Function::Return) => return false,
+            Command::Branch(_) | Command::Function(_) => return true,
+        }
+    }
+    true
+}
+
+// The labels of the `n`th inlined body. The end label has its own
+//  namespace, so a callee label named `END` cannot clash with it.
+fn inlined_label(n: usize, label: &str) -> String {
+    format!("INLINE{}_{}", n, label)
+}
+
+fn end_label(n: usize) -> String {
+    format!("INLINE{}$END", n)
+}
+
+// Whether the labels of the `n`th inlined body would clash with labels the
+//  caller's file already declares
+fn clashes(callee: &Callee, n: usize, declared: &HashSet<&str>) -> bool {
+    declared.contains(end_label(n).as_str())
+        || callee.body.iter().any(|command| match command {
+            Command::Branch(Branch::Label(label)) => {
+                declared.contains(inlined_label(n, label).as_str())
+            }
+            _ => false,
+        })
+}
+
+// The commands that replace a call with `n_args` arguments, with the number
+//  of locals the caller needs for them. `restore` tells whether pointers 0
+//  and 1 are restored after the body, `first_local` is the first local the
+//  caller does not use, and `n` numbers the inlined body's labels.
+fn expand(
+    callee: &Callee,
+    n_args: u16,
+    restore: [bool; 2],
+    first_local: u16,
+    n: usize,
+) -> (Vec<Command>, u16) {
+    let argument = |i: u16| first_local + i;
+    let local = |i: u16| first_local + n_args + i;
+    let mut n_locals = local(callee.n_locals);
+    let saved: Vec<(u16, u16)> = (0..2)
+        .filter(|pointer| restore[*pointer as usize])
+        .map(|pointer| {
+            n_locals += 1;
+            (pointer, n_locals - 1)
+        })
+        .collect();
+    let label = |label: &str| inlined_label(n, label);
+    let end = end_label(n);
+
+    let mut commands = Vec::new();
+    for i in (0..n_args).rev() {
+        commands.push(Command::Stack(Stack::Pop(Segment::Local, argument(i))));
+    }
+    for i in 0..callee.n_locals {
+        commands.push(Command::Stack(Stack::Push(Segment::Constant, 0)));
+        commands.push(Command::Stack(Stack::Pop(Segment::Local, local(i))));
+    }
+    for (pointer, saved) in &saved {
+        commands.push(Command::Stack(Stack::Push(Segment::Pointer, *pointer)));
+        commands.push(Command::Stack(Stack::Pop(Segment::Local, *saved)));
+    }
+    let remap = |segment: &Segment, i: u16| match segment {
+        Segment::Argument => (Segment::Local, argument(i)),
+        Segment::Local => (Segment::Local, local(i)),
+        _ => (segment.clone(), i),
+    };
+    let last = callee.body.len() - 1;
+    for (i, command) in callee.body.iter().enumerate() {
+        commands.push(match command {
+            Command::Stack(Stack::Push(segment, i)) => {
+                let (segment, i) = remap(segment, *i);
+                Command::Stack(Stack::Push(segment, i))
+            }
+            Command::Stack(Stack::Pop(segment, i)) => {
+                let (segment, i) = remap(segment, *i);
+                Command::Stack(Stack::Pop(segment, i))
+            }
+            Command::Branch(Branch::Label(l)) => Command::Branch(Branch::Label(label(l))),
+            Command::Branch(Branch::Goto(l)) => Command::Branch(Branch::Goto(label(l))),
+            Command::Branch(Branch::IfGoto(l)) => Command::Branch(Branch::IfGoto(label(l))),
+            // A return at the end of the body carries on with the caller
+            Command::Function(Function::Return) if i == last => continue,
+            Command::Function(Function::Return) => Command::Branch(Branch::Goto(end.clone())),
+            command => command.clone(),
+        });
+    }
+    if callee.body[..last].contains(&Command::Function(Function::Return)) {
+        commands.push(Command::Branch(Branch::Label(end)));
+    }
+    for (pointer, saved) in &saved {
+        commands.push(Command::Stack(Stack::Push(Segment::Local, *saved)));
+        commands.push(Command::Stack(Stack::Pop(Segment::Pointer, *pointer)));
+    }
+    (commands, n_locals)
+}
@@ -1,12 +1,19 @@
 use crate::ast::jack::*;
+use crate::ast::span::Span;
 use crate::ast::vm::*;
+use crate::compiler::symbol_table::SymbolTable;
 
 use std::collections::HashMap;
 use std::mem::take;
 
-pub struct JackToVm {
-    file_name: String,
+// Calls are resolved against the table of every class in the program: a
+//  method is called with its receiver as the first argument, while functions
+//  and constructors are not. Classes missing from the table, such as the OS
+//  when it is not linked, are assumed to be called correctly, as functions
+//  through their class name and as methods through a variable.
+pub struct JackToVm<'a> {
     class_name: String,
+    table: &'a SymbolTable,
     global_ctx: HashMap<String, Var>,
     local_ctx: HashMap<String, Var>,
     global_kind_counts: (u16, u16),
@@ -14,13 +21,22 @@ pub struct JackToVm {
     if_count: u16,
     while_count: u16,
     instruction_stack: Vec<Command>,
+    // The statement being compiled, and the one each command came from
+    span: Span,
+    spans: Vec<Span>,
 }
 
-impl JackToVm {
-    pub fn compile(file_name: String, class: Class) -> Vec<Command> {
+impl<'a> JackToVm<'a> {
+    pub fn compile(class: Class, table: &'a SymbolTable) -> Vec<Command> {
+        Self::compile_with_spans(class, table).0
+    }
+
+    // Also returns the span of the statement each command was compiled
+    //  from. The code setting up a subroutine comes from its declaration.
+    pub fn compile_with_spans(class: Class, table: &'a SymbolTable) -> (Vec<Command>, Vec<Span>) {
         let mut compiler = Self {
-            file_name,
             class_name: class.class_name,
+            table,
             global_ctx: HashMap::new(),
             local_ctx: HashMap::new(),
             global_kind_counts: (0, 0), // (field, static)
@@ -28,9 +44,11 @@ impl JackToVm {
             if_count: 0,
             while_count: 0,
             instruction_stack: Vec::new(),
+            span: Span::default(),
+            spans: Vec::new(),
         };
         let ins = compiler.compile_class_dec(class.class_dec);
-        take(&mut ins.instruction_stack)
+        (take(&mut ins.instruction_stack), take(&mut ins.spans))
     }
 
     // Methods to modify `Compiler`
@@ -79,6 +97,7 @@ impl JackToVm {
 
     fn push(&mut self, command: Command) -> &mut Self {
         self.instruction_stack.push(command);
+        self.spans.push(self.span);
         self
     }
 
@@ -172,7 +191,7 @@ impl JackToVm {
             self.compile_var_dec(var_dec);
         });
         self.push_func(Function::Body(
-            format!("{}.{}", self.file_name, subroutine_name),
+            format!("{}.{}", self.class_name, subroutine_name),
             local_length,
         ));
         if bool {
@@ -185,6 +204,7 @@ impl JackToVm {
 
     fn compile_subroutine_dec(&mut self, subroutine_dec: SubroutineDec) -> &mut Self {
         self.reset_local();
+        self.span = subroutine_dec.span;
         match subroutine_dec.subroutine_type {
             SubroutineType::Method => {
                 self.insert_local(
@@ -230,6 +250,16 @@ impl JackToVm {
         self
     }
 
+    // The statements nested in the statement at `span`, whose remaining
+    //  commands come from it again
+    fn compile_statements(&mut self, stmts: Vec<Statement>, span: Span) -> &mut Self {
+        stmts.into_iter().for_each(|stmt| {
+            self.compile_statement(stmt);
+        });
+        self.span = span;
+        self
+    }
+
     fn compile_parameter_list(&mut self, parameter_list: Vec<Parameter>) -> &mut Self {
         parameter_list.into_iter().for_each(|parameter| {
             self.insert_local(parameter.var_name, parameter.r#type, LocalKind::Arg);
@@ -245,17 +275,19 @@ impl JackToVm {
     }
 
     fn compile_statement(&mut self, statement: Statement) -> &mut Self {
-        match statement {
-            Statement::Return(or) => match or {
+        let span = statement.span;
+        self.span = span;
+        match statement.kind {
+            StatementKind::Return(or) => match or {
                 Some(r) => self.compile_expression(r).push_func(Function::Return),
                 None => self
                     .push_stack(Stack::Push(Segment::Constant, 0))
                     .push_func(Function::Return),
             },
-            Statement::Do(sc) => self
+            StatementKind::Do(sc) => self
                 .compile_subroutine_call(sc)
                 .push_stack(Stack::Pop(Segment::Temp, 0)),
-            Statement::Let(ident, array, e) => {
+            StatementKind::Let(ident, array, e) => {
                 let var = self.lookup(&ident).expect("Variable not in context");
                 let var_segment = var_kind_to_segment(var.var_kind.clone());
                 let index = var.index;
@@ -274,99 +306,83 @@ impl JackToVm {
                         .push_stack(Stack::Pop(Segment::That, 0)),
                 }
             }
-            Statement::While(expr, stmts) => {
+            StatementKind::While(expr, stmts) => {
                 let label = self.while_label();
                 self.push_branch(Branch::Label(format!("WHILE_EXP{}", label)))
                     .compile_expression(expr)
                     .compile_unary_op(UnaryOp::Tilde)
-                    .push_branch(Branch::IfGoto(format!("WHILE_END{}", label)));
-
-                stmts.into_iter().for_each(|stmt| {
-                    self.compile_statement(stmt);
-                });
-
-                self.push_branch(Branch::Goto(format!("WHILE_EXP{}", label)))
+                    .push_branch(Branch::IfGoto(format!("WHILE_END{}", label)))
+                    .compile_statements(stmts, span)
+                    .push_branch(Branch::Goto(format!("WHILE_EXP{}", label)))
                     .push_branch(Branch::Label(format!("WHILE_END{}", label)))
             }
-            Statement::If(e, s1, o_s2) => {
+            StatementKind::If(e, s1, o_s2) => {
                 self.compile_expression(e);
                 let label = self.if_label();
                 self.push_branch(Branch::IfGoto(format!("IF_TRUE{}", label)))
                     .push_branch(Branch::Goto(format!("IF_FALSE{}", label)))
-                    .push_branch(Branch::Label(format!("IF_TRUE{}", label)));
-
-                s1.into_iter().for_each(|stmt| {
-                    self.compile_statement(stmt);
-                });
+                    .push_branch(Branch::Label(format!("IF_TRUE{}", label)))
+                    .compile_statements(s1, span);
 
                 match o_s2 {
                     None => self.push_branch(Branch::Label(format!("IF_FALSE{}", label))),
-                    Some(s2) => {
-                        self.push_branch(Branch::Goto(format!("IF_END{}", label)))
-                            .push_branch(Branch::Label(format!("IF_FALSE{}", label)));
-                        s2.into_iter().for_each(|stmt| {
-                            self.compile_statement(stmt);
-                        });
-                        self.push_branch(Branch::Label(format!("IF_END{}", label)))
-                    }
+                    Some(s2) => self
+                        .push_branch(Branch::Goto(format!("IF_END{}", label)))
+                        .push_branch(Branch::Label(format!("IF_FALSE{}", label)))
+                        .compile_statements(s2, span)
+                        .push_branch(Branch::Label(format!("IF_END{}", label))),
                 }
             }
         }
     }
 
     fn compile_subroutine_call(&mut self, subroutine_call: SubroutineCall) -> &mut Self {
-        match subroutine_call {
-            SubroutineCall::Call(subroutine_name, exprs) => {
-                self.push_stack(Stack::Push(Segment::Pointer, 0));
-                exprs.into_iter().for_each(|expr| {
-                    self.compile_expression(*expr);
-                });
-                self.push_func(Function::Call(
-                    format!("{}.{}", self.class_name, subroutine_name),
-                    1,
-                ))
-            }
+        let (class_name, subroutine_name, exprs, receiver) = match subroutine_call {
+            SubroutineCall::Call(subroutine_name, exprs) => (
+                self.class_name.to_string(),
+                subroutine_name,
+                exprs,
+                Some(Stack::Push(Segment::Pointer, 0)),
+            ),
             SubroutineCall::ClassCall(name, subroutine_name, exprs) => match self.lookup(&name) {
                 Some(Var {
-                    r#type: Type::ClassName(c),
+                    r#type: Type::ClassName(class_name),
                     var_kind,
                     index,
                 }) => {
-                    let class_name = c.clone();
-
-                    let segment = match var_kind {
-                        VarKind::Local(LocalKind::Var) => Segment::Local,
-                        _ => Segment::This,
-                    };
-
-                    self.push_stack(Stack::Push(segment, *index));
-
-                    let mut exprs_length = 1;
-
-                    exprs.into_iter().for_each(|expr| {
-                        self.compile_expression(*expr);
-                        exprs_length += 1;
-                    });
-
-                    self.push_func(Function::Call(
-                        format!("{}.{}", class_name, subroutine_name),
-                        exprs_length,
-                    ))
-                }
-
-                _ => {
-                    let mut exprs_length = 0;
-                    exprs.into_iter().for_each(|expr| {
-                        self.compile_expression(*expr);
-                        exprs_length += 1;
-                    });
-                    self.push_func(Function::Call(
-                        format!("{}.{}", name, subroutine_name),
-                        exprs_length,
-                    ))
+                    let receiver = Stack::Push(var_kind_to_segment(var_kind.clone()), *index);
+                    (
+                        class_name.to_string(),
+                        subroutine_name,
+                        exprs,
+                        Some(receiver),
+                    )
                 }
+                _ => (name, subroutine_name, exprs, None),
+            },
+        };
+        // The table decides, where it knows the subroutine, whether there is
+        //  a receiver to pass
+        let receiver = match self.table.lookup_subroutine(&class_name, &subroutine_name) {
+            Some(signature) => match signature.subroutine_type {
+                SubroutineType::Method => receiver,
+                SubroutineType::Function | SubroutineType::Constructor => None,
             },
+            None => receiver,
+        };
+        let mut n_args = 0;
+        if let Some(receiver) = receiver {
+            self.push_stack(receiver);
+            n_args += 1;
         }
+        exprs.into_iter().for_each(|expr| {
+            self.compile_expression(expr);
+            n_args += 1;
+        });
+        self.push_func(Function::Call(
+            format!("{}.{}", class_name, subroutine_name),
+            n_args,
+        ))
     }
 
     fn compile_expression(&mut self, expression: Expression) -> &mut Self {
@@ -378,11 +394,11 @@ impl JackToVm {
     }
 
     fn compile_term(&mut self, term: Term) -> &mut Self {
-        match term {
-            Term::IntegerConstant(i) => {
+        match term.kind {
+            TermKind::IntegerConstant(i) => {
                 self.push_stack(Stack::Push(Segment::Constant, i.try_into().unwrap()))
             }
-            Term::StringConstant(s) => {
+            TermKind::StringConstant(s) => {
                 let s_length: u16 = s.len().try_into().unwrap();
                 self.push_stack(Stack::Push(Segment::Constant, s_length))
                     .push_func(Function::Call("String.new".to_string(), 1));
@@ -393,8 +409,8 @@ impl JackToVm {
                 });
                 self
             }
-            Term::KeywordConstant(kw) => self.compile_keyword_constant(kw),
-            Term::VarName(s, oe) => {
+            TermKind::KeywordConstant(kw) => self.compile_keyword_constant(kw),
+            TermKind::VarName(s, oe) => {
                 let o_var = self.lookup(&s);
                 match o_var {
                     None => self,
@@ -416,9 +432,9 @@ impl JackToVm {
                     }
                 }
             }
-            Term::Unary(uop, t) => self.compile_term(*t).compile_unary_op(uop),
-            Term::ParensExpr(e) => self.compile_expression(*e),
-            Term::SubroutineCall(sc) => self.compile_subroutine_call(sc),
+            TermKind::Unary(uop, t) => self.compile_term(*t).compile_unary_op(uop),
+            TermKind::ParensExpr(e) => self.compile_expression(*e),
+            TermKind::SubroutineCall(sc) => self.compile_subroutine_call(sc),
         }
     }
 
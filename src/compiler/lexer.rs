@@ -1,16 +1,21 @@
+use crate::ast::span::*;
 use crate::ast::token::*;
 
 use chumsky::prelude::*;
 
 // Lexer:
-pub fn tokenize() -> impl Parser<char, Vec<Token>, Error = Simple<char>> {
+//  The whole input must lex, so that a bad token is reported where it is
+//  rather than cutting the tokens short.
+pub fn tokenize() -> impl Parser<char, Vec<Spanned<Token>>, Error = Simple<char, Span>> {
     parse_comment()
         .repeated()
         .ignore_then(parse_token())
         .repeated()
+        .then_ignore(parse_comment().repeated())
+        .then_ignore(end())
 }
 
-fn parse_comment() -> impl Parser<char, (), Error = Simple<char>> {
+fn parse_comment() -> impl Parser<char, (), Error = Simple<char, Span>> {
     let single_line_comment = just("//")
         .then_ignore(filter(|&c| c != '\n').repeated())
         .padded();
@@ -22,7 +27,9 @@ fn parse_comment() -> impl Parser<char, (), Error = Simple<char>> {
     choice((single_line_comment, multi_line_comment, api_comment)).ignored()
 }
 
-fn parse_token() -> impl Parser<char, Token, Error = Simple<char>> {
+// Tokens are padded here rather than in each parser below, so that the
+//  span covers the token itself and not the surrounding whitespace.
+fn parse_token() -> impl Parser<char, Spanned<Token>, Error = Simple<char, Span>> {
     choice((
         parse_keyword().map(Token::Keyword),
         parse_symbol().map(Token::Symbol),
@@ -30,36 +37,39 @@ fn parse_token() -> impl Parser<char, Token, Error = Simple<char>> {
         parse_string().map(Token::String),
         parse_identifier().map(Token::Identifier),
     ))
+    .map_with_span(|token, span| (token, span))
+    .padded()
 }
 
-fn parse_keyword() -> impl Parser<char, Keyword, Error = Simple<char>> {
+// Keywords must be whole words, so that `charAt` or `doubleQuote` are lexed
+//  as identifiers rather than a keyword followed by an identifier
+fn parse_keyword() -> impl Parser<char, Keyword, Error = Simple<char, Span>> {
     choice((
-        just("class").to(Keyword::Class),
-        just("constructor").to(Keyword::Constructor),
-        just("function").to(Keyword::Function),
-        just("method").to(Keyword::Method),
-        just("field").to(Keyword::Field),
-        just("static").to(Keyword::Static),
-        just("var").to(Keyword::Var),
-        just("int").to(Keyword::Int),
-        just("char").to(Keyword::Char),
-        just("boolean").to(Keyword::Boolean),
-        just("void").to(Keyword::Void),
-        just("true").to(Keyword::True),
-        just("false").to(Keyword::False),
-        just("null").to(Keyword::Null),
-        just("this").to(Keyword::This),
-        just("let").to(Keyword::Let),
-        just("do").to(Keyword::Do),
-        just("if").to(Keyword::If),
-        just("else").to(Keyword::Else),
-        just("while").to(Keyword::While),
-        just("return").to(Keyword::Return),
+        text::keyword("class").to(Keyword::Class),
+        text::keyword("constructor").to(Keyword::Constructor),
+        text::keyword("function").to(Keyword::Function),
+        text::keyword("method").to(Keyword::Method),
+        text::keyword("field").to(Keyword::Field),
+        text::keyword("static").to(Keyword::Static),
+        text::keyword("var").to(Keyword::Var),
+        text::keyword("int").to(Keyword::Int),
+        text::keyword("char").to(Keyword::Char),
+        text::keyword("boolean").to(Keyword::Boolean),
+        text::keyword("void").to(Keyword::Void),
+        text::keyword("true").to(Keyword::True),
+        text::keyword("false").to(Keyword::False),
+        text::keyword("null").to(Keyword::Null),
+        text::keyword("this").to(Keyword::This),
+        text::keyword("let").to(Keyword::Let),
+        text::keyword("do").to(Keyword::Do),
+        text::keyword("if").to(Keyword::If),
+        text::keyword("else").to(Keyword::Else),
+        text::keyword("while").to(Keyword::While),
+        text::keyword("return").to(Keyword::Return),
     ))
-    .padded()
 }
 
-fn parse_symbol() -> impl Parser<char, Symbol, Error = Simple<char>> {
+fn parse_symbol() -> impl Parser<char, Symbol, Error = Simple<char, Span>> {
     choice((
         just('{').to(Symbol::LCurly),
         just('}').to(Symbol::RCurly),
@@ -81,29 +91,27 @@ fn parse_symbol() -> impl Parser<char, Symbol, Error = Simple<char>> {
         just('=').to(Symbol::Equal),
         just('~').to(Symbol::Tilde),
     ))
-    .padded()
 }
 
-fn parse_num() -> impl Parser<char, i16, Error = Simple<char>> {
-    text::int(10)
-        .map(|c: String| c.parse::<i16>().unwrap())
-        .padded()
+fn parse_num() -> impl Parser<char, i16, Error = Simple<char, Span>> {
+    text::int(10).try_map(|c: String, span| {
+        c.parse::<i16>()
+            .map_err(|_| Simple::custom(span, "integer constant must be in 0..=32767".to_string()))
+    })
 }
 
-fn parse_string() -> impl Parser<char, String, Error = Simple<char>> {
+fn parse_string() -> impl Parser<char, String, Error = Simple<char, Span>> {
     let valid_char = filter(|&c: &char| c != '"' && c != '\n');
     valid_char
         .repeated()
         .delimited_by('"', '"')
         .collect::<String>()
-        .padded()
 }
 
-fn parse_identifier() -> impl Parser<char, String, Error = Simple<char>> {
+fn parse_identifier() -> impl Parser<char, String, Error = Simple<char, Span>> {
     let starting_char = filter(|&c: &char| c.is_alphabetic() || c == '_');
     let follow_char = filter(|&c: &char| c.is_alphanumeric() || c == '_');
     starting_char
         .chain(follow_char.repeated())
         .collect::<String>()
-        .padded()
 }
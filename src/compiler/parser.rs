@@ -1,32 +1,53 @@
 use crate::ast::jack::*;
+use crate::ast::span::*;
 use crate::ast::token::*;
 use chumsky::prelude::*;
 
 // Token helper functions:
-fn kw(expected: Keyword) -> impl Parser<Token, (), Error = Simple<Token>> {
+fn kw(expected: Keyword) -> impl Parser<Token, (), Error = Simple<Token, Span>> {
     just(Token::Keyword(expected)).ignored()
 }
 
-fn sym(expected: Symbol) -> impl Parser<Token, (), Error = Simple<Token>> {
+fn sym(expected: Symbol) -> impl Parser<Token, (), Error = Simple<Token, Span>> {
     just(Token::Symbol(expected)).ignored()
 }
 
-fn ident() -> impl Parser<Token, String, Error = Simple<Token>> {
-    select! {
-        Token::Identifier(s) => s,
-    }
+// The tokens of a kind are expected as one placeholder token, which
+//  diagnostics name by its kind
+fn expecting<O>(
+    parser: impl Parser<Token, O, Error = Simple<Token, Span>>,
+    placeholder: Token,
+) -> impl Parser<Token, O, Error = Simple<Token, Span>> {
+    parser.map_err(move |e: Simple<Token, Span>| {
+        Simple::expected_input_found(e.span(), [Some(placeholder.clone())], e.found().cloned())
+    })
+}
+
+fn ident() -> impl Parser<Token, String, Error = Simple<Token, Span>> {
+    expecting(
+        select! {
+            Token::Identifier(s) => s,
+        },
+        Token::Identifier(String::new()),
+    )
 }
 
-fn int_const() -> impl Parser<Token, i16, Error = Simple<Token>> {
-    select! {
-        Token::Integer(i) => i,
-    }
+fn int_const() -> impl Parser<Token, i16, Error = Simple<Token, Span>> {
+    expecting(
+        select! {
+            Token::Integer(i) => i,
+        },
+        Token::Integer(0),
+    )
 }
 
-fn string_const() -> impl Parser<Token, String, Error = Simple<Token>> {
-    select! {
-        Token::String(s) => s,
-    }
+fn string_const() -> impl Parser<Token, String, Error = Simple<Token, Span>> {
+    expecting(
+        select! {
+            Token::String(s) => s,
+        },
+        Token::String(String::new()),
+    )
 }
 
 // Parser specification notation (section 10.2.1):
@@ -42,22 +63,23 @@ fn string_const() -> impl Parser<Token, String, Error = Simple<Token>> {
 
 // parse_class:
 //  'class' [class_name] '{' [class_dec] '}'
-pub fn parse_class() -> impl Parser<Token, Class, Error = Simple<Token>> {
+pub fn parse_class() -> impl Parser<Token, Class, Error = Simple<Token, Span>> {
     kw(Keyword::Class)
         .ignore_then(ident())
         .then_ignore(sym(Symbol::LCurly))
         .then(parse_class_dec())
         .then_ignore(sym(Symbol::RCurly))
-        .map(|(class_name, class_dec)| Class {
+        .map_with_span(|(class_name, class_dec), span| Class {
             class_name,
             class_dec,
+            span,
         })
         .labelled("class")
 }
 
 // parse_class_dec:
 //  [class_var_dec]* [subroutine_dec]*
-fn parse_class_dec() -> impl Parser<Token, ClassDec, Error = Simple<Token>> {
+fn parse_class_dec() -> impl Parser<Token, ClassDec, Error = Simple<Token, Span>> {
     parse_class_var_dec()
         .repeated()
         .then(parse_subroutine_dec().repeated())
@@ -70,18 +92,23 @@ fn parse_class_dec() -> impl Parser<Token, ClassDec, Error = Simple<Token>> {
 
 // parse_class_var_dec:
 //  [class_var_type] [type] [var_name] (',' [var_name])* ';'
-fn parse_class_var_dec() -> impl Parser<Token, ClassVarDec, Error = Simple<Token>> {
+fn parse_class_var_dec() -> impl Parser<Token, ClassVarDec, Error = Simple<Token, Span>> {
     parse_kind()
         .then(parse_type())
         .then(ident().separated_by(sym(Symbol::Comma)))
         .then_ignore(sym(Symbol::Semicolon))
-        .map(|((kind, r#type), vars)| ClassVarDec { kind, r#type, vars })
+        .map_with_span(|((kind, r#type), vars), span| ClassVarDec {
+            kind,
+            r#type,
+            vars,
+            span,
+        })
         .labelled("class variable declaration")
 }
 
 // parse_kind:
 //  ('static' | 'field')
-fn parse_kind() -> impl Parser<Token, Kind, Error = Simple<Token>> {
+fn parse_kind() -> impl Parser<Token, Kind, Error = Simple<Token, Span>> {
     choice((
         kw(Keyword::Static).to(Kind::Static),
         kw(Keyword::Field).to(Kind::Field),
@@ -91,7 +118,7 @@ fn parse_kind() -> impl Parser<Token, Kind, Error = Simple<Token>> {
 
 // parse_subroutine_dec:
 //  [subroutine_type] [subroutine_return_type] [subroutine_name] '(' [parameter_list] ')' [subroutine_body]
-fn parse_subroutine_dec() -> impl Parser<Token, SubroutineDec, Error = Simple<Token>> {
+fn parse_subroutine_dec() -> impl Parser<Token, SubroutineDec, Error = Simple<Token, Span>> {
     parse_subroutine_type()
         .then(parse_subroutine_return_type())
         .then(ident())
@@ -99,16 +126,18 @@ fn parse_subroutine_dec() -> impl Parser<Token, SubroutineDec, Error = Simple<To
         .then(parse_parameter_list())
         .then_ignore(sym(Symbol::RParens))
         .then(parse_subroutine_body())
-        .map(
+        .map_with_span(
             |(
                 (((subroutine_type, subroutine_return_type), subroutine_name), parameter_list),
                 subroutine_body,
-            )| SubroutineDec {
+            ),
+             span| SubroutineDec {
                 subroutine_type,
                 subroutine_return_type,
                 subroutine_name,
                 parameter_list,
                 subroutine_body,
+                span,
             },
         )
         .labelled("subroutine declaration")
@@ -116,7 +145,7 @@ fn parse_subroutine_dec() -> impl Parser<Token, SubroutineDec, Error = Simple<To
 
 // parse_subroutine_type:
 //  ('constructor' | 'function' | 'method')
-fn parse_subroutine_type() -> impl Parser<Token, SubroutineType, Error = Simple<Token>> {
+fn parse_subroutine_type() -> impl Parser<Token, SubroutineType, Error = Simple<Token, Span>> {
     choice((
         kw(Keyword::Constructor).to(SubroutineType::Constructor),
         kw(Keyword::Function).to(SubroutineType::Function),
@@ -127,8 +156,8 @@ fn parse_subroutine_type() -> impl Parser<Token, SubroutineType, Error = Simple<
 
 // parse_subroutine_return_type:
 //  ('void' | [type])
-fn parse_subroutine_return_type() -> impl Parser<Token, SubroutineReturnType, Error = Simple<Token>>
-{
+fn parse_subroutine_return_type(
+) -> impl Parser<Token, SubroutineReturnType, Error = Simple<Token, Span>> {
     choice((
         kw(Keyword::Void).to(SubroutineReturnType::Void),
         parse_type().map(SubroutineReturnType::Type),
@@ -138,17 +167,21 @@ fn parse_subroutine_return_type() -> impl Parser<Token, SubroutineReturnType, Er
 
 // parse_parameter_list:
 //  (([type] ([var_name]) (',' [type] [var_name])*))?
-fn parse_parameter_list() -> impl Parser<Token, Vec<Parameter>, Error = Simple<Token>> {
+fn parse_parameter_list() -> impl Parser<Token, Vec<Parameter>, Error = Simple<Token, Span>> {
     (parse_type()
         .then(ident())
-        .map(|(r#type, var_name)| Parameter { r#type, var_name }))
+        .map_with_span(|(r#type, var_name), span| Parameter {
+            r#type,
+            var_name,
+            span,
+        }))
     .separated_by(sym(Symbol::Comma))
     .labelled("parameter list")
 }
 
 // parse_subroutine_body:
 //  '{' [var_dec]* [statement]* '}'
-fn parse_subroutine_body() -> impl Parser<Token, SubroutineBody, Error = Simple<Token>> {
+fn parse_subroutine_body() -> impl Parser<Token, SubroutineBody, Error = Simple<Token, Span>> {
     sym(Symbol::LCurly)
         .ignore_then(parse_var_dec().repeated())
         .then(parse_statement().repeated())
@@ -159,12 +192,16 @@ fn parse_subroutine_body() -> impl Parser<Token, SubroutineBody, Error = Simple<
 
 // parse_var_dec:
 //  'var' [type] ([var_name] (','  [var_name])*) ';'
-fn parse_var_dec() -> impl Parser<Token, VarDec, Error = Simple<Token>> {
+fn parse_var_dec() -> impl Parser<Token, VarDec, Error = Simple<Token, Span>> {
     kw(Keyword::Var)
         .ignore_then(parse_type())
         .then(ident().separated_by(sym(Symbol::Comma)))
         .then_ignore(sym(Symbol::Semicolon))
-        .map(|(r#type, var_name)| VarDec { r#type, var_name })
+        .map_with_span(|(r#type, var_name), span| VarDec {
+            r#type,
+            var_name,
+            span,
+        })
         .labelled("variable declaration")
 }
 
@@ -172,7 +209,7 @@ fn parse_var_dec() -> impl Parser<Token, VarDec, Error = Simple<Token>> {
 
 // parse_statement:
 //  [let_statement] | [if_statement] | [while_statement] | [do_statement] | [return_statement]
-fn parse_statement() -> impl Parser<Token, Statement, Error = Simple<Token>> {
+fn parse_statement() -> impl Parser<Token, Statement, Error = Simple<Token, Span>> {
     recursive(|statement| {
         choice((
             parse_let_statement(),
@@ -181,13 +218,22 @@ fn parse_statement() -> impl Parser<Token, Statement, Error = Simple<Token>> {
             parse_do_statement(),
             parse_return_statement(),
         ))
+        .map_with_span(|kind, span| Statement { kind, span })
+        // On a malformed statement, skip ahead to the next statement that parses
+        //  so that later errors in the same subroutine are reported too.
+        .recover_with(skip_then_retry_until([
+            Token::Symbol(Symbol::RCurly),
+            Token::Keyword(Keyword::Function),
+            Token::Keyword(Keyword::Method),
+            Token::Keyword(Keyword::Constructor),
+        ]))
         .labelled("statement")
     })
 }
 
 // parse_let_statement:
 //  'let' [var_name] ('[' [expression] ']')? '=' [expression] ';'
-fn parse_let_statement() -> impl Parser<Token, Statement, Error = Simple<Token>> {
+fn parse_let_statement() -> impl Parser<Token, StatementKind, Error = Simple<Token, Span>> {
     kw(Keyword::Let)
         .ignore_then(ident())
         .then(
@@ -200,16 +246,16 @@ fn parse_let_statement() -> impl Parser<Token, Statement, Error = Simple<Token>>
         .then(parse_expression())
         .then_ignore(sym(Symbol::Semicolon))
         .map(|((var_name, option_expression), expression)| {
-            Statement::Let(var_name, option_expression, expression)
+            StatementKind::Let(var_name, option_expression, expression)
         })
         .labelled("let statement")
 }
 
 // parse_if_statement:
 //  'if' '(' [expression] ')' '{' [statement]* '}' ('else' '{' [statement]* '}')?
-fn parse_if_statement<P: Parser<Token, Statement, Error = Simple<Token>> + Clone>(
+fn parse_if_statement<P: Parser<Token, Statement, Error = Simple<Token, Span>> + Clone>(
     statement: P,
-) -> impl Parser<Token, Statement, Error = Simple<Token>> {
+) -> impl Parser<Token, StatementKind, Error = Simple<Token, Span>> {
     kw(Keyword::If)
         .ignore_then(
             sym(Symbol::LParens)
@@ -230,15 +276,15 @@ fn parse_if_statement<P: Parser<Token, Statement, Error = Simple<Token>> + Clone
                 )
                 .or_not(),
         )
-        .map(|((cond, then), else_opt)| Statement::If(cond, then, else_opt))
+        .map(|((cond, then), else_opt)| StatementKind::If(cond, then, else_opt))
         .labelled("if statement")
 }
 
 // parse_while_statement:
 //  'while' '(' [expression] ')' '{' [statement]* '}'
 fn parse_while_statement(
-    statement: impl Parser<Token, Statement, Error = Simple<Token>>,
-) -> impl Parser<Token, Statement, Error = Simple<Token>> {
+    statement: impl Parser<Token, Statement, Error = Simple<Token, Span>>,
+) -> impl Parser<Token, StatementKind, Error = Simple<Token, Span>> {
     kw(Keyword::While)
         .ignore_then(
             sym(Symbol::LParens)
@@ -250,27 +296,27 @@ fn parse_while_statement(
                 .ignore_then(statement.repeated())
                 .then_ignore(sym(Symbol::RCurly)),
         )
-        .map(|(e, s)| Statement::While(e, s))
+        .map(|(e, s)| StatementKind::While(e, s))
         .labelled("while statement")
 }
 
 // parse_do_statement:
 //  'do' [subroutine_call] ';'
-fn parse_do_statement() -> impl Parser<Token, Statement, Error = Simple<Token>> {
+fn parse_do_statement() -> impl Parser<Token, StatementKind, Error = Simple<Token, Span>> {
     kw(Keyword::Do)
         .ignore_then(parse_subroutine_call())
         .then_ignore(sym(Symbol::Semicolon))
-        .map(Statement::Do)
+        .map(StatementKind::Do)
         .labelled("do statement")
 }
 
 // parse_return_statement:
 //  'return' [expression]? ';'
-fn parse_return_statement() -> impl Parser<Token, Statement, Error = Simple<Token>> {
+fn parse_return_statement() -> impl Parser<Token, StatementKind, Error = Simple<Token, Span>> {
     kw(Keyword::Return)
         .ignore_then(parse_expression().or_not())
         .then_ignore(sym(Symbol::Semicolon))
-        .map(Statement::Return)
+        .map(StatementKind::Return)
         .labelled("return statement")
 }
 
@@ -279,7 +325,7 @@ fn parse_return_statement() -> impl Parser<Token, Statement, Error = Simple<Toke
 // neede fixing
 // parse_expression:
 //  [term] ([binary_op] [term])*
-pub fn parse_expression() -> impl Parser<Token, Expression, Error = Simple<Token>> {
+pub fn parse_expression() -> impl Parser<Token, Expression, Error = Simple<Token, Span>> {
     recursive(|expr| {
         parse_term(expr.clone())
             .map(Box::new)
@@ -288,7 +334,7 @@ pub fn parse_expression() -> impl Parser<Token, Expression, Error = Simple<Token
                     .then(parse_term(expr).map(Box::new))
                     .repeated(),
             )
-            .map(|(term, bin)| Expression { term, bin })
+            .map_with_span(|(term, bin), span| Expression { term, bin, span })
             .labelled("expression")
     })
     .boxed()
@@ -298,32 +344,32 @@ pub fn parse_expression() -> impl Parser<Token, Expression, Error = Simple<Token
 //  [integer_constant] | [string_constant] | [keyword_constant] | [var_name] ('[' [expression] ']')?
 //  | '(' [expression] ')' | ([unary_op] [term]) | [subroutine_call]
 pub fn parse_term<'a>(
-    expr: Recursive<'a, Token, Expression, Simple<Token>>,
-) -> impl Parser<Token, Term, Error = Simple<Token>> + 'a {
+    expr: Recursive<'a, Token, Expression, Simple<Token, Span>>,
+) -> impl Parser<Token, Term, Error = Simple<Token, Span>> + 'a {
     recursive(|term| {
-        let int_const = int_const().map(Term::IntegerConstant);
-        let string_const = string_const().map(Term::StringConstant);
-        let keyword_const = parse_keyword_constant().map(Term::KeywordConstant);
+        let int_const = int_const().map(TermKind::IntegerConstant);
+        let string_const = string_const().map(TermKind::StringConstant);
+        let keyword_const = parse_keyword_constant().map(TermKind::KeywordConstant);
         let unary_term = parse_unary_op()
             .then(Box::new(term))
-            .map(|(u, t)| Term::Unary(u, Box::new(t)));
+            .map(|(u, t)| TermKind::Unary(u, Box::new(t)));
         let parens_expr = sym(Symbol::LParens)
             .ignore_then(expr.clone().map(Box::new))
             .then_ignore(sym(Symbol::RParens))
-            .map(Term::ParensExpr);
+            .map(TermKind::ParensExpr);
 
         let var_or_subroutine = ident()
             .then_ignore(sym(Symbol::Period))
             .then(ident())
             .then_ignore(sym(Symbol::LParens))
-            .then(expr.clone().map(Box::new).separated_by(sym(Symbol::Comma)))
+            .then(expr.clone().separated_by(sym(Symbol::Comma)))
             .then_ignore(sym(Symbol::RParens))
-            .map(|((c, s), es)| Term::SubroutineCall(SubroutineCall::ClassCall(c, s, es)))
+            .map(|((c, s), es)| TermKind::SubroutineCall(SubroutineCall::ClassCall(c, s, es)))
             .or(ident()
                 .then_ignore(sym(Symbol::LParens))
-                .then(expr.clone().map(Box::new).separated_by(sym(Symbol::Comma)))
+                .then(expr.clone().separated_by(sym(Symbol::Comma)))
                 .then_ignore(sym(Symbol::RParens))
-                .map(|(s, es)| Term::SubroutineCall(SubroutineCall::Call(s, es))))
+                .map(|(s, es)| TermKind::SubroutineCall(SubroutineCall::Call(s, es))))
             .or(ident()
                 .then(
                     (sym(Symbol::LBracket)
@@ -331,7 +377,7 @@ pub fn parse_term<'a>(
                         .then_ignore(sym(Symbol::RBracket)))
                     .or_not(),
                 )
-                .map(|(s, oe)| Term::VarName(s, oe)));
+                .map(|(s, oe)| TermKind::VarName(s, oe)));
 
         choice((
             unary_term,
@@ -341,6 +387,7 @@ pub fn parse_term<'a>(
             parens_expr,
             var_or_subroutine,
         ))
+        .map_with_span(|kind, span| Term { kind, span })
         .labelled("term")
     })
 }
@@ -348,7 +395,7 @@ pub fn parse_term<'a>(
 // parse_subroutine_call:
 //  ([var_name] | [class_name]) '.' [subroutine_name] '(' [expression_list] ')'
 //  | [subroutine_name] '(' [expression_list] ')'
-fn parse_subroutine_call() -> impl Parser<Token, SubroutineCall, Error = Simple<Token>> {
+fn parse_subroutine_call() -> impl Parser<Token, SubroutineCall, Error = Simple<Token, Span>> {
     ident()
         .then_ignore(sym(Symbol::Period))
         .then(ident())
@@ -366,16 +413,15 @@ fn parse_subroutine_call() -> impl Parser<Token, SubroutineCall, Error = Simple<
 
 // parse_expression_list:
 //  ([expression] (',' [expression]) *)?
-fn parse_expression_list() -> impl Parser<Token, Vec<Box<Expression>>, Error = Simple<Token>> {
+fn parse_expression_list() -> impl Parser<Token, Vec<Expression>, Error = Simple<Token, Span>> {
     parse_expression()
-        .map(Box::new)
         .separated_by(sym(Symbol::Comma))
         .labelled("expression list")
 }
 
 // parse_binary_op:
 //  '+' | '-' | '*' | '/' | '&' | '|' | '<' | '>' | '='
-fn parse_binary_op() -> impl Parser<Token, BinaryOp, Error = Simple<Token>> {
+fn parse_binary_op() -> impl Parser<Token, BinaryOp, Error = Simple<Token, Span>> {
     choice((
         sym(Symbol::Plus).to(BinaryOp::Plus),
         sym(Symbol::Minus).to(BinaryOp::Minus),
@@ -391,7 +437,7 @@ fn parse_binary_op() -> impl Parser<Token, BinaryOp, Error = Simple<Token>> {
 
 // parse_unary_op:
 //  '-' | '~'
-fn parse_unary_op() -> impl Parser<Token, UnaryOp, Error = Simple<Token>> {
+fn parse_unary_op() -> impl Parser<Token, UnaryOp, Error = Simple<Token, Span>> {
     choice((
         sym(Symbol::Minus).to(UnaryOp::Negation),
         sym(Symbol::Tilde).to(UnaryOp::Tilde),
@@ -400,7 +446,7 @@ fn parse_unary_op() -> impl Parser<Token, UnaryOp, Error = Simple<Token>> {
 
 // parse_keyword_constant:
 //  'true' | 'false' | 'null' | 'this'
-fn parse_keyword_constant() -> impl Parser<Token, KeywordConstant, Error = Simple<Token>> {
+fn parse_keyword_constant() -> impl Parser<Token, KeywordConstant, Error = Simple<Token, Span>> {
     choice((
         kw(Keyword::True).to(KeywordConstant::True),
         kw(Keyword::False).to(KeywordConstant::False),
@@ -412,7 +458,7 @@ fn parse_keyword_constant() -> impl Parser<Token, KeywordConstant, Error = Simpl
 
 // parse_type:
 //  'int' | 'char' | 'boolean' | [class_name]
-fn parse_type() -> impl Parser<Token, Type, Error = Simple<Token>> {
+fn parse_type() -> impl Parser<Token, Type, Error = Simple<Token, Span>> {
     choice((
         kw(Keyword::Int).to(Type::Int),
         kw(Keyword::Boolean).to(Type::Boolean),
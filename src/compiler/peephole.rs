@@ -0,0 +1,205 @@
+use crate::ast::asm::*;
+
+// Instruction counts of a program before and after the peephole optimizer.
+//  Labels are not instructions, so they are not counted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PeepholeReport {
+    pub before: usize,
+    pub after: usize,
+}
+
+impl PeepholeReport {
+    // The report for a program that was not optimized
+    pub fn unchanged(assembly: &[Assembly]) -> Self {
+        let count = count_instructions(assembly);
+        Self {
+            before: count,
+            after: count,
+        }
+    }
+
+    pub fn saved(&self) -> usize {
+        self.before - self.after
+    }
+}
+
+// Peephole optimizer:
+//  Rewrites the fixed templates `VmToAsm` emits for each VM command where they
+//  meet. It relies on two properties of that code: the stack above SP is never
+//  read, and every label is followed by code that sets A before using it.
+pub struct Peephole;
+
+impl Peephole {
+    pub fn optimize(assembly: Vec<Assembly>) -> (Vec<Assembly>, PeepholeReport) {
+        let before = count_instructions(&assembly);
+        let mut assembly = assembly;
+        // Every rule shortens the program, so a pass that keeps the length
+        //  has nothing left to do
+        loop {
+            let len = assembly.len();
+            assembly = optimize_pass(assembly);
+            if assembly.len() == len {
+                break;
+            }
+        }
+        let report = PeepholeReport {
+            before,
+            after: count_instructions(&assembly),
+        };
+        (assembly, report)
+    }
+}
+
+fn count_instructions(assembly: &[Assembly]) -> usize {
+    assembly
+        .iter()
+        .filter(|asm| !matches!(asm, Assembly::Label(_)))
+        .count()
+}
+
+fn optimize_pass(assembly: Vec<Assembly>) -> Vec<Assembly> {
+    let mut optimized = Vec::with_capacity(assembly.len());
+    let mut i = 0;
+    while i < assembly.len() {
+        let rest = &assembly[i..];
+        match rewrite(rest) {
+            Some((consumed, replacement)) => {
+                optimized.extend(replacement);
+                i += consumed;
+            }
+            None => {
+                optimized.push(rest[0].clone());
+                i += 1;
+            }
+        }
+    }
+    optimized
+}
+
+// Try every rule at the start of `rest`, returning how many instructions
+//  were matched and what replaces them
+fn rewrite(rest: &[Assembly]) -> Option<(usize, Vec<Assembly>)> {
+    // Pushing D and popping it straight back leaves D as it was, with A at the
+    //  top of the stack
+    //  @SP, A=M, M=D, @SP, M=M+1, @SP, AM=M-1, D=M => @SP, A=M
+    if rest.starts_with(&[
+        at("SP"),
+        c(Some(Dest::A), Comp::M, None),
+        c(Some(Dest::M), Comp::D, None),
+        at("SP"),
+        c(Some(Dest::M), Comp::MPlusOne, None),
+        at("SP"),
+        c(Some(Dest::AM), Comp::MMinusOne, None),
+        c(Some(Dest::D), Comp::M, None),
+    ]) {
+        return Some((8, vec![at("SP"), c(Some(Dest::A), Comp::M, None)]));
+    }
+
+    // Incrementing SP and decrementing it again
+    //  @SP, M=M+1, @SP, AM=M-1 => @SP, A=M
+    if rest.starts_with(&[
+        at("SP"),
+        c(Some(Dest::M), Comp::MPlusOne, None),
+        at("SP"),
+        c(Some(Dest::AM), Comp::MMinusOne, None),
+    ]) {
+        return Some((4, vec![at("SP"), c(Some(Dest::A), Comp::M, None)]));
+    }
+
+    // Adjusting an address that was just loaded
+    //  A=M, A=A-1 => A=M-1
+    if let [Assembly::C(load), Assembly::C(adjust), ..] = rest {
+        if *load == c_instruction(Some(Dest::A), Comp::M, None) {
+            let comp = match adjust {
+                CInstruction {
+                    o_dest: Some(Dest::A),
+                    comp: Comp::AMinusOne,
+                    o_jump: None,
+                } => Some(Comp::MMinusOne),
+                CInstruction {
+                    o_dest: Some(Dest::A),
+                    comp: Comp::APlusOne,
+                    o_jump: None,
+                } => Some(Comp::MPlusOne),
+                _ => None,
+            };
+            if let Some(comp) = comp {
+                return Some((2, vec![c(Some(Dest::A), comp, None)]));
+            }
+        }
+    }
+
+    // Loading A only for the next instruction to load it again
+    //  @X, @Y => @Y
+    if let [first, Assembly::A(_), ..] = rest {
+        if only_sets_a(first) {
+            return Some((1, vec![]));
+        }
+    }
+
+    // Jumping to the next instruction
+    //  @L, 0;JMP, (L) => (L)
+    if let [Assembly::A(AInstruction::Symbol(target)), Assembly::C(CInstruction {
+        o_dest: None,
+        o_jump: Some(_),
+        ..
+    }), labels @ ..] = rest
+    {
+        let jumps_to_next = labels
+            .iter()
+            .map_while(|asm| match asm {
+                Assembly::Label(label) => Some(label),
+                _ => None,
+            })
+            .any(|label| label == target);
+        if jumps_to_next {
+            return Some((2, vec![]));
+        }
+    }
+
+    // Code after an unconditional jump is unreachable until the next label
+    if let [jump @ Assembly::C(CInstruction {
+        o_jump: Some(Jump::JMP),
+        ..
+    }), after @ ..] = rest
+    {
+        let unreachable = after
+            .iter()
+            .take_while(|asm| !matches!(asm, Assembly::Label(_)))
+            .count();
+        if unreachable > 0 {
+            return Some((1 + unreachable, vec![jump.clone()]));
+        }
+    }
+
+    None
+}
+
+// Whether an instruction has no effect other than setting A
+fn only_sets_a(assembly: &Assembly) -> bool {
+    matches!(
+        assembly,
+        Assembly::A(_)
+            | Assembly::C(CInstruction {
+                o_dest: Some(Dest::A),
+                o_jump: None,
+                ..
+            })
+    )
+}
+
+fn at(symbol: &str) -> Assembly {
+    Assembly::A(AInstruction::Symbol(symbol.to_string()))
+}
+
+fn c(o_dest: Option<Dest>, comp: Comp, o_jump: Option<Jump>) -> Assembly {
+    Assembly::C(c_instruction(o_dest, comp, o_jump))
+}
+
+fn c_instruction(o_dest: Option<Dest>, comp: Comp, o_jump: Option<Jump>) -> CInstruction {
+    CInstruction {
+        comp,
+        o_dest,
+        o_jump,
+    }
+}
@@ -0,0 +1,215 @@
+use crate::ast::jack::*;
+use crate::ast::span::Span;
+use crate::compiler::diagnostic::Diagnostic;
+use crate::pretty_printer::jack::PrettyPrint;
+
+// How the binary operators of an expression are grouped. The Jack language
+//  evaluates them from left to right; the `Climbing` dialect gives `*` and `/`
+//  precedence over `+` and `-`, which take precedence over the comparisons,
+//  which take precedence over `&` and `|`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Precedence {
+    #[default]
+    LeftToRight,
+    Climbing,
+}
+
+fn binding(op: &BinaryOp) -> u8 {
+    match op {
+        BinaryOp::Times | BinaryOp::Div => 3,
+        BinaryOp::Plus | BinaryOp::Minus => 2,
+        BinaryOp::Lesser | BinaryOp::Greater | BinaryOp::Equal => 1,
+        BinaryOp::And | BinaryOp::Or => 0,
+    }
+}
+
+// Operator precedence:
+//  Rewrites every expression of a class so that evaluating it from left to
+//  right, as `JackToVm` does, respects precedence. Operands of a tighter
+//  operator are grouped in parentheses, so `a + b * c` becomes `a + (b * c)`.
+pub fn climb_class(class: Class) -> Class {
+    let mut class = class;
+    visit_class(&mut class, &mut |expression| {
+        let flat = std::mem::replace(expression, placeholder());
+        *expression = climb(flat);
+    });
+    class
+}
+
+// Warn about every expression that reads differently with precedence
+pub fn check_class(file_path: &str, class: &Class) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let mut class = class.clone();
+    visit_class(&mut class, &mut |expression| {
+        if is_ambiguous(expression) {
+            diagnostics.push(
+                Diagnostic::warning(
+                    file_path,
+                    Some(expression.span),
+                    format!(
+                        "`{}` is evaluated from left to right, as `{}`",
+                        expression.pretty_print(0),
+                        left_to_right(expression.clone()).pretty_print(0)
+                    ),
+                )
+                .with_note(format!(
+                    "with operator precedence it would be `{}`",
+                    climb(expression.clone()).pretty_print(0)
+                )),
+            );
+        }
+    });
+    diagnostics
+}
+
+// Left to right and precedence only agree when no operator binds tighter
+//  than the one before it
+fn is_ambiguous(expression: &Expression) -> bool {
+    expression
+        .bin
+        .windows(2)
+        .any(|pair| binding(&pair[1].0) > binding(&pair[0].0))
+}
+
+// Split the expression at its loosest operators, which are then evaluated
+//  from left to right, and climb into each group in between
+fn climb(expression: Expression) -> Expression {
+    let Some(loosest) = expression.bin.iter().map(|(op, _)| binding(op)).min() else {
+        return expression;
+    };
+    let span = expression.span;
+    let mut first = group(expression.term, Vec::new());
+    let mut groups: Vec<(BinaryOp, Expression)> = Vec::new();
+    for (op, term) in expression.bin {
+        match (binding(&op) == loosest, groups.last_mut()) {
+            (true, _) => groups.push((op, group(term, Vec::new()))),
+            (false, Some((_, last))) => last.bin.push((op, term)),
+            (false, None) => first.bin.push((op, term)),
+        }
+    }
+    // The first group is evaluated before anything else, so it needs no
+    //  parentheses of its own
+    let first = climb(first);
+    let rest = groups.into_iter().map(|(op, expression)| {
+        let term = match expression.bin.is_empty() {
+            true => expression.term,
+            false => parenthesize(climb(group(expression.term, expression.bin))),
+        };
+        (op, term)
+    });
+    Expression {
+        term: first.term,
+        bin: first.bin.into_iter().chain(rest).collect(),
+        span,
+    }
+}
+
+// The grouping that left to right evaluation implies, for diagnostics
+fn left_to_right(expression: Expression) -> Expression {
+    let span = expression.span;
+    let mut bin = expression.bin.into_iter();
+    let mut term = expression.term;
+    let Some(last) = bin.next_back() else {
+        return Expression {
+            term,
+            bin: Vec::new(),
+            span,
+        };
+    };
+    for (op, next) in bin {
+        term = parenthesize(group(term, vec![(op, next)]));
+    }
+    Expression {
+        term,
+        bin: vec![last],
+        span,
+    }
+}
+
+fn group(term: Box<Term>, bin: Vec<(BinaryOp, Box<Term>)>) -> Expression {
+    let end = bin.last().map_or(term.span.end, |(_, last)| last.span.end);
+    Expression {
+        span: Span {
+            start: term.span.start,
+            end,
+        },
+        term,
+        bin,
+    }
+}
+
+fn parenthesize(expression: Expression) -> Box<Term> {
+    Box::new(Term {
+        span: expression.span,
+        kind: TermKind::ParensExpr(Box::new(expression)),
+    })
+}
+
+fn placeholder() -> Expression {
+    Expression::new(Term::new(TermKind::IntegerConstant(0)), Vec::new())
+}
+
+// Visiting every expression of a class, inner expressions first:
+fn visit_class(class: &mut Class, f: &mut impl FnMut(&mut Expression)) {
+    class
+        .class_dec
+        .subroutine_dec
+        .iter_mut()
+        .for_each(|sd| visit_statements(&mut sd.subroutine_body.stmts, f));
+}
+
+fn visit_statements(statements: &mut [Statement], f: &mut impl FnMut(&mut Expression)) {
+    statements
+        .iter_mut()
+        .for_each(|statement| match &mut statement.kind {
+            StatementKind::Let(_, index, value) => {
+                if let Some(index) = index {
+                    visit_expression(index, f);
+                }
+                visit_expression(value, f);
+            }
+            StatementKind::If(condition, then, otherwise) => {
+                visit_expression(condition, f);
+                visit_statements(then, f);
+                if let Some(otherwise) = otherwise {
+                    visit_statements(otherwise, f);
+                }
+            }
+            StatementKind::While(condition, body) => {
+                visit_expression(condition, f);
+                visit_statements(body, f);
+            }
+            StatementKind::Do(call) => visit_call(call, f),
+            StatementKind::Return(value) => {
+                if let Some(value) = value {
+                    visit_expression(value, f);
+                }
+            }
+        });
+}
+
+fn visit_expression(expression: &mut Expression, f: &mut impl FnMut(&mut Expression)) {
+    visit_term(&mut expression.term, f);
+    expression
+        .bin
+        .iter_mut()
+        .for_each(|(_, term)| visit_term(term, f));
+    f(expression);
+}
+
+fn visit_term(term: &mut Term, f: &mut impl FnMut(&mut Expression)) {
+    match &mut term.kind {
+        TermKind::VarName(_, Some(index)) => visit_expression(index, f),
+        TermKind::Unary(_, term) => visit_term(term, f),
+        TermKind::ParensExpr(expression) => visit_expression(expression, f),
+        TermKind::SubroutineCall(call) => visit_call(call, f),
+        _ => (),
+    }
+}
+
+fn visit_call(call: &mut SubroutineCall, f: &mut impl FnMut(&mut Expression)) {
+    let (SubroutineCall::Call(_, arguments) | SubroutineCall::ClassCall(_, _, arguments)) = call;
+    arguments
+        .iter_mut()
+        .for_each(|argument| visit_expression(argument, f));
+}
@@ -0,0 +1,77 @@
+use crate::ast::vm::*;
+
+use std::ops::Range;
+
+// The addresses of the VM registers at the bottom of RAM
+pub const SP: u16 = 0;
+pub const LCL: u16 = 1;
+pub const ARG: u16 = 2;
+pub const THIS: u16 = 3;
+pub const THAT: u16 = 4;
+
+// How a VM program starts and where its segments live, shared by the
+//  assembly backend and the emulators. The default is the nand2tetris
+//  platform: the bootstrap sets SP to 256 and calls `Sys.init`, temp is
+//  RAM[5..13] and the statics of every file share RAM[16..256].
+//
+//  Without the bootstrap the program starts at its first command, as the
+//  project 7 tests expect, and the emulators set the registers themselves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeProfile {
+    pub bootstrap: bool,
+    pub stack_base: u16,
+    // LCL, ARG, THIS and THAT when the program starts, left alone if unset
+    pub lcl: Option<u16>,
+    pub arg: Option<u16>,
+    pub this: Option<u16>,
+    pub that: Option<u16>,
+    // The function the bootstrap calls
+    pub entry: String,
+    pub temp: Range<u16>,
+    pub statics: Range<u16>,
+}
+
+impl Default for RuntimeProfile {
+    fn default() -> Self {
+        Self {
+            bootstrap: true,
+            stack_base: 256,
+            lcl: None,
+            arg: None,
+            this: None,
+            that: None,
+            entry: "Sys.init".to_string(),
+            temp: 5..13,
+            statics: 16..256,
+        }
+    }
+}
+
+impl RuntimeProfile {
+    // The registers to set when the program starts, with their values
+    pub fn registers(&self) -> Vec<(u16, u16)> {
+        [
+            (SP, Some(self.stack_base)),
+            (LCL, self.lcl),
+            (ARG, self.arg),
+            (THIS, self.this),
+            (THAT, self.that),
+        ]
+        .into_iter()
+        .filter_map(|(register, value)| value.map(|value| (register, value)))
+        .collect()
+    }
+}
+
+// The number of static variables used by a file
+pub fn static_count(commands: &[Command]) -> usize {
+    commands
+        .iter()
+        .filter_map(|command| match command {
+            Command::Stack(Stack::Push(Segment::Static, i))
+            | Command::Stack(Stack::Pop(Segment::Static, i)) => Some(*i as usize + 1),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0)
+}
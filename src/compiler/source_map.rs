@@ -0,0 +1,113 @@
+use crate::ast::asm::*;
+use crate::ast::span::Span;
+use crate::ast::vm::Command;
+use crate::compiler::assembler::Assembler;
+
+// A VM file of a program, with the line of each of its commands
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VmSource {
+    pub file: String,
+    pub lines: Vec<usize>,
+}
+
+impl VmSource {
+    // A file as it is printed, one command per line
+    pub fn printed(name: &str, commands: &[Command]) -> Self {
+        Self {
+            file: format!("{}.vm", name),
+            lines: (1..=commands.len()).collect(),
+        }
+    }
+}
+
+// The Jack file a VM file was compiled from, with the span of the statement
+//  each of its commands was compiled from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JackSource {
+    pub file: String,
+    pub spans: Vec<Span>,
+}
+
+// The VM command an assembly instruction was translated from, as the index
+//  of its file in the program and its index in the file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VmOrigin {
+    pub file: usize,
+    pub command: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VmLocation {
+    pub file: String,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JackLocation {
+    pub file: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+// Where the instruction at a ROM address came from. The assembly line is
+//  that of the printed `.asm` file, which holds one instruction or label per
+//  line. The bootstrap and the shared routines come from no VM command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MapEntry {
+    pub address: u16,
+    pub asm_line: usize,
+    pub vm: Option<VmLocation>,
+    pub jack: Option<JackLocation>,
+}
+
+// Maps every ROM address of a program back through its assembly and VM code
+//  to the Jack statement it was compiled from
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceMap {
+    pub entries: Vec<MapEntry>,
+}
+
+impl SourceMap {
+    // `vm` gives the VM files that `origins` refer to, and `jack` their Jack
+    //  sources in the same order, or nothing for VM programs. `origins` has
+    //  one entry per instruction of `assembly`.
+    pub fn new(
+        vm: &[VmSource],
+        jack: &[JackSource],
+        assembly: &[Assembly],
+        origins: &[Option<VmOrigin>],
+    ) -> Self {
+        let entries = Assembler::addresses(assembly)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, address)| {
+                let origin = origins.get(i).copied().flatten();
+                address.map(|address| MapEntry {
+                    address,
+                    asm_line: i + 1,
+                    vm: origin.and_then(|origin| {
+                        let source = vm.get(origin.file)?;
+                        Some(VmLocation {
+                            file: source.file.to_string(),
+                            line: *source.lines.get(origin.command)?,
+                        })
+                    }),
+                    jack: origin.and_then(|origin| {
+                        let source = jack.get(origin.file)?;
+                        let span = source.spans.get(origin.command)?;
+                        Some(JackLocation {
+                            file: source.file.to_string(),
+                            line: span.start.line,
+                            column: span.start.column,
+                        })
+                    }),
+                })
+            })
+            .collect();
+        Self { entries }
+    }
+
+    pub fn lookup(&self, address: u16) -> Option<&MapEntry> {
+        self.entries.get(address as usize)
+    }
+}
@@ -0,0 +1,67 @@
+use crate::ast::jack::*;
+
+use std::collections::HashMap;
+
+// Program-wide table of the classes being compiled together and the
+//  signatures of their subroutines. Classes that are not part of the
+//  program (e.g. the OS when it is not linked) are simply absent.
+#[derive(Clone)]
+pub struct SubroutineSignature {
+    pub subroutine_type: SubroutineType,
+    pub return_type: SubroutineReturnType,
+    pub parameters: Vec<Type>,
+}
+
+#[derive(Clone, Default)]
+pub struct ClassSignature {
+    pub subroutines: HashMap<String, SubroutineSignature>,
+}
+
+#[derive(Clone, Default)]
+pub struct SymbolTable {
+    classes: HashMap<String, ClassSignature>,
+}
+
+impl SymbolTable {
+    pub fn new(classes: &[&Class]) -> Self {
+        let mut table = Self::default();
+        classes.iter().for_each(|class| {
+            table.insert_class(class);
+        });
+        table
+    }
+
+    pub fn insert_class(&mut self, class: &Class) -> &mut Self {
+        let subroutines = class
+            .class_dec
+            .subroutine_dec
+            .iter()
+            .map(|sd| {
+                (
+                    sd.subroutine_name.to_string(),
+                    SubroutineSignature {
+                        subroutine_type: sd.subroutine_type.clone(),
+                        return_type: sd.subroutine_return_type.clone(),
+                        parameters: sd.parameter_list.iter().map(|p| p.r#type.clone()).collect(),
+                    },
+                )
+            })
+            .collect();
+        self.classes
+            .insert(class.class_name.to_string(), ClassSignature { subroutines });
+        self
+    }
+
+    pub fn lookup_class(&self, class_name: &str) -> Option<&ClassSignature> {
+        self.classes.get(class_name)
+    }
+
+    pub fn lookup_subroutine(
+        &self,
+        class_name: &str,
+        subroutine_name: &str,
+    ) -> Option<&SubroutineSignature> {
+        self.lookup_class(class_name)
+            .and_then(|c| c.subroutines.get(subroutine_name))
+    }
+}
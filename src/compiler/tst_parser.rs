@@ -0,0 +1,177 @@
+use crate::ast::tst::*;
+
+use crate::ast::span::Span;
+
+use chumsky::prelude::*;
+
+// Test scripts (Appendix 3):
+//  The commands of the nand2tetris `.tst` files for the CPU and VM emulators,
+//  each ended by `,`, `;` or `!`. Numbers that do not fit the variable, value
+//  or format they are used for are reported where they appear.
+pub fn parse_tst() -> impl Parser<char, Vec<ScriptCommand>, Error = Simple<char, Span>> {
+    parse_commands().then_ignore(end())
+}
+
+fn parse_comment() -> impl Parser<char, (), Error = Simple<char, Span>> {
+    choice((
+        just("//")
+            .then_ignore(filter(|&c| c != '\n').repeated())
+            .ignored(),
+        just("/*").then_ignore(take_until(just("*/"))).ignored(),
+    ))
+    .padded()
+}
+
+fn parse_commands() -> impl Parser<char, Vec<ScriptCommand>, Error = Simple<char, Span>> {
+    recursive(|commands| {
+        let repeat = text::keyword("repeat")
+            .padded()
+            .ignore_then(parse_num().or_not())
+            .then(commands.delimited_by('{', '}'))
+            .map(|(n, cmds)| ScriptCommand::Repeat(n, cmds));
+
+        let command = parse_command()
+            .then_ignore(one_of([',', ';', '!']))
+            .padded();
+
+        parse_comment()
+            .repeated()
+            .ignore_then(choice((repeat, command)))
+            .then_ignore(parse_comment().repeated())
+            .padded()
+            .repeated()
+    })
+}
+
+fn parse_command() -> impl Parser<char, ScriptCommand, Error = Simple<char, Span>> {
+    choice((
+        text::keyword("load")
+            .ignore_then(parse_file_name().or_not())
+            .map(ScriptCommand::Load),
+        just("output-file")
+            .ignore_then(parse_file_name())
+            .map(ScriptCommand::OutputFile),
+        just("compare-to")
+            .ignore_then(parse_file_name())
+            .map(ScriptCommand::CompareTo),
+        just("output-list")
+            .ignore_then(parse_output_column().repeated())
+            .map(ScriptCommand::OutputList),
+        text::keyword("output").to(ScriptCommand::Output),
+        text::keyword("set")
+            .ignore_then(parse_variable())
+            .then(parse_value())
+            .map(|(variable, value)| ScriptCommand::Set(variable, value)),
+        text::keyword("ticktock").to(ScriptCommand::TickTock),
+        text::keyword("vmstep").to(ScriptCommand::VmStep),
+        text::keyword("echo")
+            .ignore_then(
+                filter(|&c| c != '"')
+                    .repeated()
+                    .collect::<String>()
+                    .padded()
+                    .delimited_by('"', '"')
+                    .padded(),
+            )
+            .map(ScriptCommand::Echo),
+    ))
+    .padded()
+}
+
+fn parse_file_name() -> impl Parser<char, String, Error = Simple<char, Span>> {
+    filter(|&c: &char| c.is_alphanumeric() || c == '.' || c == '_' || c == '-')
+        .repeated()
+        .at_least(1)
+        .collect::<String>()
+        .padded()
+}
+
+fn parse_output_column() -> impl Parser<char, OutputColumn, Error = Simple<char, Span>> {
+    parse_variable()
+        .then_ignore(just('%'))
+        .then(parse_format())
+        .map(|(variable, (format, left, width, right))| OutputColumn {
+            variable,
+            format,
+            left,
+            width,
+            right,
+        })
+        .padded()
+}
+
+// `D1.6.1`: the format, then the left padding, width and right padding
+fn parse_format() -> impl Parser<char, (Format, usize, usize, usize), Error = Simple<char, Span>> {
+    let size = || number("format size", 0, u16::MAX as i64).map(|n| n as usize);
+    choice((
+        just('D').to(Format::Decimal),
+        just('X').to(Format::Hexadecimal),
+        just('B').to(Format::Binary),
+    ))
+    .then(size())
+    .then_ignore(just('.'))
+    .then(size())
+    .then_ignore(just('.'))
+    .then(size())
+    .map(|(((format, left), width), right)| (format, left, width, right))
+}
+
+fn parse_variable() -> impl Parser<char, Variable, Error = Simple<char, Span>> {
+    text::ident()
+        .then(
+            number("index", 0, u16::MAX as i64)
+                .padded()
+                .delimited_by('[', ']')
+                .or_not(),
+        )
+        .map(|(name, index): (String, Option<i64>)| match index {
+            Some(i) if name == "RAM" => Variable::Ram(i as u16),
+            Some(i) => Variable::Segment(name, i as u16),
+            None => Variable::Register(name),
+        })
+        .padded()
+}
+
+// A value is a 16-bit word, written signed or unsigned
+fn parse_value() -> impl Parser<char, i16, Error = Simple<char, Span>> {
+    just('-')
+        .or_not()
+        .then(text::int(10))
+        .try_map(|(sign, n): (Option<char>, String), span| {
+            let n = match sign {
+                Some(_) => format!("-{}", n),
+                None => n,
+            };
+            in_range(&n, span, "value", i16::MIN as i64, u16::MAX as i64)
+        })
+        .map(|n| n as i16)
+        .padded()
+}
+
+fn parse_num() -> impl Parser<char, u32, Error = Simple<char, Span>> {
+    number("repeat count", 0, u32::MAX as i64)
+        .map(|n| n as u32)
+        .padded()
+}
+
+// A decimal number from `min` to `max`, which `what` names in errors
+fn number(
+    what: &'static str,
+    min: i64,
+    max: i64,
+) -> impl Parser<char, i64, Error = Simple<char, Span>> {
+    text::int(10).try_map(move |n: String, span| in_range(&n, span, what, min, max))
+}
+
+fn in_range(
+    n: &str,
+    span: Span,
+    what: &str,
+    min: i64,
+    max: i64,
+) -> Result<i64, Simple<char, Span>> {
+    n.parse::<i64>()
+        .ok()
+        .filter(|n| (min..=max).contains(n))
+        .ok_or_else(|| Simple::custom(span, format!("{} must be in {}..={}", what, min, max)))
+}
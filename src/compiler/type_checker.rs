@@ -0,0 +1,406 @@
+use crate::ast::jack::*;
+use crate::ast::span::Span;
+use crate::compiler::diagnostic::Diagnostic;
+use crate::compiler::symbol_table::*;
+use crate::pretty_printer::jack::PrettyPrint;
+
+use std::collections::HashMap;
+use std::mem::take;
+
+// Jack itself lets `int`, `char` and `boolean` interconvert freely and treats
+//  `Array` as untyped memory. `Lenient` follows those rules, `Strict` requires
+//  the types on both sides of an assignment or return to match exactly.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Strictness {
+    Strict,
+    #[default]
+    Lenient,
+}
+
+// The type of an expression. `Unknown` is used whenever the type cannot be
+//  determined (array elements, calls into classes outside the program), and
+//  is compatible with everything.
+#[derive(Clone)]
+enum ExprType {
+    Type(Type),
+    Null,
+    Void,
+    Unknown,
+}
+
+pub struct TypeChecker<'a> {
+    file_name: String,
+    class_name: String,
+    table: &'a SymbolTable,
+    strictness: Strictness,
+    global_ctx: HashMap<String, Type>,
+    local_ctx: HashMap<String, Type>,
+    return_type: SubroutineReturnType,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> TypeChecker<'a> {
+    pub fn check(
+        file_name: &str,
+        class: &Class,
+        table: &'a SymbolTable,
+        strictness: Strictness,
+    ) -> Vec<Diagnostic> {
+        let mut checker = Self {
+            file_name: file_name.to_string(),
+            class_name: class.class_name.to_string(),
+            table,
+            strictness,
+            global_ctx: HashMap::new(),
+            local_ctx: HashMap::new(),
+            return_type: SubroutineReturnType::Void,
+            diagnostics: Vec::new(),
+        };
+        let checked = checker.check_class_dec(&class.class_dec);
+        take(&mut checked.diagnostics)
+    }
+
+    fn error(&mut self, span: Span, message: String) -> &mut Self {
+        self.diagnostics
+            .push(Diagnostic::error(&self.file_name, Some(span), message));
+        self
+    }
+
+    fn lookup(&self, name: &str) -> Option<&Type> {
+        self.local_ctx
+            .get(name)
+            .or_else(|| self.global_ctx.get(name))
+    }
+
+    // Whether a value of type `value` may be stored in a variable of type `target`
+    fn assignable(&self, target: &Type, value: &ExprType) -> bool {
+        match value {
+            ExprType::Unknown | ExprType::Void => true,
+            ExprType::Null => matches!(target, Type::ClassName(_)),
+            ExprType::Type(value) => {
+                target == value
+                    || match self.strictness {
+                        Strictness::Strict => false,
+                        Strictness::Lenient => {
+                            (is_primitive(target) && is_primitive(value))
+                                || is_array(target)
+                                || is_array(value)
+                        }
+                    }
+            }
+        }
+    }
+
+    // Checking functions:
+    fn check_class_dec(&mut self, class_dec: &ClassDec) -> &mut Self {
+        class_dec.class_var_dec.iter().for_each(|cvd| {
+            cvd.vars.iter().for_each(|var| {
+                self.global_ctx.insert(var.to_string(), cvd.r#type.clone());
+            });
+        });
+        class_dec.subroutine_dec.iter().for_each(|sd| {
+            self.check_subroutine_dec(sd);
+        });
+        self
+    }
+
+    fn check_subroutine_dec(&mut self, subroutine_dec: &SubroutineDec) -> &mut Self {
+        self.local_ctx = HashMap::new();
+        self.return_type = subroutine_dec.subroutine_return_type.clone();
+        subroutine_dec.parameter_list.iter().for_each(|p| {
+            self.local_ctx
+                .insert(p.var_name.to_string(), p.r#type.clone());
+        });
+        subroutine_dec
+            .subroutine_body
+            .var_decs
+            .iter()
+            .for_each(|vd| {
+                vd.var_name.iter().for_each(|var| {
+                    self.local_ctx.insert(var.to_string(), vd.r#type.clone());
+                });
+            });
+        self.check_statements(&subroutine_dec.subroutine_body.stmts)
+    }
+
+    fn check_statements(&mut self, statements: &[Statement]) -> &mut Self {
+        statements.iter().for_each(|stmt| {
+            self.check_statement(stmt);
+        });
+        self
+    }
+
+    fn check_statement(&mut self, statement: &Statement) -> &mut Self {
+        match &statement.kind {
+            StatementKind::Let(ident, array, e) => {
+                if let Some(e2) = array {
+                    self.type_of_expression(e2);
+                }
+                let value = self.type_of_expression(e);
+                let target = match array {
+                    None => self.lookup(ident).cloned(),
+                    Some(_) => None,
+                };
+                match target {
+                    Some(target) if !self.assignable(&target, &value) => self.error(
+                        e.span,
+                        format!(
+                            "cannot assign {} to `{}` of type `{}`",
+                            show_expr_type(&value),
+                            ident,
+                            target.pretty_print(0)
+                        ),
+                    ),
+                    _ => self,
+                }
+            }
+            StatementKind::If(e, s1, o_s2) => {
+                self.type_of_expression(e);
+                self.check_statements(s1);
+                match o_s2 {
+                    Some(s2) => self.check_statements(s2),
+                    None => self,
+                }
+            }
+            StatementKind::While(e, stmts) => {
+                self.type_of_expression(e);
+                self.check_statements(stmts)
+            }
+            StatementKind::Do(sc) => {
+                self.type_of_subroutine_call(sc, statement.span);
+                self
+            }
+            StatementKind::Return(oe) => self.check_return(oe.as_ref(), statement.span),
+        }
+    }
+
+    fn check_return(&mut self, expression: Option<&Expression>, span: Span) -> &mut Self {
+        match (self.return_type.clone(), expression) {
+            (SubroutineReturnType::Void, None) => self,
+            (SubroutineReturnType::Void, Some(e)) => {
+                self.type_of_expression(e);
+                self.error(span, "a void subroutine cannot return a value".to_string())
+            }
+            (SubroutineReturnType::Type(t), None) => self.error(
+                span,
+                format!("expected a return value of type `{}`", t.pretty_print(0)),
+            ),
+            (SubroutineReturnType::Type(t), Some(e)) => {
+                let value = self.type_of_expression(e);
+                if self.assignable(&t, &value) {
+                    self
+                } else {
+                    self.error(
+                        e.span,
+                        format!(
+                            "cannot return {} from a subroutine returning `{}`",
+                            show_expr_type(&value),
+                            t.pretty_print(0)
+                        ),
+                    )
+                }
+            }
+        }
+    }
+
+    // Typing functions:
+    fn type_of_expression(&mut self, expression: &Expression) -> ExprType {
+        let first = self.type_of_term(&expression.term);
+        expression.bin.iter().fold(first, |left, (op, t)| {
+            let right = self.type_of_term(t);
+            match op {
+                BinaryOp::Plus | BinaryOp::Minus | BinaryOp::Times | BinaryOp::Div => {
+                    ExprType::Type(Type::Int)
+                }
+                BinaryOp::Lesser | BinaryOp::Greater | BinaryOp::Equal => {
+                    ExprType::Type(Type::Boolean)
+                }
+                BinaryOp::And | BinaryOp::Or => match (left, right) {
+                    (ExprType::Type(Type::Boolean), ExprType::Type(Type::Boolean)) => {
+                        ExprType::Type(Type::Boolean)
+                    }
+                    _ => ExprType::Type(Type::Int),
+                },
+            }
+        })
+    }
+
+    fn type_of_term(&mut self, term: &Term) -> ExprType {
+        match &term.kind {
+            TermKind::IntegerConstant(_) => ExprType::Type(Type::Int),
+            TermKind::StringConstant(_) => ExprType::Type(Type::ClassName("String".to_string())),
+            TermKind::KeywordConstant(kw) => match kw {
+                KeywordConstant::True | KeywordConstant::False => ExprType::Type(Type::Boolean),
+                KeywordConstant::Null => ExprType::Null,
+                KeywordConstant::This => {
+                    ExprType::Type(Type::ClassName(self.class_name.to_string()))
+                }
+            },
+            TermKind::VarName(s, oe) => match oe {
+                None => self
+                    .lookup(s)
+                    .cloned()
+                    .map_or(ExprType::Unknown, ExprType::Type),
+                Some(e) => {
+                    self.type_of_expression(e);
+                    ExprType::Unknown
+                }
+            },
+            TermKind::Unary(op, t) => match (op, self.type_of_term(t)) {
+                (UnaryOp::Tilde, ExprType::Type(Type::Boolean)) => ExprType::Type(Type::Boolean),
+                _ => ExprType::Type(Type::Int),
+            },
+            TermKind::ParensExpr(e) => self.type_of_expression(e),
+            TermKind::SubroutineCall(sc) => match self.type_of_subroutine_call(sc, term.span) {
+                ExprType::Void => {
+                    self.error(
+                        term.span,
+                        format!(
+                            "`{}` returns void and cannot be used in an expression",
+                            show_call(sc)
+                        ),
+                    );
+                    ExprType::Unknown
+                }
+                t => t,
+            },
+        }
+    }
+
+    fn type_of_subroutine_call(
+        &mut self,
+        subroutine_call: &SubroutineCall,
+        span: Span,
+    ) -> ExprType {
+        let (class_name, subroutine_name, exprs, receiver) = match subroutine_call {
+            SubroutineCall::Call(s, es) => (self.class_name.to_string(), s, es, None),
+            SubroutineCall::ClassCall(c, s, es) => match self.lookup(c).cloned() {
+                Some(Type::ClassName(class_name)) => (class_name, s, es, Some(c)),
+                Some(t) => {
+                    es.iter().for_each(|e| {
+                        self.type_of_expression(e);
+                    });
+                    self.error(
+                        span,
+                        format!(
+                            "`{}` has type `{}` and has no methods",
+                            c,
+                            t.pretty_print(0)
+                        ),
+                    );
+                    return ExprType::Unknown;
+                }
+                None => (c.to_string(), s, es, None),
+            },
+        };
+        let arguments: Vec<ExprType> = exprs.iter().map(|e| self.type_of_expression(e)).collect();
+
+        let table = self.table;
+        if table.lookup_class(&class_name).is_none() {
+            return ExprType::Unknown;
+        }
+        let signature = match table.lookup_subroutine(&class_name, subroutine_name) {
+            Some(signature) => signature,
+            // Calls within the class itself are reported by the `Checker`
+            None => {
+                if let Some(var) = receiver {
+                    self.error(
+                        span,
+                        format!(
+                            "`{}` has type `{}`, which has no method `{}`",
+                            var, class_name, subroutine_name
+                        ),
+                    );
+                } else if class_name != self.class_name {
+                    self.error(
+                        span,
+                        format!(
+                            "class `{}` has no subroutine `{}`",
+                            class_name, subroutine_name
+                        ),
+                    );
+                }
+                return ExprType::Unknown;
+            }
+        };
+
+        let full_name = format!("{}.{}", class_name, subroutine_name);
+        if class_name != self.class_name {
+            match (&signature.subroutine_type, receiver) {
+                (SubroutineType::Method, None) => {
+                    self.error(
+                        span,
+                        format!("method `{}` must be called on an object", full_name),
+                    );
+                }
+                (SubroutineType::Function | SubroutineType::Constructor, Some(_)) => {
+                    self.error(
+                        span,
+                        format!(
+                            "`{}` is not a method and cannot be called on an object",
+                            full_name
+                        ),
+                    );
+                }
+                _ => (),
+            }
+            if signature.parameters.len() != arguments.len() {
+                self.error(
+                    span,
+                    format!(
+                        "`{}` expects {} argument(s), found {}",
+                        full_name,
+                        signature.parameters.len(),
+                        arguments.len()
+                    ),
+                );
+            }
+        }
+        signature
+            .parameters
+            .iter()
+            .zip(arguments.iter().zip(exprs))
+            .for_each(|(parameter, (argument, e))| {
+                if !self.assignable(parameter, argument) {
+                    self.error(
+                        e.span,
+                        format!(
+                            "cannot pass {} as an argument of type `{}` to `{}`",
+                            show_expr_type(argument),
+                            parameter.pretty_print(0),
+                            full_name
+                        ),
+                    );
+                }
+            });
+
+        match &signature.return_type {
+            SubroutineReturnType::Void => ExprType::Void,
+            SubroutineReturnType::Type(t) => ExprType::Type(t.clone()),
+        }
+    }
+}
+
+fn is_primitive(t: &Type) -> bool {
+    matches!(t, Type::Int | Type::Char | Type::Boolean)
+}
+
+fn is_array(t: &Type) -> bool {
+    matches!(t, Type::ClassName(c) if c == "Array")
+}
+
+fn show_expr_type(t: &ExprType) -> String {
+    match t {
+        ExprType::Type(t) => format!("a value of type `{}`", t.pretty_print(0)),
+        ExprType::Null => "`null`".to_string(),
+        ExprType::Void => "`void`".to_string(),
+        ExprType::Unknown => "a value of unknown type".to_string(),
+    }
+}
+
+fn show_call(subroutine_call: &SubroutineCall) -> String {
+    match subroutine_call {
+        SubroutineCall::Call(s, _) => s.to_string(),
+        SubroutineCall::ClassCall(c, s, _) => format!("{}.{}", c, s),
+    }
+}
@@ -0,0 +1,239 @@
+use crate::ast::vm::*;
+
+// VM optimizer:
+//  Simplifies the commands of each VM file before translation, whether they
+//  come from `JackToVm` or from a parsed `.vm` file. Labels are only ever
+//  removed together with the code around them when it is unreachable.
+pub struct VmOptimizer;
+
+impl VmOptimizer {
+    pub fn optimize(commands: Vec<Command>) -> Vec<Command> {
+        let mut commands = commands;
+        loop {
+            let optimized = rotate_loops(simplify(commands.clone()));
+            if optimized == commands {
+                return optimized;
+            }
+            commands = optimized;
+        }
+    }
+
+    pub fn optimize_program(files: Vec<(String, Vec<Command>)>) -> Vec<(String, Vec<Command>)> {
+        files
+            .into_iter()
+            .map(|(file_name, commands)| (file_name, Self::optimize(commands)))
+            .collect()
+    }
+}
+
+// Rewrites that only look at the commands just before the current one. The
+//  optimized commands are kept on a stack, so that a rewrite can expose
+//  another one, as in `push constant 0 / not / not`.
+fn simplify(commands: Vec<Command>) -> Vec<Command> {
+    let mut optimized: Vec<Command> = Vec::with_capacity(commands.len());
+    for command in commands {
+        // Nothing after a `goto` or `return` runs unless it is jumped to
+        let unreachable = matches!(
+            optimized.last(),
+            Some(Command::Branch(Branch::Goto(_))) | Some(Command::Function(Function::Return))
+        );
+        if unreachable
+            && !matches!(
+                command,
+                Command::Branch(Branch::Label(_)) | Command::Function(Function::Body(..))
+            )
+        {
+            continue;
+        }
+        simplify_command(&mut optimized, command);
+    }
+    optimized
+}
+
+fn simplify_command(optimized: &mut Vec<Command>, command: Command) {
+    match command {
+        // goto L / label L => label L
+        Command::Branch(Branch::Label(ref label))
+            if optimized.last() == Some(&Command::Branch(Branch::Goto(label.clone()))) =>
+        {
+            optimized.pop();
+        }
+        // push constant c / if-goto L => goto L, or nothing when c is false
+        Command::Branch(Branch::IfGoto(label)) => {
+            let Some((value, length)) = tail_constant(optimized) else {
+                optimized.push(Command::Branch(Branch::IfGoto(label)));
+                return;
+            };
+            optimized.truncate(optimized.len() - length);
+            if value != 0 {
+                simplify_command(optimized, Command::Branch(Branch::Goto(label)));
+            }
+            return;
+        }
+        // push X / pop X => nothing
+        Command::Stack(Stack::Pop(ref segment, index))
+            if optimized.last() == Some(&Command::Stack(Stack::Push(segment.clone(), index))) =>
+        {
+            optimized.pop();
+            return;
+        }
+        Command::ACL(ref acl) => {
+            if fold_constants(optimized, acl) {
+                return;
+            }
+            // not / not => nothing, and likewise for neg
+            let unary = matches!(
+                acl,
+                ACL::Arithmetic(Arithmetic::Neg) | ACL::Logical(Logical::Not)
+            );
+            if unary && optimized.last() == Some(&command) {
+                optimized.pop();
+                return;
+            }
+        }
+        _ => (),
+    }
+    optimized.push(command);
+}
+
+// The value of the constant the last commands push, and how many commands
+//  push it. Negative constants take a `neg` or `not` after the push.
+fn tail_constant(commands: &[Command]) -> Option<(i16, usize)> {
+    match commands {
+        [.., Command::Stack(Stack::Push(Segment::Constant, i)), Command::ACL(ACL::Arithmetic(Arithmetic::Neg))] => {
+            Some(((*i as i16).wrapping_neg(), 2))
+        }
+        [.., Command::Stack(Stack::Push(Segment::Constant, i)), Command::ACL(ACL::Logical(Logical::Not))] => {
+            Some((!(*i as i16), 2))
+        }
+        [.., Command::Stack(Stack::Push(Segment::Constant, i))] => Some((*i as i16, 1)),
+        _ => None,
+    }
+}
+
+// Replace the constant operands of `acl` on top of `optimized` with its
+//  result, returning whether it could be computed
+fn fold_constants(optimized: &mut Vec<Command>, acl: &ACL) -> bool {
+    let Some((y, y_length)) = tail_constant(optimized) else {
+        return false;
+    };
+    let boolean = |b: bool| -(b as i16);
+    let (value, length) = match acl {
+        ACL::Arithmetic(Arithmetic::Neg) => (y.wrapping_neg(), y_length),
+        ACL::Logical(Logical::Not) => (!y, y_length),
+        _ => {
+            let Some((x, x_length)) = tail_constant(&optimized[..optimized.len() - y_length])
+            else {
+                return false;
+            };
+            let value = match acl {
+                ACL::Arithmetic(Arithmetic::Add) => x.wrapping_add(y),
+                ACL::Arithmetic(Arithmetic::Sub) => x.wrapping_sub(y),
+                ACL::Logical(Logical::And) => x & y,
+                ACL::Logical(Logical::Or) => x | y,
+                ACL::Comparison(comparison) => boolean(comparison.holds(x, y)),
+                ACL::Arithmetic(Arithmetic::Neg) | ACL::Logical(Logical::Not) => unreachable!(),
+            };
+            (value, x_length + y_length)
+        }
+    };
+    optimized.truncate(optimized.len() - length);
+    optimized.extend(push_constant(value));
+    true
+}
+
+// The shortest commands that push a value, with `true` pushed the way
+//  `JackToVm` does
+fn push_constant(value: i16) -> Vec<Command> {
+    let push = |i: i16| Command::Stack(Stack::Push(Segment::Constant, i as u16));
+    match value {
+        0.. => vec![push(value)],
+        i16::MIN => vec![push(i16::MAX), Command::ACL(ACL::Logical(Logical::Not))],
+        _ if value == -1 => vec![push(0), Command::ACL(ACL::Logical(Logical::Not))],
+        _ => vec![push(-value), Command::ACL(ACL::Arithmetic(Arithmetic::Neg))],
+    }
+}
+
+// `JackToVm` tests the condition of a `while` loop at the top and leaves the
+//  loop with `not / if-goto END`. When the condition is a comparison, and so
+//  either true or false, the test can be moved to the bottom of the loop where
+//  it branches straight back to the body:
+//      label EXP, C, not, if-goto END, S, goto EXP, label END
+//  becomes
+//      goto EXP, label EXP_BODY, S, label EXP, C, if-goto EXP_BODY, label END
+//  which runs two commands fewer on each iteration. `EXP_BODY` is numbered
+//  when the file already declares it.
+fn rotate_loops(commands: Vec<Command>) -> Vec<Command> {
+    let mut commands = commands;
+    let mut labels: std::collections::HashSet<String> = commands
+        .iter()
+        .filter_map(|command| match command {
+            Command::Branch(Branch::Label(label)) => Some(label.clone()),
+            _ => None,
+        })
+        .collect();
+    let mut start = 0;
+    while let Some((top, test, bottom)) = find_loop(&commands, start) {
+        let exp = match &commands[top] {
+            Command::Branch(Branch::Label(exp)) => exp.clone(),
+            _ => unreachable!(),
+        };
+        let body_label = std::iter::once(format!("{}_BODY", exp))
+            .chain((1..).map(|n| format!("{}_BODY{}", exp, n)))
+            .find(|label| !labels.contains(label))
+            .unwrap();
+        labels.insert(body_label.clone());
+        let condition = commands[top + 1..test].to_vec();
+        let body = commands[test + 2..bottom].to_vec();
+        let end = commands[bottom + 1].clone();
+
+        let mut rotated = vec![
+            Command::Branch(Branch::Goto(exp.clone())),
+            Command::Branch(Branch::Label(body_label.clone())),
+        ];
+        rotated.extend(body);
+        rotated.push(Command::Branch(Branch::Label(exp)));
+        rotated.extend(condition);
+        rotated.push(Command::Branch(Branch::IfGoto(body_label)));
+        rotated.push(end);
+
+        start = top + 1;
+        commands.splice(top..bottom + 2, rotated);
+    }
+    commands
+}
+
+// The indices of `label EXP`, of the `not` before `if-goto END` and of
+//  `goto EXP` for the first loop from `start` that can be rotated
+fn find_loop(commands: &[Command], start: usize) -> Option<(usize, usize, usize)> {
+    (start..commands.len()).find_map(|top| {
+        let Command::Branch(Branch::Label(exp)) = &commands[top] else {
+            return None;
+        };
+        // The condition runs straight through to a comparison
+        let branch = top
+            + 1
+            + commands[top + 1..]
+                .iter()
+                .position(|command| matches!(command, Command::Branch(_) | Command::Function(_)))?;
+        let test = branch.checked_sub(1)?;
+        let (
+            [.., Command::ACL(ACL::Comparison(_))],
+            [Command::ACL(ACL::Logical(Logical::Not)), Command::Branch(Branch::IfGoto(end)), ..],
+        ) = (commands.get(top + 1..test)?, &commands[test..])
+        else {
+            return None;
+        };
+        // The body ends by jumping back, right before the end of the loop
+        let bottom = test
+            + 2
+            + commands[test + 2..].windows(2).position(|pair| {
+                pair[0] == Command::Branch(Branch::Goto(exp.clone()))
+                    && pair[1] == Command::Branch(Branch::Label(end.clone()))
+            })?;
+        let in_function = !commands[test + 2..bottom]
+            .iter()
+            .any(|command| matches!(command, Command::Function(Function::Body(..))));
+        in_function.then_some((top, test, bottom))
+    })
+}
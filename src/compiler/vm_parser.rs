@@ -0,0 +1,195 @@
+use crate::ast::span::*;
+use crate::ast::vm::*;
+
+use chumsky::prelude::*;
+
+// VM language (chapters 7 and 8):
+//  One command per line, made of words separated by spaces or tabs, optionally
+//  followed by a `//` comment. Blank lines and comment lines may appear anywhere.
+pub fn parse_vm() -> impl Parser<char, Vec<Command>, Error = Simple<char, Span>> {
+    parse_vm_spanned().map(|commands| commands.into_iter().map(|(command, _)| command).collect())
+}
+
+// Also gives the span of each command, for source maps
+pub fn parse_vm_spanned() -> impl Parser<char, Vec<Spanned<Command>>, Error = Simple<char, Span>> {
+    parse_blank()
+        .ignore_then(
+            parse_command()
+                .map_with_span(|command, span| (command, span))
+                .then_ignore(parse_line_end())
+                .then_ignore(parse_blank())
+                .repeated(),
+        )
+        .then_ignore(end())
+}
+
+fn parse_comment() -> impl Parser<char, (), Error = Simple<char, Span>> {
+    just("//")
+        .then_ignore(filter(|&c| c != '\n').repeated())
+        .ignored()
+}
+
+// Whitespace, including newlines, and comments between commands
+fn parse_blank() -> impl Parser<char, (), Error = Simple<char, Span>> {
+    filter(|c: &char| c.is_whitespace())
+        .ignored()
+        .or(parse_comment())
+        .repeated()
+        .ignored()
+}
+
+// Whitespace within a line
+fn parse_space() -> impl Parser<char, (), Error = Simple<char, Span>> {
+    filter(|&c| c == ' ' || c == '\t' || c == '\r')
+        .repeated()
+        .at_least(1)
+        .ignored()
+}
+
+fn parse_line_end() -> impl Parser<char, (), Error = Simple<char, Span>> {
+    parse_space()
+        .or_not()
+        .then(parse_comment().or_not())
+        .then(just('\n').ignored().or(end()))
+        .ignored()
+        .labelled("end of line")
+}
+
+// A command word, segment, index or symbol. Words are validated once the whole
+//  command is known, so that errors can say what was expected in its place.
+fn parse_word() -> impl Parser<char, Spanned<String>, Error = Simple<char, Span>> {
+    filter(|&c: &char| c.is_alphanumeric() || "_.$:-".contains(c))
+        .repeated()
+        .at_least(1)
+        .collect::<String>()
+        .map_with_span(|word, span| (word, span))
+}
+
+fn parse_command() -> impl Parser<char, Command, Error = Simple<char, Span>> {
+    parse_word()
+        .then(parse_space().ignore_then(parse_word()).repeated())
+        .try_map(|(op, args), span| command(op, args, span))
+        .labelled("command")
+}
+
+fn command(
+    (op, op_span): Spanned<String>,
+    args: Vec<Spanned<String>>,
+    span: Span,
+) -> Result<Command, Simple<char, Span>> {
+    let arity = match op.as_str() {
+        "push" | "pop" | "function" | "call" => 2,
+        "label" | "goto" | "if-goto" => 1,
+        "add" | "sub" | "neg" | "eq" | "gt" | "lt" | "and" | "or" | "not" | "return" => 0,
+        _ => return Err(Simple::custom(op_span, format!("unknown command `{}`", op))),
+    };
+    if args.len() > arity {
+        let (word, span) = &args[arity];
+        return Err(Simple::custom(
+            *span,
+            format!("unexpected `{}` after `{}`", word, op),
+        ));
+    }
+    if args.len() < arity {
+        return Err(Simple::custom(
+            span,
+            format!("`{}` expects {} argument(s)", op, arity),
+        ));
+    }
+
+    let command = match op.as_str() {
+        "push" => {
+            let (segment, index) = segment_index(&args[0], &args[1])?;
+            Command::Stack(Stack::Push(segment, index))
+        }
+        "pop" => {
+            let (segment, index) = segment_index(&args[0], &args[1])?;
+            if let Segment::Constant = segment {
+                return Err(Simple::custom(
+                    args[0].1,
+                    "cannot pop into the constant segment".to_string(),
+                ));
+            }
+            Command::Stack(Stack::Pop(segment, index))
+        }
+        "add" => Command::ACL(ACL::Arithmetic(Arithmetic::Add)),
+        "sub" => Command::ACL(ACL::Arithmetic(Arithmetic::Sub)),
+        "neg" => Command::ACL(ACL::Arithmetic(Arithmetic::Neg)),
+        "eq" => Command::ACL(ACL::Comparison(Comparison::Eq)),
+        "gt" => Command::ACL(ACL::Comparison(Comparison::Gt)),
+        "lt" => Command::ACL(ACL::Comparison(Comparison::Lt)),
+        "and" => Command::ACL(ACL::Logical(Logical::And)),
+        "or" => Command::ACL(ACL::Logical(Logical::Or)),
+        "not" => Command::ACL(ACL::Logical(Logical::Not)),
+        "label" => Command::Branch(Branch::Label(symbol(&args[0])?)),
+        "goto" => Command::Branch(Branch::Goto(symbol(&args[0])?)),
+        "if-goto" => Command::Branch(Branch::IfGoto(symbol(&args[0])?)),
+        "function" => Command::Function(Function::Body(
+            symbol(&args[0])?,
+            index(&args[1], MAX_INDEX)?,
+        )),
+        "call" => Command::Function(Function::Call(
+            symbol(&args[0])?,
+            index(&args[1], MAX_INDEX)?,
+        )),
+        _ => Command::Function(Function::Return),
+    };
+    Ok(command)
+}
+
+// Indices are also pushed as constants, so they are limited to 15 bits
+const MAX_INDEX: u16 = 32767;
+
+fn segment_index(
+    (segment, span): &Spanned<String>,
+    word: &Spanned<String>,
+) -> Result<(Segment, u16), Simple<char, Span>> {
+    let (segment, max) = match segment.as_str() {
+        "argument" => (Segment::Argument, MAX_INDEX),
+        "local" => (Segment::Local, MAX_INDEX),
+        // Where statics and temps live depends on the runtime profile, so
+        //  their indices are checked when the program is verified
+        "static" => (Segment::Static, MAX_INDEX),
+        "constant" => (Segment::Constant, MAX_INDEX),
+        "this" => (Segment::This, MAX_INDEX),
+        "that" => (Segment::That, MAX_INDEX),
+        "pointer" => (Segment::Pointer, 1),
+        "temp" => (Segment::Temp, MAX_INDEX),
+        _ => {
+            return Err(Simple::custom(
+                *span,
+                format!("unknown segment `{}`", segment),
+            ))
+        }
+    };
+    Ok((segment, index(word, max)?))
+}
+
+fn index((word, span): &Spanned<String>, max: u16) -> Result<u16, Simple<char, Span>> {
+    if word.is_empty() || !word.chars().all(|c| c.is_ascii_digit()) {
+        return Err(Simple::custom(
+            *span,
+            format!("expected an index, found `{}`", word),
+        ));
+    }
+    match word.parse::<u16>() {
+        Ok(i) if i <= max => Ok(i),
+        _ => Err(Simple::custom(
+            *span,
+            format!("index {} is out of range 0..={}", word, max),
+        )),
+    }
+}
+
+// Symbols are sequences of letters, digits, `_`, `.`, `$` and `:` that do not
+//  begin with a digit
+fn symbol((word, span): &Spanned<String>) -> Result<String, Simple<char, Span>> {
+    let valid = !word.starts_with(|c: char| c.is_ascii_digit())
+        && word
+            .chars()
+            .all(|c| c.is_alphanumeric() || "_.$:".contains(c));
+    match valid {
+        true => Ok(word.to_string()),
+        false => Err(Simple::custom(*span, format!("invalid symbol `{}`", word))),
+    }
+}
@@ -1,6 +1,10 @@
 use crate::ast::asm::*;
 use crate::ast::vm::*;
+use crate::compiler::diagnostic::Diagnostic;
+use crate::compiler::runtime::{static_count, RuntimeProfile};
+use crate::compiler::source_map::VmOrigin;
 
+use std::collections::HashSet;
 use std::mem::take;
 
 enum CallPush {
@@ -8,23 +12,185 @@ enum CallPush {
     False,
 }
 
+// How calls, returns and comparisons are translated. Inline code is faster,
+//  while shared routines keep large programs within the 32K ROM: each use
+//  only passes its operands in R13-R15 and jumps to a routine emitted once
+//  at the end of the program.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Routines {
+    #[default]
+    Inline,
+    Shared,
+}
+
+// The shared routines a program uses
+#[derive(Clone, PartialEq)]
+enum Routine {
+    Call,
+    Return,
+    Comparison(Comparison),
+}
+
 pub struct VmToAsm {
     file_name: String,
     func_name: String,
     label_count: u64,
+    routines: Routines,
+    profile: RuntimeProfile,
+    used_routines: Vec<Routine>,
     assembly_stack: Vec<Assembly>,
+    // The command being translated, and the one each instruction came from
+    origin: Option<VmOrigin>,
+    origins: Vec<Option<VmOrigin>>,
 }
 
 impl VmToAsm {
     pub fn compile(file_name: String, commands: Vec<Command>) -> Vec<Assembly> {
+        Self::compile_program(vec![(file_name, commands)])
+    }
+
+    // Translates every file of a program into a single assembly stream.
+    //  The bootstrap is emitted once and the label counter is shared, so
+    //  return addresses and comparison labels stay unique across files.
+    pub fn compile_program(files: Vec<(String, Vec<Command>)>) -> Vec<Assembly> {
+        Self::compile_program_with(files, Routines::Inline, &RuntimeProfile::default())
+    }
+
+    pub fn compile_program_with(
+        files: Vec<(String, Vec<Command>)>,
+        routines: Routines,
+        profile: &RuntimeProfile,
+    ) -> Vec<Assembly> {
+        Self::compile_program_with_origins(files, routines, profile).0
+    }
+
+    // Also returns the VM command each instruction was translated from
+    pub fn compile_program_with_origins(
+        files: Vec<(String, Vec<Command>)>,
+        routines: Routines,
+        profile: &RuntimeProfile,
+    ) -> (Vec<Assembly>, Vec<Option<VmOrigin>>) {
         let mut virtual_machine = Self {
-            file_name,
+            file_name: "Bootstrap".to_string(),
             func_name: "".to_string(),
             label_count: 1,
+            routines,
+            profile: profile.clone(),
+            used_routines: Vec::new(),
             assembly_stack: Vec::new(),
+            origin: None,
+            origins: Vec::new(),
         };
-        let asm = virtual_machine.compile_commands(commands);
-        take(&mut asm.assembly_stack)
+        virtual_machine.compile_bootstrap();
+        files
+            .into_iter()
+            .enumerate()
+            .for_each(|(file, (file_name, commands))| {
+                virtual_machine.file_name = file_name;
+                virtual_machine.func_name = "".to_string();
+                virtual_machine.compile_commands(file, commands);
+            });
+        virtual_machine.origin = None;
+        virtual_machine.compile_routines();
+        (
+            take(&mut virtual_machine.assembly_stack),
+            take(&mut virtual_machine.origins),
+        )
+    }
+
+    // Labels are translated to `Function$label`, so each function has labels
+    //  of its own. Reject the programs where that would make two labels the
+    //  same, or where a `goto` or `if-goto` jumps to a label its function does
+    //  not declare. Labels before the first function of a file are scoped to
+    //  the file's code outside any function, as `File.vm$label`.
+    //
+    //  The files are verified as one program, so a `call` must name a
+    //  function that one of them defines, and their temps and statics must
+    //  fit in the ranges of the profile. A program that is not linked with the
+    //  OS may still call into it, so those calls are only warned about, and the
+    //  warnings are returned.
+    pub fn verify(
+        files: &[(String, Vec<Command>)],
+        profile: &RuntimeProfile,
+    ) -> Result<Vec<Diagnostic>, Vec<Diagnostic>> {
+        let defined: HashSet<&str> = files
+            .iter()
+            .flat_map(|(_, commands)| commands)
+            .filter_map(|command| match command {
+                Command::Function(Function::Body(name, _)) => Some(name.as_str()),
+                _ => None,
+            })
+            .collect();
+        let mut diagnostics = Vec::new();
+        let temps = profile.temp.len() as u16;
+        let mut statics = 0;
+        for (file_name, commands) in files {
+            let file_path = format!("{}.vm", file_name);
+            // Reported once, in the first file that does not fit
+            let fitted = statics <= profile.statics.len();
+            statics += static_count(commands);
+            if fitted && statics > profile.statics.len() {
+                diagnostics.push(Diagnostic::error(
+                    &file_path,
+                    None,
+                    format!(
+                        "the statics of `{}` do not fit in RAM[{}..{}]",
+                        file_name, profile.statics.start, profile.statics.end
+                    ),
+                ));
+            }
+            let mut function = "";
+            let mut labels = HashSet::new();
+            let mut targets = Vec::new();
+            // The targets are checked once every label of the function is known
+            for command in commands {
+                match command {
+                    Command::Function(Function::Body(name, _)) => {
+                        diagnostics
+                            .extend(undefined_labels(&file_path, function, &labels, &targets));
+                        function = name;
+                        labels.clear();
+                        targets.clear();
+                    }
+                    Command::Branch(Branch::Label(label)) if !labels.insert(label.as_str()) => {
+                        diagnostics.push(Diagnostic::error(
+                            &file_path,
+                            None,
+                            format!("duplicate label `{}` {}", label, scope(function)),
+                        ));
+                    }
+                    Command::Branch(Branch::Goto(label) | Branch::IfGoto(label)) => {
+                        targets.push(label.as_str())
+                    }
+                    Command::Stack(
+                        Stack::Push(Segment::Temp, i) | Stack::Pop(Segment::Temp, i),
+                    ) if *i >= temps => {
+                        diagnostics.push(Diagnostic::error(
+                            &file_path,
+                            None,
+                            format!("temp {} is out of range {}", i, scope(function)),
+                        ));
+                    }
+                    Command::Function(Function::Call(name, _))
+                        if !defined.contains(name.as_str()) =>
+                    {
+                        let message =
+                            format!("undefined function `{}` called {}", name, scope(function));
+                        diagnostics.push(match is_os_function(name) {
+                            true => Diagnostic::warning(&file_path, None, message)
+                                .with_note("the OS is not linked with this program".to_string()),
+                            false => Diagnostic::error(&file_path, None, message),
+                        });
+                    }
+                    _ => (),
+                }
+            }
+            diagnostics.extend(undefined_labels(&file_path, function, &labels, &targets));
+        }
+        match diagnostics.iter().any(|d| d.is_error()) {
+            true => Err(diagnostics),
+            false => Ok(diagnostics),
+        }
     }
 
     fn inc_label(&mut self) {
@@ -33,6 +199,7 @@ impl VmToAsm {
 
     fn push(&mut self, asm: Assembly) -> &mut Self {
         self.assembly_stack.push(asm);
+        self.origins.push(self.origin);
         self
     }
 
@@ -52,20 +219,26 @@ impl VmToAsm {
         self.push(Assembly::Label(name))
     }
 
-    fn compile_commands(&mut self, commands: Vec<Command>) -> &mut Self {
-        self.compile_bootstrap();
-        commands.into_iter().for_each(|command| {
+    fn compile_commands(&mut self, file: usize, commands: Vec<Command>) -> &mut Self {
+        commands.into_iter().enumerate().for_each(|(i, command)| {
+            self.origin = Some(VmOrigin { file, command: i });
             self.compile_command(command);
         });
         self
     }
 
     fn compile_bootstrap(&mut self) {
-        self.push_a(AInstruction::Constant(256))
-            .push_c(Some(Dest::D), Comp::A, None)
-            .push_a(AInstruction::Symbol("SP".to_string()))
-            .push_c(Some(Dest::M), Comp::D, None)
-            .compile_function(Function::Call("Sys.init".to_string(), 0));
+        if !self.profile.bootstrap {
+            return;
+        }
+        for (register, value) in self.profile.registers() {
+            self.push_a(AInstruction::Constant(value))
+                .push_c(Some(Dest::D), Comp::A, None)
+                .push_a(AInstruction::Symbol(show_register(register)))
+                .push_c(Some(Dest::M), Comp::D, None);
+        }
+        let entry = self.profile.entry.clone();
+        self.compile_function(Function::Call(entry, 0));
     }
 
     fn compile_command(&mut self, command: Command) -> &mut Self {
@@ -103,10 +276,9 @@ impl VmToAsm {
                 self.push_a(AInstruction::Symbol(file_name))
                     .push_c(Some(Dest::D), Comp::M, None)
             }
-            Segment::Temp => {
-                self.push_a(AInstruction::Constant(i + 5))
-                    .push_c(Some(Dest::D), Comp::M, None)
-            }
+            Segment::Temp => self
+                .push_a(AInstruction::Constant(self.profile.temp.start + i))
+                .push_c(Some(Dest::D), Comp::M, None),
             seg => match bool {
                 CallPush::True => self.push_a(AInstruction::Symbol(show_segment(seg))).push_c(
                     Some(Dest::D),
@@ -152,12 +324,14 @@ impl VmToAsm {
                     .push_a(AInstruction::Symbol(symbol.to_string()))
                     .push_c(Some(Dest::M), Comp::D, None)
             }
-            Segment::Temp => self
-                .push_a(AInstruction::Symbol("SP".to_string()))
-                .push_c(Some(Dest::AM), Comp::MMinusOne, None)
-                .push_c(Some(Dest::D), Comp::M, None)
-                .push_a(AInstruction::Constant(i + 5))
-                .push_c(Some(Dest::M), Comp::D, None),
+            Segment::Temp => {
+                let address = self.profile.temp.start + i;
+                self.push_a(AInstruction::Symbol("SP".to_string()))
+                    .push_c(Some(Dest::AM), Comp::MMinusOne, None)
+                    .push_c(Some(Dest::D), Comp::M, None)
+                    .push_a(AInstruction::Constant(address))
+                    .push_c(Some(Dest::M), Comp::D, None)
+            }
             Segment::Static => {
                 let file_name = format!("{}.{}", &self.file_name, i);
                 self.push_a(AInstruction::Symbol("SP".to_string()))
@@ -202,7 +376,26 @@ impl VmToAsm {
         }
     }
 
+    // Jumps on `x - y`, which wraps around, see `Comparison::holds`
     fn compile_comparison(&mut self, comp: Comparison) -> &mut Self {
+        if self.routines == Routines::Shared {
+            let return_address = format!(
+                "{}_return_{}",
+                show_comparison(comp.clone()),
+                self.label_count
+            );
+            self.inc_label();
+            let routine = show_routine(&Routine::Comparison(comp.clone()));
+            return self
+                .use_routine(Routine::Comparison(comp))
+                .push_a(AInstruction::Symbol(return_address.clone()))
+                .push_c(Some(Dest::D), Comp::A, None)
+                .push_a(AInstruction::Symbol("R15".to_string()))
+                .push_c(Some(Dest::M), Comp::D, None)
+                .push_a(AInstruction::Symbol(routine))
+                .push_c(None, Comp::Zero, Some(Jump::JMP))
+                .push_label(return_address);
+        }
         let i = self.label_count;
         self.inc_label();
         let comp_s = show_comparison(comp.clone());
@@ -252,68 +445,65 @@ impl VmToAsm {
 
     fn compile_branch(&mut self, branch: Branch) -> &mut Self {
         match branch {
-            Branch::Label(l) => self.push_label(format!("{}${}", &self.func_name, l)),
+            Branch::Label(l) => self.push_label(self.scoped_label(&l)),
             Branch::Goto(l) => self
-                .push_a(AInstruction::Symbol(format!("{}${}", &self.func_name, l)))
+                .push_a(AInstruction::Symbol(self.scoped_label(&l)))
                 .push_c(None, Comp::Zero, Some(Jump::JMP)),
             Branch::IfGoto(l) => {
-                let func_name = format!("{}${}", &self.func_name, l);
+                let label = self.scoped_label(&l);
                 self.push_a(AInstruction::Symbol("SP".to_string()))
                     .push_c(Some(Dest::AM), Comp::MMinusOne, None)
                     .push_c(Some(Dest::D), Comp::M, None)
                     .push_c(Some(Dest::A), Comp::AMinusOne, None)
-                    .push_a(AInstruction::Symbol(func_name))
+                    .push_a(AInstruction::Symbol(label))
                     .push_c(None, Comp::D, Some(Jump::JNE))
             }
         }
     }
 
+    // `Function$label`, or `File.vm$label` outside any function, so that the
+    //  labels of different files and the shared routines never meet
+    fn scoped_label(&self, label: &str) -> String {
+        match self.func_name.as_str() {
+            "" => format!("{}.vm${}", self.file_name, label),
+            function => format!("{}${}", function, label),
+        }
+    }
+
     fn compile_function(&mut self, func: Function) -> &mut Self {
         match func {
             Function::Body(s, i) => {
-                self.label_count = 1;
+                self.func_name = s.clone();
                 self.push_label(s).compile_function_locals(i)
             }
-            Function::Return => {
-                self.push_a(AInstruction::Symbol("LCL".to_string()))
-                    .push_c(Some(Dest::D), Comp::M, None)
-                    .push_a(AInstruction::Symbol("R13".to_string()))
-                    .push_c(Some(Dest::M), Comp::D, None)
-                    //
-                    .push_a(AInstruction::Constant(5))
-                    .push_c(Some(Dest::A), Comp::DMinusA, None)
-                    .push_c(Some(Dest::D), Comp::M, None)
-                    .push_a(AInstruction::Symbol("R14".to_string()))
-                    .push_c(Some(Dest::M), Comp::D, None)
-                    //
-                    .push_a(AInstruction::Symbol("SP".to_string()))
-                    .push_c(Some(Dest::AM), Comp::MMinusOne, None)
-                    .push_c(Some(Dest::D), Comp::M, None)
-                    .push_a(AInstruction::Symbol("ARG".to_string()))
-                    .push_c(Some(Dest::A), Comp::M, None)
-                    .push_c(Some(Dest::M), Comp::D, None)
-                    //
-                    .push_a(AInstruction::Symbol("ARG".to_string()))
-                    .push_c(Some(Dest::D), Comp::MPlusOne, None)
-                    .push_a(AInstruction::Symbol("SP".to_string()))
-                    .push_c(Some(Dest::M), Comp::D, None)
-                    //
-                    .compile_seg_reset(Segment::That)
-                    //
-                    .compile_seg_reset(Segment::This)
-                    //
-                    .compile_seg_reset(Segment::Argument)
-                    //
-                    .compile_seg_reset(Segment::Local)
-                    //
-                    .push_a(AInstruction::Symbol("R14".to_string()))
-                    .push_c(Some(Dest::A), Comp::M, None)
-                    .push_c(None, Comp::Zero, Some(Jump::JMP))
-            }
+            Function::Return if self.routines == Routines::Shared => self
+                .use_routine(Routine::Return)
+                .push_a(AInstruction::Symbol(show_routine(&Routine::Return)))
+                .push_c(None, Comp::Zero, Some(Jump::JMP)),
+            Function::Return => self.compile_return(),
             Function::Call(s, args) => {
                 let label_count = self.label_count;
                 self.label_count += 1;
                 let return_address = format!("{}.{}$ret.{}", &self.file_name, s, label_count);
+                if self.routines == Routines::Shared {
+                    return self
+                        .use_routine(Routine::Call)
+                        .push_a(AInstruction::Symbol(return_address.clone()))
+                        .push_c(Some(Dest::D), Comp::A, None)
+                        .push_a(AInstruction::Symbol("R15".to_string()))
+                        .push_c(Some(Dest::M), Comp::D, None)
+                        .push_a(AInstruction::Constant(args))
+                        .push_c(Some(Dest::D), Comp::A, None)
+                        .push_a(AInstruction::Symbol("R14".to_string()))
+                        .push_c(Some(Dest::M), Comp::D, None)
+                        .push_a(AInstruction::Symbol(s))
+                        .push_c(Some(Dest::D), Comp::A, None)
+                        .push_a(AInstruction::Symbol("R13".to_string()))
+                        .push_c(Some(Dest::M), Comp::D, None)
+                        .push_a(AInstruction::Symbol(show_routine(&Routine::Call)))
+                        .push_c(None, Comp::Zero, Some(Jump::JMP))
+                        .push_label(return_address);
+                }
                 self.push_a(AInstruction::Symbol(return_address.clone()))
                     .push_c(Some(Dest::D), Comp::A, None)
                     .push_pattern()
@@ -341,6 +531,116 @@ impl VmToAsm {
         }
     }
 
+    fn compile_return(&mut self) -> &mut Self {
+        self.push_a(AInstruction::Symbol("LCL".to_string()))
+            .push_c(Some(Dest::D), Comp::M, None)
+            .push_a(AInstruction::Symbol("R13".to_string()))
+            .push_c(Some(Dest::M), Comp::D, None)
+            //
+            .push_a(AInstruction::Constant(5))
+            .push_c(Some(Dest::A), Comp::DMinusA, None)
+            .push_c(Some(Dest::D), Comp::M, None)
+            .push_a(AInstruction::Symbol("R14".to_string()))
+            .push_c(Some(Dest::M), Comp::D, None)
+            //
+            .push_a(AInstruction::Symbol("SP".to_string()))
+            .push_c(Some(Dest::AM), Comp::MMinusOne, None)
+            .push_c(Some(Dest::D), Comp::M, None)
+            .push_a(AInstruction::Symbol("ARG".to_string()))
+            .push_c(Some(Dest::A), Comp::M, None)
+            .push_c(Some(Dest::M), Comp::D, None)
+            //
+            .push_a(AInstruction::Symbol("ARG".to_string()))
+            .push_c(Some(Dest::D), Comp::MPlusOne, None)
+            .push_a(AInstruction::Symbol("SP".to_string()))
+            .push_c(Some(Dest::M), Comp::D, None)
+            //
+            .compile_seg_reset(Segment::That)
+            //
+            .compile_seg_reset(Segment::This)
+            //
+            .compile_seg_reset(Segment::Argument)
+            //
+            .compile_seg_reset(Segment::Local)
+            //
+            .push_a(AInstruction::Symbol("R14".to_string()))
+            .push_c(Some(Dest::A), Comp::M, None)
+            .push_c(None, Comp::Zero, Some(Jump::JMP))
+    }
+
+    fn use_routine(&mut self, routine: Routine) -> &mut Self {
+        if !self.used_routines.contains(&routine) {
+            self.used_routines.push(routine);
+        }
+        self
+    }
+
+    // The shared routines are emitted after the last file, so that only the
+    //  ones the program uses take up ROM
+    fn compile_routines(&mut self) -> &mut Self {
+        take(&mut self.used_routines)
+            .into_iter()
+            .for_each(|routine| {
+                self.push_label(show_routine(&routine));
+                match routine {
+                    Routine::Call => self.compile_call_routine(),
+                    Routine::Return => self.compile_return(),
+                    Routine::Comparison(comp) => self.compile_comparison_routine(comp),
+                };
+            });
+        self
+    }
+
+    // Expects the return address in R15, the number of arguments in R14 and
+    //  the address of the function in R13
+    fn compile_call_routine(&mut self) -> &mut Self {
+        self.push_a(AInstruction::Symbol("R15".to_string()))
+            .push_c(Some(Dest::D), Comp::M, None)
+            .push_pattern()
+            .compile_push(Segment::Local, 0, CallPush::True)
+            .compile_push(Segment::Argument, 0, CallPush::True)
+            .compile_push(Segment::This, 0, CallPush::True)
+            .compile_push(Segment::That, 0, CallPush::True)
+            //
+            .push_a(AInstruction::Symbol("R14".to_string()))
+            .push_c(Some(Dest::D), Comp::M, None)
+            .push_a(AInstruction::Constant(5))
+            .push_c(Some(Dest::D), Comp::DPlusA, None)
+            .push_a(AInstruction::Symbol("SP".to_string()))
+            .push_c(Some(Dest::D), Comp::MMinusD, None)
+            .push_a(AInstruction::Symbol("ARG".to_string()))
+            .push_c(Some(Dest::M), Comp::D, None)
+            //
+            .push_a(AInstruction::Symbol("SP".to_string()))
+            .push_c(Some(Dest::D), Comp::M, None)
+            .push_a(AInstruction::Symbol("LCL".to_string()))
+            .push_c(Some(Dest::M), Comp::D, None)
+            //
+            .push_a(AInstruction::Symbol("R13".to_string()))
+            .push_c(Some(Dest::A), Comp::M, None)
+            .push_c(None, Comp::Zero, Some(Jump::JMP))
+    }
+
+    // Expects the return address in R15
+    fn compile_comparison_routine(&mut self, comp: Comparison) -> &mut Self {
+        let routine = show_routine(&Routine::Comparison(comp.clone()));
+        self.compile_binary()
+            .push_c(Some(Dest::D), Comp::MMinusD, None)
+            .push_c(Some(Dest::M), Comp::Zero, None)
+            .push_a(AInstruction::Symbol(format!("{}_TRUE", routine)))
+            .push_c(None, Comp::D, Some(comparison_to_jump(comp)))
+            .push_a(AInstruction::Symbol("R15".to_string()))
+            .push_c(Some(Dest::A), Comp::M, None)
+            .push_c(None, Comp::Zero, Some(Jump::JMP))
+            .push_label(format!("{}_TRUE", routine))
+            .push_a(AInstruction::Symbol("SP".to_string()))
+            .push_c(Some(Dest::A), Comp::MMinusOne, None)
+            .push_c(Some(Dest::M), Comp::NegOne, None)
+            .push_a(AInstruction::Symbol("R15".to_string()))
+            .push_c(Some(Dest::A), Comp::M, None)
+            .push_c(None, Comp::Zero, Some(Jump::JMP))
+    }
+
     fn compile_function_locals(&mut self, i: u16) -> &mut Self {
         match i {
             0 => self,
@@ -364,6 +664,41 @@ impl VmToAsm {
     }
 }
 
+// Whether a function belongs to one of the OS classes
+fn is_os_function(name: &str) -> bool {
+    let class = name.split('.').next().unwrap_or_default();
+    crate::os::library::CLASSES
+        .iter()
+        .any(|(os_class, _)| *os_class == class)
+}
+
+// Where a command is, for diagnostics
+fn scope(function: &str) -> String {
+    match function {
+        "" => "outside any function".to_string(),
+        function => format!("in `{}`", function),
+    }
+}
+
+fn undefined_labels(
+    file_path: &str,
+    function: &str,
+    labels: &HashSet<&str>,
+    targets: &[&str],
+) -> Vec<Diagnostic> {
+    targets
+        .iter()
+        .filter(|label| !labels.contains(*label))
+        .map(|label| {
+            Diagnostic::error(
+                file_path,
+                None,
+                format!("undefined label `{}` {}", label, scope(function)),
+            )
+        })
+        .collect()
+}
+
 fn show_comparison(comp: Comparison) -> String {
     match comp {
         Comparison::Eq => "EQ".to_string(),
@@ -372,6 +707,14 @@ fn show_comparison(comp: Comparison) -> String {
     }
 }
 
+fn show_routine(routine: &Routine) -> String {
+    match routine {
+        Routine::Call => "$CALL".to_string(),
+        Routine::Return => "$RETURN".to_string(),
+        Routine::Comparison(comp) => format!("${}", show_comparison(comp.clone())),
+    }
+}
+
 fn comparison_to_jump(comp: Comparison) -> Jump {
     match comp {
         Comparison::Eq => Jump::JEQ,
@@ -380,6 +723,16 @@ fn comparison_to_jump(comp: Comparison) -> Jump {
     }
 }
 
+fn show_register(register: u16) -> String {
+    match register {
+        crate::compiler::runtime::SP => "SP".to_string(),
+        crate::compiler::runtime::LCL => "LCL".to_string(),
+        crate::compiler::runtime::ARG => "ARG".to_string(),
+        crate::compiler::runtime::THIS => "THIS".to_string(),
+        _ => "THAT".to_string(),
+    }
+}
+
 fn show_segment(seg: Segment) -> String {
     match seg {
         Segment::This => "THIS".to_string(),
@@ -0,0 +1,202 @@
+// Emulator for the Hack computer (chapter 5): a 32K instruction ROM, a 32K
+//  data RAM with the screen and keyboard memory maps, and the A, D and PC
+//  registers. Words are stored as `u16` and read back as signed values.
+pub const SCREEN: u16 = 16384;
+pub const KBD: u16 = 24576;
+
+const MEMORY_SIZE: usize = 32768;
+
+pub struct Hack {
+    rom: Vec<u16>,
+    ram: Vec<u16>,
+    a: u16,
+    d: u16,
+    pc: u16,
+    cycles: usize,
+}
+
+impl Hack {
+    pub fn new(rom: Vec<u16>) -> Self {
+        Self {
+            rom,
+            ram: vec![0; MEMORY_SIZE],
+            a: 0,
+            d: 0,
+            pc: 0,
+            cycles: 0,
+        }
+    }
+
+    // Load the textual output of `Assembler::assemble`, one 16-bit binary
+    //  word per line
+    pub fn from_binary(lines: &[String]) -> Result<Self, String> {
+        let rom = lines
+            .iter()
+            .map(|line| line.trim())
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(i, line)| {
+                if line.len() != 16 {
+                    return Err(format!(
+                        "line {}: expected 16 bits, found `{}`",
+                        i + 1,
+                        line
+                    ));
+                }
+                u16::from_str_radix(line, 2)
+                    .map_err(|_| format!("line {}: invalid instruction `{}`", i + 1, line))
+            })
+            .collect::<Result<Vec<u16>, String>>()?;
+        if rom.len() > MEMORY_SIZE {
+            return Err(format!(
+                "program has {} instructions, the ROM holds {}",
+                rom.len(),
+                MEMORY_SIZE
+            ));
+        }
+        Ok(Self::new(rom))
+    }
+
+    // Accessors:
+    pub fn ram(&self, address: u16) -> i16 {
+        self.ram[address as usize % MEMORY_SIZE] as i16
+    }
+
+    pub fn set_ram(&mut self, address: u16, value: i16) -> &mut Self {
+        self.ram[address as usize % MEMORY_SIZE] = value as u16;
+        self
+    }
+
+    pub fn screen(&self) -> &[u16] {
+        &self.ram[SCREEN as usize..KBD as usize]
+    }
+
+    pub fn set_key(&mut self, key: u16) -> &mut Self {
+        self.ram[KBD as usize] = key;
+        self
+    }
+
+    pub fn a(&self) -> i16 {
+        self.a as i16
+    }
+
+    pub fn d(&self) -> i16 {
+        self.d as i16
+    }
+
+    pub fn pc(&self) -> u16 {
+        self.pc
+    }
+
+    pub fn cycles(&self) -> usize {
+        self.cycles
+    }
+
+    // Clear the registers and the program counter, keeping RAM and ROM
+    pub fn reset(&mut self) -> &mut Self {
+        self.a = 0;
+        self.d = 0;
+        self.pc = 0;
+        self
+    }
+
+    // Execution:
+    pub fn step(&mut self) -> &mut Self {
+        let instruction = self.fetch(self.pc);
+        self.cycles += 1;
+        if instruction & 0x8000 == 0 {
+            self.a = instruction;
+            self.pc = self.pc.wrapping_add(1);
+            return self;
+        }
+
+        // Jumps and M use the value of A before this instruction writes to it
+        let target = self.a;
+        let address = self.a as usize % MEMORY_SIZE;
+        let y = if instruction & 0x1000 == 0 {
+            self.a
+        } else {
+            self.ram[address]
+        };
+        let out = alu(self.d, y, (instruction >> 6) & 0x3F);
+
+        if instruction & 0x20 != 0 {
+            self.a = out;
+        }
+        if instruction & 0x10 != 0 {
+            self.d = out;
+        }
+        if instruction & 0x08 != 0 {
+            self.ram[address] = out;
+        }
+
+        let out = out as i16;
+        let jump = instruction & 0x07;
+        let taken = (jump & 0x04 != 0 && out < 0)
+            || (jump & 0x02 != 0 && out == 0)
+            || (jump & 0x01 != 0 && out > 0);
+        self.pc = if taken {
+            target
+        } else {
+            self.pc.wrapping_add(1)
+        };
+        self
+    }
+
+    pub fn run(&mut self, cycles: usize) -> &mut Self {
+        (0..cycles).for_each(|_| {
+            self.step();
+        });
+        self
+    }
+
+    // Run until the program reaches a halt loop, e.g. `(END) @END 0;JMP`, or
+    //  until `max_cycles` have elapsed. Returns whether the program halted.
+    pub fn run_until_halt(&mut self, max_cycles: usize) -> bool {
+        for _ in 0..max_cycles {
+            if self.is_halted() {
+                return true;
+            }
+            self.step();
+        }
+        self.is_halted()
+    }
+
+    // A program is halted when it has run off the end of the ROM or is
+    //  about to jump unconditionally to where it already is
+    pub fn is_halted(&self) -> bool {
+        if self.pc as usize >= self.rom.len() {
+            return true;
+        }
+        let instruction = self.fetch(self.pc);
+        let next = self.fetch(self.pc.wrapping_add(1));
+        (instruction == self.pc && is_goto(next)) || (is_goto(instruction) && self.a == self.pc)
+    }
+
+    fn fetch(&self, address: u16) -> u16 {
+        self.rom.get(address as usize).copied().unwrap_or(0)
+    }
+}
+
+// An unconditional jump that writes nothing, e.g. `0;JMP`
+fn is_goto(instruction: u16) -> bool {
+    instruction & 0x8000 != 0 && instruction & 0x07 == 0x07 && instruction & 0x38 == 0
+}
+
+// The Hack ALU (Figure 2.6), driven by the six control bits zx nx zy ny f no
+fn alu(x: u16, y: u16, control: u16) -> u16 {
+    let x = if control & 0x20 != 0 { 0 } else { x };
+    let x = if control & 0x10 != 0 { !x } else { x };
+    let y = if control & 0x08 != 0 { 0 } else { y };
+    let y = if control & 0x04 != 0 { !y } else { y };
+    let out = if control & 0x02 != 0 {
+        x.wrapping_add(y)
+    } else {
+        x & y
+    };
+    if control & 0x01 != 0 {
+        !out
+    } else {
+        out
+    }
+}
@@ -0,0 +1,300 @@
+use crate::ast::tst::*;
+use crate::compiler::diagnostic::Diagnostic;
+use crate::compiler::runtime::RuntimeProfile;
+use crate::emulator::hack::Hack;
+use crate::emulator::vm::Vm;
+
+use std::path::{Path, PathBuf};
+
+// Interpreter for `.tst` test scripts. A script loads a program into one of
+//  the emulators, drives it with `ticktock` (CPU) or `vmstep` (VM) and writes
+//  the variables of its output list to an `.out` file, which is compared line
+//  by line against the `.cmp` file as it is written.
+enum Machine {
+    Empty,
+    Cpu(Hack),
+    Vm(Vm),
+}
+
+pub struct Script {
+    script_path: String,
+    dir: PathBuf,
+    machine: Machine,
+    output_file: Option<PathBuf>,
+    compare_to: Option<Vec<String>>,
+    output_list: Vec<OutputColumn>,
+    output: Vec<String>,
+}
+
+impl Script {
+    pub fn run(script_path: &str, commands: Vec<ScriptCommand>) -> Result<(), Vec<Diagnostic>> {
+        let mut script = Self {
+            script_path: script_path.to_string(),
+            dir: Path::new(script_path)
+                .parent()
+                .map_or(PathBuf::new(), |p| p.to_path_buf()),
+            machine: Machine::Empty,
+            output_file: None,
+            compare_to: None,
+            output_list: Vec::new(),
+            output: Vec::new(),
+        };
+        let result = script.execute_commands(&commands);
+        script.write_output()?;
+        result
+    }
+
+    fn error(&self, message: String) -> Vec<Diagnostic> {
+        vec![Diagnostic::error(&self.script_path, None, message)]
+    }
+
+    fn execute_commands(&mut self, commands: &[ScriptCommand]) -> Result<(), Vec<Diagnostic>> {
+        commands
+            .iter()
+            .try_for_each(|command| self.execute_command(command))
+    }
+
+    fn execute_command(&mut self, command: &ScriptCommand) -> Result<(), Vec<Diagnostic>> {
+        match command {
+            ScriptCommand::Load(file) => self.load(file.as_deref()),
+            ScriptCommand::OutputFile(file) => {
+                self.output_file = Some(self.dir.join(file));
+                Ok(())
+            }
+            ScriptCommand::CompareTo(file) => {
+                let path = self.dir.join(file);
+                let contents = std::fs::read_to_string(&path)
+                    .map_err(|e| vec![Diagnostic::from_io(path.to_str().unwrap_or(file), e)])?;
+                self.compare_to = Some(contents.lines().map(|l| l.to_string()).collect());
+                Ok(())
+            }
+            ScriptCommand::OutputList(columns) => {
+                self.output_list = columns.clone();
+                let header = columns.iter().map(print_header).collect::<String>();
+                self.output_line(format!("|{}", header))
+            }
+            ScriptCommand::Output => {
+                let values = self
+                    .output_list
+                    .iter()
+                    .map(|column| {
+                        self.read(&column.variable)
+                            .map(|value| print_value(column, value))
+                    })
+                    .collect::<Result<String, _>>()?;
+                self.output_line(format!("|{}", values))
+            }
+            ScriptCommand::Set(variable, value) => self.write(variable, *value),
+            ScriptCommand::Repeat(Some(n), commands) => {
+                (0..*n).try_for_each(|_| self.execute_commands(commands))
+            }
+            ScriptCommand::Repeat(None, _) => {
+                Err(self.error("`repeat` without a count is not supported".to_string()))
+            }
+            ScriptCommand::TickTock => match &mut self.machine {
+                Machine::Cpu(cpu) => {
+                    cpu.step();
+                    Ok(())
+                }
+                _ => Err(self
+                    .error("`ticktock` requires a loaded `.asm` or `.hack` program".to_string())),
+            },
+            ScriptCommand::VmStep => match &mut self.machine {
+                Machine::Vm(vm) => match vm.step() {
+                    Ok(_) => Ok(()),
+                    Err(e) => Err(self.error(e)),
+                },
+                _ => Err(self.error("`vmstep` requires a loaded VM program".to_string())),
+            },
+            ScriptCommand::Echo(message) => {
+                eprintln!("{}", message);
+                Ok(())
+            }
+        }
+    }
+
+    // `load` without a file name loads every VM file of the script's directory
+    fn load(&mut self, file: Option<&str>) -> Result<(), Vec<Diagnostic>> {
+        let path = match file {
+            Some(file) => self.dir.join(file),
+            None => self.dir.clone(),
+        };
+        let path = path.to_str().unwrap_or_default().to_string();
+        self.machine = match Path::new(&path).extension().and_then(|s| s.to_str()) {
+            Some("asm") => {
+                let asm = crate::parse_asm_file(&path)?;
+                let hack = crate::compiler::assembler::Assembler::assemble(asm);
+                Machine::Cpu(Hack::from_binary(&hack).map_err(|e| self.error(e))?)
+            }
+            Some("hack") => Machine::Cpu(crate::load_hack_file(&path)?),
+            Some("vm") | None => Machine::Vm(crate::load_vm(&path)?),
+            Some(extension) => {
+                return Err(self.error(format!("cannot load a `.{}` file", extension)))
+            }
+        };
+        Ok(())
+    }
+
+    fn output_line(&mut self, line: String) -> Result<(), Vec<Diagnostic>> {
+        let line_number = self.output.len() + 1;
+        let expected = self
+            .compare_to
+            .as_ref()
+            .map(|lines| lines.get(line_number - 1).cloned().unwrap_or_default());
+        self.output.push(line.to_string());
+        match expected {
+            Some(expected) if expected != line => Err(vec![Diagnostic::error(
+                &self.script_path,
+                None,
+                format!("comparison failure at line {}", line_number),
+            )
+            .with_note(format!("expected: {}", expected))
+            .with_note(format!("found:    {}", line))]),
+            _ => Ok(()),
+        }
+    }
+
+    // Lines end in CRLF like the output of the nand2tetris tools, so that the
+    //  shipped `.out` files are reproduced exactly
+    fn write_output(&self) -> Result<(), Vec<Diagnostic>> {
+        match &self.output_file {
+            None => Ok(()),
+            Some(path) => {
+                let contents: String = self.output.iter().map(|l| format!("{}\r\n", l)).collect();
+                std::fs::write(path, contents)
+                    .map_err(|e| vec![Diagnostic::from_io(path.to_str().unwrap_or_default(), e)])
+            }
+        }
+    }
+
+    // Variables:
+    fn ram(&self, address: u16) -> Result<i16, Vec<Diagnostic>> {
+        match &self.machine {
+            Machine::Cpu(cpu) => Ok(cpu.ram(address)),
+            Machine::Vm(vm) => Ok(vm.ram(address)),
+            Machine::Empty => Err(self.error("no program is loaded".to_string())),
+        }
+    }
+
+    fn set_ram(&mut self, address: u16, value: i16) -> Result<(), Vec<Diagnostic>> {
+        match &mut self.machine {
+            Machine::Cpu(cpu) => {
+                cpu.set_ram(address, value);
+            }
+            Machine::Vm(vm) => {
+                vm.set_ram(address, value);
+            }
+            Machine::Empty => return Err(self.error("no program is loaded".to_string())),
+        };
+        Ok(())
+    }
+
+    // The RAM address a variable refers to, if it lives in RAM
+    fn address(&self, variable: &Variable) -> Result<Option<u16>, Vec<Diagnostic>> {
+        match variable {
+            Variable::Ram(address) => Ok(Some(*address)),
+            Variable::Register(name) => Ok(pointer(name)),
+            Variable::Segment(name, i) => match (name.as_str(), pointer(name)) {
+                ("temp", _) => self.temp(*i).map(Some),
+                (_, Some(pointer)) => Ok(Some((self.ram(pointer)? as u16).wrapping_add(*i))),
+                _ => Err(self.error(format!("unknown segment `{}`", name))),
+            },
+        }
+    }
+
+    // Temps live where the profile of the loaded VM program puts them. An
+    //  assembly program does not say how it was translated, so the default
+    //  layout is assumed.
+    fn temp(&self, i: u16) -> Result<u16, Vec<Diagnostic>> {
+        let temp = match &self.machine {
+            Machine::Vm(vm) => vm.profile().temp.clone(),
+            _ => RuntimeProfile::default().temp,
+        };
+        match (i as usize) < temp.len() {
+            true => Ok(temp.start + i),
+            false => Err(self.error(format!("temp {} is out of range", i))),
+        }
+    }
+
+    fn read(&self, variable: &Variable) -> Result<i16, Vec<Diagnostic>> {
+        if let Some(address) = self.address(variable)? {
+            return self.ram(address);
+        }
+        match (&self.machine, variable) {
+            (Machine::Cpu(cpu), Variable::Register(name)) => match name.as_str() {
+                "A" => Ok(cpu.a()),
+                "D" => Ok(cpu.d()),
+                "PC" => Ok(cpu.pc() as i16),
+                "time" => Ok(cpu.cycles() as i16),
+                _ => Err(self.error(format!("unknown variable `{}`", name))),
+            },
+            (_, _) => Err(self.error(format!("unknown variable `{}`", show_variable(variable)))),
+        }
+    }
+
+    fn write(&mut self, variable: &Variable, value: i16) -> Result<(), Vec<Diagnostic>> {
+        match self.address(variable)? {
+            Some(address) => self.set_ram(address, value),
+            None => Err(self.error(format!("cannot set `{}`", show_variable(variable)))),
+        }
+    }
+}
+
+// The VM registers, which are stored at the bottom of RAM
+fn pointer(name: &str) -> Option<u16> {
+    match name {
+        "sp" => Some(0),
+        "local" => Some(1),
+        "argument" => Some(2),
+        "this" => Some(3),
+        "that" => Some(4),
+        _ => None,
+    }
+}
+
+fn show_variable(variable: &Variable) -> String {
+    match variable {
+        Variable::Ram(address) => format!("RAM[{}]", address),
+        Variable::Register(name) => name.to_string(),
+        Variable::Segment(name, i) => format!("{}[{}]", name, i),
+    }
+}
+
+// Printing:
+//  | RAM[0] |RAM[256]|
+//  |    257 |     15 |
+// Headers are centered over the whole column and cut to fit, values are
+//  right-aligned within their width.
+fn print_header(column: &OutputColumn) -> String {
+    let total = column.left + column.width + column.right;
+    let name: String = show_variable(&column.variable)
+        .chars()
+        .take(total)
+        .collect();
+    let left = (total - name.len()) / 2;
+    format!(
+        "{}{}{}|",
+        " ".repeat(left),
+        name,
+        " ".repeat(total - left - name.len())
+    )
+}
+
+fn print_value(column: &OutputColumn, value: i16) -> String {
+    let value = match column.format {
+        Format::Decimal => value.to_string(),
+        Format::Hexadecimal => format!("{:04X}", value as u16),
+        Format::Binary => format!("{:016b}", value as u16),
+    };
+    let value: String = value
+        .chars()
+        .skip(value.len().saturating_sub(column.width))
+        .collect();
+    format!(
+        "{}{:>width$}{}|",
+        " ".repeat(column.left),
+        value,
+        " ".repeat(column.right),
+        width = column.width
+    )
+}
@@ -0,0 +1,354 @@
+use crate::ast::vm::*;
+use crate::compiler::runtime::{static_count, RuntimeProfile};
+
+use std::collections::HashMap;
+
+// Emulator for VM programs (chapters 7 and 8), executing `Command`s directly
+//  rather than their translation to assembly. Segments and call frames live in
+//  the same RAM layout the assembly backend uses, so both can be compared word
+//  for word: SP, LCL, ARG, THIS and THAT in RAM[0..5], then temp and the
+//  static variables of each file where the `RuntimeProfile` puts them.
+const MEMORY_SIZE: usize = 32768;
+const SP: usize = 0;
+const LCL: usize = 1;
+const ARG: usize = 2;
+const THIS: usize = 3;
+const THAT: usize = 4;
+
+struct Instruction {
+    command: Command,
+    file: usize,
+    function: String,
+}
+
+pub struct Vm {
+    program: Vec<Instruction>,
+    functions: HashMap<String, usize>,
+    labels: HashMap<String, usize>,
+    statics: Vec<usize>,
+    profile: RuntimeProfile,
+    ram: Vec<i16>,
+    pc: usize,
+    steps: usize,
+}
+
+impl Vm {
+    // Load the files of a program. Execution starts at `Sys.init` when the
+    //  program defines it and at the first command otherwise.
+    pub fn new(files: Vec<(String, Vec<Command>)>) -> Result<Self, String> {
+        Self::with_profile(files, RuntimeProfile::default())
+    }
+
+    // Load a program that starts at the entry function of `profile`, with its
+    //  temps and statics in the profile's ranges
+    pub fn with_profile(
+        files: Vec<(String, Vec<Command>)>,
+        profile: RuntimeProfile,
+    ) -> Result<Self, String> {
+        let mut vm = Self {
+            program: Vec::new(),
+            functions: HashMap::new(),
+            labels: HashMap::new(),
+            statics: Vec::new(),
+            profile,
+            ram: vec![0; MEMORY_SIZE],
+            pc: 0,
+            steps: 0,
+        };
+        let mut static_base = vm.profile.statics.start as usize;
+        for (file, (file_name, commands)) in files.into_iter().enumerate() {
+            vm.statics.push(static_base);
+            static_base += static_count(&commands);
+            if static_base > vm.profile.statics.end as usize {
+                return Err(format!(
+                    "{}: the statics do not fit in RAM[{}..{}]",
+                    file_name, vm.profile.statics.start, vm.profile.statics.end
+                ));
+            }
+            let mut function = String::new();
+            for command in commands {
+                match &command {
+                    Command::Function(Function::Body(name, _)) => {
+                        if vm.functions.contains_key(name) {
+                            return Err(format!("{}: duplicate function `{}`", file_name, name));
+                        }
+                        vm.functions.insert(name.to_string(), vm.program.len());
+                        function = name.to_string();
+                    }
+                    Command::Branch(Branch::Label(label)) => {
+                        let key = label_key(file, &function, label);
+                        if vm.labels.contains_key(&key) {
+                            return Err(format!(
+                                "{}: duplicate label `{}` in `{}`",
+                                file_name, label, function
+                            ));
+                        }
+                        vm.labels.insert(key, vm.program.len());
+                    }
+                    _ => (),
+                }
+                vm.program.push(Instruction {
+                    command,
+                    file,
+                    function: function.to_string(),
+                });
+            }
+        }
+        vm.pc = vm.functions.get(&vm.profile.entry).copied().unwrap_or(0);
+        Ok(vm)
+    }
+
+    // Accessors:
+    pub fn ram(&self, address: u16) -> i16 {
+        self.ram[address as usize % MEMORY_SIZE]
+    }
+
+    pub fn set_ram(&mut self, address: u16, value: i16) -> &mut Self {
+        self.ram[address as usize % MEMORY_SIZE] = value;
+        self
+    }
+
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn profile(&self) -> &RuntimeProfile {
+        &self.profile
+    }
+
+    // The function containing the next command to execute
+    pub fn current_function(&self) -> Option<&str> {
+        self.program
+            .get(self.pc)
+            .map(|instruction| instruction.function.as_str())
+    }
+
+    // Set the registers and call the entry function, as the bootstrap code of
+    //  the assembly backend does. Without the bootstrap only the registers are
+    //  set, and the program starts where it was loaded.
+    pub fn bootstrap(&mut self) -> Result<&mut Self, String> {
+        for (register, value) in self.profile.registers() {
+            self.ram[register as usize] = value as i16;
+        }
+        if !self.profile.bootstrap {
+            return Ok(self);
+        }
+        self.pc = self.program.len();
+        let entry = self.profile.entry.clone();
+        self.call(&entry, 0)
+    }
+
+    // Execution:
+    // Execute the next command. Labels are not commands of their own and are
+    //  skipped, matching the step count of the nand2tetris VM emulator.
+    pub fn step(&mut self) -> Result<&mut Self, String> {
+        self.skip_labels();
+        let instruction = match self.program.get(self.pc) {
+            Some(instruction) => instruction,
+            None => return Ok(self),
+        };
+        let command = instruction.command.clone();
+        self.steps += 1;
+        self.pc += 1;
+        match command {
+            Command::Stack(Stack::Push(segment, i)) => {
+                let value = match segment {
+                    Segment::Constant => i as i16,
+                    segment => {
+                        let address = self.address(&segment, i)?;
+                        self.ram[address]
+                    }
+                };
+                self.push(value);
+                Ok(self)
+            }
+            Command::Stack(Stack::Pop(segment, i)) => {
+                let address = self.address(&segment, i)?;
+                let value = self.pop();
+                self.ram[address] = value;
+                Ok(self)
+            }
+            Command::ACL(acl) => {
+                self.execute_acl(acl);
+                Ok(self)
+            }
+            Command::Branch(Branch::Label(_)) => Ok(self),
+            Command::Branch(Branch::Goto(label)) => {
+                self.pc = self.resolve_label(&label)?;
+                Ok(self)
+            }
+            Command::Branch(Branch::IfGoto(label)) => {
+                if self.pop() != 0 {
+                    self.pc = self.resolve_label(&label)?;
+                }
+                Ok(self)
+            }
+            Command::Function(Function::Body(_, n_vars)) => {
+                (0..n_vars).for_each(|_| self.push(0));
+                Ok(self)
+            }
+            Command::Function(Function::Call(name, n_args)) => self.call(&name, n_args),
+            Command::Function(Function::Return) => {
+                self.execute_return();
+                Ok(self)
+            }
+        }
+    }
+
+    pub fn run(&mut self, steps: usize) -> Result<&mut Self, String> {
+        for _ in 0..steps {
+            self.step()?;
+        }
+        Ok(self)
+    }
+
+    // Run until the program reaches a halt loop, e.g. `label END goto END`,
+    //  or until `max_steps` have elapsed. Returns whether the program halted.
+    pub fn run_until_halt(&mut self, max_steps: usize) -> Result<bool, String> {
+        for _ in 0..max_steps {
+            if self.is_halted() {
+                return Ok(true);
+            }
+            self.step()?;
+        }
+        Ok(self.is_halted())
+    }
+
+    // A program is halted when it has returned past its last command or is
+    //  about to jump to itself
+    pub fn is_halted(&self) -> bool {
+        let pc = self.next_command(self.pc);
+        match self.program.get(pc) {
+            None => true,
+            Some(instruction) => match &instruction.command {
+                Command::Branch(Branch::Goto(label)) => self
+                    .labels
+                    .get(&label_key(instruction.file, &instruction.function, label))
+                    .is_some_and(|target| self.next_command(*target) == pc),
+                _ => false,
+            },
+        }
+    }
+
+    fn next_command(&self, mut pc: usize) -> usize {
+        while let Some(Instruction {
+            command: Command::Branch(Branch::Label(_)),
+            ..
+        }) = self.program.get(pc)
+        {
+            pc += 1;
+        }
+        pc
+    }
+
+    fn skip_labels(&mut self) {
+        self.pc = self.next_command(self.pc);
+    }
+
+    fn push(&mut self, value: i16) {
+        let sp = self.ram[SP] as u16 as usize % MEMORY_SIZE;
+        self.ram[sp] = value;
+        self.ram[SP] = self.ram[SP].wrapping_add(1);
+    }
+
+    fn pop(&mut self) -> i16 {
+        self.ram[SP] = self.ram[SP].wrapping_sub(1);
+        self.ram[self.ram[SP] as u16 as usize % MEMORY_SIZE]
+    }
+
+    // The RAM address of `segment i`
+    fn address(&self, segment: &Segment, i: u16) -> Result<usize, String> {
+        let base = |pointer: usize| self.ram[pointer] as u16 as usize;
+        let address = match segment {
+            Segment::Local => base(LCL) + i as usize,
+            Segment::Argument => base(ARG) + i as usize,
+            Segment::This => base(THIS) + i as usize,
+            Segment::That => base(THAT) + i as usize,
+            Segment::Pointer if i < 2 => THIS + i as usize,
+            Segment::Temp if (i as usize) < self.profile.temp.len() => {
+                self.profile.temp.start as usize + i as usize
+            }
+            Segment::Static => {
+                let file = self.program[self.pc - 1].file;
+                self.statics[file] + i as usize
+            }
+            Segment::Pointer => return Err(format!("pointer {} is out of range", i)),
+            Segment::Temp => return Err(format!("temp {} is out of range", i)),
+            Segment::Constant => return Err("cannot pop to the constant segment".to_string()),
+        };
+        Ok(address % MEMORY_SIZE)
+    }
+
+    fn resolve_label(&self, label: &str) -> Result<usize, String> {
+        let Instruction { file, function, .. } = &self.program[self.pc - 1];
+        self.labels
+            .get(&label_key(*file, function, label))
+            .copied()
+            .ok_or_else(|| format!("undefined label `{}` in `{}`", label, function))
+    }
+
+    fn execute_acl(&mut self, acl: ACL) {
+        let value = match acl {
+            ACL::Arithmetic(Arithmetic::Neg) => self.pop().wrapping_neg(),
+            ACL::Logical(Logical::Not) => !self.pop(),
+            acl => {
+                let y = self.pop();
+                let x = self.pop();
+                match acl {
+                    ACL::Arithmetic(Arithmetic::Add) => x.wrapping_add(y),
+                    ACL::Arithmetic(Arithmetic::Sub) => x.wrapping_sub(y),
+                    ACL::Logical(Logical::And) => x & y,
+                    ACL::Logical(Logical::Or) => x | y,
+                    ACL::Comparison(comparison) => -(comparison.holds(x, y) as i16),
+                    ACL::Arithmetic(Arithmetic::Neg) | ACL::Logical(Logical::Not) => {
+                        unreachable!()
+                    }
+                }
+            }
+        };
+        self.push(value);
+    }
+
+    // Push the return address and the caller's frame, then jump to the callee
+    fn call(&mut self, name: &str, n_args: u16) -> Result<&mut Self, String> {
+        let target = match self.functions.get(name) {
+            Some(target) => *target,
+            None => return Err(format!("call to undefined function `{}`", name)),
+        };
+        self.push(self.pc as i16);
+        for pointer in [LCL, ARG, THIS, THAT] {
+            self.push(self.ram[pointer]);
+        }
+        self.ram[ARG] = self.ram[SP].wrapping_sub(5).wrapping_sub(n_args as i16);
+        self.ram[LCL] = self.ram[SP];
+        self.pc = target;
+        Ok(self)
+    }
+
+    fn execute_return(&mut self) {
+        let frame = self.ram[LCL] as u16 as usize;
+        let saved = |offset: usize| self.ram[frame.wrapping_sub(offset) % MEMORY_SIZE];
+        let return_address = saved(5) as u16 as usize;
+        let (that, this, arg, lcl) = (saved(1), saved(2), saved(3), saved(4));
+
+        let value = self.pop();
+        let arg_base = self.ram[ARG] as u16 as usize % MEMORY_SIZE;
+        self.ram[arg_base] = value;
+        self.ram[SP] = self.ram[ARG].wrapping_add(1);
+        self.ram[THAT] = that;
+        self.ram[THIS] = this;
+        self.ram[ARG] = arg;
+        self.ram[LCL] = lcl;
+        self.pc = return_address;
+    }
+}
+
+// Labels are scoped to the function that declares them, or to their file
+//  outside any function
+fn label_key(file: usize, function: &str, label: &str) -> String {
+    format!("{}:{}${}", file, function, label)
+}
@@ -0,0 +1,799 @@
+//! A compiler for the Jack language of the nand2tetris course, together with
+//! the rest of its toolchain: a VM translator, an assembler, emulators for the
+//! VM and the Hack computer, and the Jack OS.
+//!
+//! [`Compiler`] compiles whole programs. The functions of this crate run a
+//! single stage of the pipeline, and the modules expose the AST of every
+//! language along with the passes between them.
+//!
+//! ```
+//! use jack_compiler_rust::Compiler;
+//!
+//! let source = "class Main { function void main() { do Output.printInt(7); return; } }";
+//! let program = Compiler::new().compile_sources(&[("Main.jack", source)]).unwrap();
+//! let hack = Compiler::new().assemble(Compiler::new().vm_to_asm(program));
+//! assert!(!hack.is_empty());
+//! ```
+
+// The parsers report errors with chumsky's own `Simple` type, which is large
+//  but only built on the failure path.
+#![allow(clippy::result_large_err)]
+
+pub mod ast {
+    pub mod asm;
+    pub mod jack;
+    pub mod span;
+    pub mod token;
+    pub mod tst;
+    pub mod vm;
+}
+pub mod cli;
+pub mod compiler {
+    pub mod asm_parser;
+    pub mod assembler;
+    pub mod checker;
+    pub mod dead_code;
+    pub mod diagnostic;
+    pub mod inliner;
+    pub mod jack_to_vm;
+    pub mod lexer;
+    pub mod parser;
+    pub mod peephole;
+    pub mod precedence;
+    pub mod runtime;
+    pub mod source_map;
+    pub mod symbol_table;
+    pub mod tst_parser;
+    pub mod type_checker;
+    pub mod vm_optimizer;
+    pub mod vm_parser;
+    pub mod vm_to_asm;
+}
+pub mod emulator {
+    pub mod hack;
+    pub mod script;
+    pub mod vm;
+}
+mod os {
+    pub mod library;
+}
+pub mod pretty_printer {
+    pub mod asm;
+    pub mod diagnostic;
+    pub mod jack;
+    pub mod lexer;
+    pub mod source_map;
+    pub mod vm;
+    pub mod xml;
+}
+mod test {
+    #[allow(clippy::module_inception)]
+    pub mod tests;
+}
+
+use std::fs;
+
+use crate::compiler::diagnostic::Diagnostic;
+use crate::compiler::source_map::JackSource;
+use crate::compiler::type_checker::Strictness;
+use chumsky::Parser;
+use std::path::{Path, PathBuf};
+
+/// The version of this crate. The public API follows semantic versioning.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The VM files of a program, each named after its class or file.
+pub type VmProgram = Vec<(String, Vec<crate::ast::vm::Command>)>;
+
+/// Compiles Jack programs into VM code, assembly and Hack binaries. The
+/// builder methods configure a compiler, which can then be used for any
+/// number of programs.
+///
+/// ```
+/// use jack_compiler_rust::compiler::type_checker::Strictness;
+/// use jack_compiler_rust::Compiler;
+///
+/// let source = "class Main { function void main() { return; } }";
+/// let program = Compiler::new()
+///     .strictness(Strictness::Strict)
+///     .link_os(false)
+///     .compile_sources(&[("Main.jack", source)])
+///     .unwrap();
+/// assert_eq!(program.len(), 1);
+/// assert_eq!(program[0].0, "Main");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Compiler {
+    strictness: Strictness,
+    precedence: crate::compiler::precedence::Precedence,
+    link_os: bool,
+    optimize_vm: bool,
+    peephole: bool,
+    routines: crate::compiler::vm_to_asm::Routines,
+    runtime: crate::compiler::runtime::RuntimeProfile,
+}
+
+impl Default for Compiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Compiler {
+    /// A compiler that type checks leniently and links programs with the OS.
+    pub fn new() -> Self {
+        Self {
+            strictness: Strictness::Lenient,
+            precedence: crate::compiler::precedence::Precedence::LeftToRight,
+            link_os: true,
+            optimize_vm: false,
+            peephole: false,
+            routines: crate::compiler::vm_to_asm::Routines::Inline,
+            runtime: crate::compiler::runtime::RuntimeProfile::default(),
+        }
+    }
+
+    /// How strictly the types of Jack code are checked.
+    pub fn strictness(&mut self, strictness: Strictness) -> &mut Self {
+        self.strictness = strictness;
+        self
+    }
+
+    /// How the binary operators of Jack expressions are grouped. With the
+    /// default, left to right, expressions that would read differently with
+    /// precedence are reported as warnings.
+    pub fn precedence(&mut self, precedence: crate::compiler::precedence::Precedence) -> &mut Self {
+        self.precedence = precedence;
+        self
+    }
+
+    /// Whether programs are linked with the OS classes they do not define
+    /// themselves. Without the OS, calls into it are not type checked.
+    pub fn link_os(&mut self, link_os: bool) -> &mut Self {
+        self.link_os = link_os;
+        self
+    }
+
+    /// Whether the VM code compiled from Jack is run through the VM
+    /// optimizer, see [`compiler::vm_optimizer::VmOptimizer`].
+    pub fn optimize_vm(&mut self, optimize_vm: bool) -> &mut Self {
+        self.optimize_vm = optimize_vm;
+        self
+    }
+
+    /// Whether the assembly translated from VM code is run through the
+    /// peephole optimizer, see [`compiler::peephole::Peephole`].
+    pub fn peephole(&mut self, peephole: bool) -> &mut Self {
+        self.peephole = peephole;
+        self
+    }
+
+    /// Whether calls, returns and comparisons are translated inline or as
+    /// jumps to shared routines, which trades speed for ROM space.
+    pub fn routines(&mut self, routines: crate::compiler::vm_to_asm::Routines) -> &mut Self {
+        self.routines = routines;
+        self
+    }
+
+    /// How the translated program starts and where its segments live, see
+    /// [`compiler::runtime::RuntimeProfile`].
+    pub fn runtime(&mut self, runtime: crate::compiler::runtime::RuntimeProfile) -> &mut Self {
+        self.runtime = runtime;
+        self
+    }
+
+    /// Check and compile a Jack file or a directory of Jack files into VM.
+    /// Every class is checked even if an earlier one fails, so that all
+    /// diagnostics are reported at once.
+    pub fn compile_vm(&self, path: &str) -> Result<VmProgram, Vec<Diagnostic>> {
+        self.compile_vm_with_warnings(path)
+            .map(|(program, _)| program)
+    }
+
+    /// Like [`Compiler::compile_vm`], also returning the warnings raised
+    /// while compiling.
+    pub fn compile_vm_with_warnings(
+        &self,
+        path: &str,
+    ) -> Result<(VmProgram, Vec<Diagnostic>), Vec<Diagnostic>> {
+        self.compile_vm_with_origins(path)
+            .map(|(program, _, warnings)| (program, warnings))
+    }
+
+    /// Like [`Compiler::compile_vm_with_warnings`], also returning the Jack
+    /// source of every VM file, see
+    /// [`Compiler::compile_sources_with_origins`].
+    pub fn compile_vm_with_origins(
+        &self,
+        path: &str,
+    ) -> Result<(VmProgram, Vec<JackSource>, Vec<Diagnostic>), Vec<Diagnostic>> {
+        let metadata = fs::metadata(path).map_err(|e| vec![Diagnostic::from_io(path, e)])?;
+        let file_paths = if metadata.is_dir() {
+            files_with_extension(path, "jack")?
+        } else {
+            vec![PathBuf::from(path)]
+        };
+        let sources = file_paths
+            .iter()
+            .map(|file_path| {
+                let file_path = file_path.to_str().unwrap_or_default().to_string();
+                read_file(&file_path).map(|contents| (file_path, contents))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        self.compile_sources_with_origins(
+            &sources
+                .iter()
+                .map(|(file_path, contents)| (file_path.as_str(), contents.as_str()))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Check and compile Jack sources, given as pairs of a file path and its
+    /// contents, into VM. Each file is named after the stem of its path.
+    pub fn compile_sources(&self, sources: &[(&str, &str)]) -> Result<VmProgram, Vec<Diagnostic>> {
+        self.compile_sources_with_warnings(sources)
+            .map(|(program, _)| program)
+    }
+
+    /// Like [`Compiler::compile_sources`], also returning the warnings raised
+    /// while compiling.
+    pub fn compile_sources_with_warnings(
+        &self,
+        sources: &[(&str, &str)],
+    ) -> Result<(VmProgram, Vec<Diagnostic>), Vec<Diagnostic>> {
+        self.compile_sources_with_origins(sources)
+            .map(|(program, _, warnings)| (program, warnings))
+    }
+
+    /// Like [`Compiler::compile_sources_with_warnings`], also returning the
+    /// Jack source of every VM file, with the span of the statement each
+    /// command was compiled from. The VM optimizer moves commands away from
+    /// their statements, so no Jack sources are returned when it is enabled.
+    pub fn compile_sources_with_origins(
+        &self,
+        sources: &[(&str, &str)],
+    ) -> Result<(VmProgram, Vec<JackSource>, Vec<Diagnostic>), Vec<Diagnostic>> {
+        use crate::compiler::precedence::{check_class, climb_class, Precedence};
+        let mut classes = Vec::new();
+        let mut warnings = Vec::new();
+        let mut diagnostics = Vec::new();
+        for (file_path, contents) in sources {
+            match check_jack_source(file_path, contents) {
+                Ok(class) => {
+                    // The OS is written for left to right evaluation, so only
+                    //  the program's own classes are affected
+                    let class = match self.precedence {
+                        Precedence::LeftToRight => {
+                            warnings.append(&mut check_class(file_path, &class));
+                            class
+                        }
+                        Precedence::Climbing => climb_class(class),
+                    };
+                    classes.push((file_path.to_string(), class))
+                }
+                Err(mut ds) => diagnostics.append(&mut ds),
+            }
+        }
+        if !diagnostics.is_empty() {
+            return Err(diagnostics);
+        }
+
+        let os = match self.link_os {
+            true => os_classes(&classes)?,
+            false => Vec::new(),
+        };
+        type_check_classes(&classes, &os, self.strictness)?;
+        let table = crate::compiler::symbol_table::SymbolTable::new(
+            &classes
+                .iter()
+                .chain(&os)
+                .map(|(_, class)| class)
+                .collect::<Vec<_>>(),
+        );
+        let (program, jack): (VmProgram, Vec<JackSource>) = classes
+            .into_iter()
+            .chain(os)
+            .map(|(file_path, class)| {
+                let (vm, spans) =
+                    crate::compiler::jack_to_vm::JackToVm::compile_with_spans(class, &table);
+                let jack = JackSource {
+                    file: file_path.to_string(),
+                    spans,
+                };
+                ((file_stem(&file_path), vm), jack)
+            })
+            .unzip();
+        match self.optimize_vm {
+            true => Ok((
+                crate::compiler::vm_optimizer::VmOptimizer::optimize_program(program),
+                Vec::new(),
+                warnings,
+            )),
+            false => Ok((program, jack, warnings)),
+        }
+    }
+
+    /// Compile a Jack file or directory into one assembly program.
+    pub fn compile_asm(
+        &self,
+        path: &str,
+    ) -> Result<Vec<crate::ast::asm::Assembly>, Vec<Diagnostic>> {
+        let program = self.compile_vm(path)?;
+        crate::compiler::vm_to_asm::VmToAsm::verify(&program, &self.runtime)?;
+        Ok(self.vm_to_asm(program))
+    }
+
+    /// Compile a Jack file or directory into a Hack binary, one word per line.
+    pub fn compile_hack(&self, path: &str) -> Result<Vec<String>, Vec<Diagnostic>> {
+        self.compile_asm(path).map(|asm| self.assemble(asm))
+    }
+
+    /// Compile a Jack file or directory into a Hack binary, along with the
+    /// source map from its ROM addresses back to the Jack statements, see
+    /// [`compiler::source_map::SourceMap`]. Lines of assembly and VM code
+    /// are those of the printed programs. The peephole and VM optimizers
+    /// rewrite the code the map refers to, so neither may be enabled.
+    pub fn compile_hack_with_map(
+        &self,
+        path: &str,
+    ) -> Result<(Vec<String>, crate::compiler::source_map::SourceMap), Vec<Diagnostic>> {
+        let rewriting = match (self.peephole, self.optimize_vm) {
+            (true, _) => Some("peephole"),
+            (_, true) => Some("vm"),
+            _ => None,
+        };
+        if let Some(pass) = rewriting {
+            return Err(vec![Diagnostic::error(
+                path,
+                None,
+                format!(
+                    "a source map cannot be made with the `{}` optimization",
+                    pass
+                ),
+            )]);
+        }
+        let (program, jack, _) = self.compile_vm_with_origins(path)?;
+        crate::compiler::vm_to_asm::VmToAsm::verify(&program, &self.runtime)?;
+        let vm: Vec<_> = program
+            .iter()
+            .map(|(name, commands)| crate::compiler::source_map::VmSource::printed(name, commands))
+            .collect();
+        let (asm, origins) = self.vm_to_asm_with_origins(program);
+        let map = crate::compiler::source_map::SourceMap::new(&vm, &jack, &asm, &origins);
+        Ok((self.assemble(asm), map))
+    }
+
+    /// Link a VM program with the compiled OS classes it does not define,
+    /// when it calls into one of them. Programs that make no such call, such
+    /// as the nand2tetris tests of the VM translator, are left as they are,
+    /// and so is every program when the compiler does not link the OS.
+    pub fn link_vm(&self, mut program: VmProgram) -> Result<VmProgram, Vec<Diagnostic>> {
+        use crate::ast::vm::{Command, Function};
+        let defined: std::collections::HashSet<String> = program
+            .iter()
+            .flat_map(|(name, commands)| {
+                commands
+                    .iter()
+                    .filter_map(|command| match command {
+                        Command::Function(Function::Body(function, _)) => {
+                            function.split('.').next().map(str::to_string)
+                        }
+                        _ => None,
+                    })
+                    .chain([name.to_string()])
+            })
+            .collect();
+        let os: Vec<(String, &str)> = crate::os::library::CLASSES
+            .iter()
+            .filter(|(name, _)| !defined.contains(*name))
+            .map(|(name, source)| (format!("os/{}.jack", name), *source))
+            .collect();
+        let is_os = |function: &str| {
+            let class = function.split('.').next().unwrap_or_default();
+            os.iter()
+                .any(|(file_path, _)| file_stem(file_path) == class)
+        };
+        let calls_os = program
+            .iter()
+            .flat_map(|(_, commands)| commands)
+            .any(|command| {
+                matches!(command, Command::Function(Function::Call(function, _)) if is_os(function))
+            });
+        if !self.link_os || !calls_os {
+            return Ok(program);
+        }
+        let sources: Vec<(&str, &str)> = os
+            .iter()
+            .map(|(file_path, source)| (file_path.as_str(), *source))
+            .collect();
+        program.extend(
+            Compiler::new()
+                .strictness(self.strictness)
+                .link_os(false)
+                .compile_sources(&sources)?,
+        );
+        Ok(program)
+    }
+
+    /// Translate the VM files of a program into one assembly program,
+    /// starting with the bootstrap code.
+    pub fn vm_to_asm(&self, program: VmProgram) -> Vec<crate::ast::asm::Assembly> {
+        self.vm_to_asm_with_report(program).0
+    }
+
+    /// Like [`Compiler::vm_to_asm`], also reporting how many instructions the
+    /// peephole optimizer saved, which is none when it is disabled.
+    pub fn vm_to_asm_with_report(
+        &self,
+        program: VmProgram,
+    ) -> (
+        Vec<crate::ast::asm::Assembly>,
+        crate::compiler::peephole::PeepholeReport,
+    ) {
+        let asm = crate::compiler::vm_to_asm::VmToAsm::compile_program_with(
+            program,
+            self.routines,
+            &self.runtime,
+        );
+        match self.peephole {
+            true => crate::compiler::peephole::Peephole::optimize(asm),
+            false => {
+                let report = crate::compiler::peephole::PeepholeReport::unchanged(&asm);
+                (asm, report)
+            }
+        }
+    }
+
+    /// Like [`Compiler::vm_to_asm`], also returning the VM command each
+    /// instruction was translated from. The peephole optimizer merges the
+    /// instructions of neighbouring commands, so none has an origin when it
+    /// is enabled.
+    pub fn vm_to_asm_with_origins(
+        &self,
+        program: VmProgram,
+    ) -> (
+        Vec<crate::ast::asm::Assembly>,
+        Vec<Option<crate::compiler::source_map::VmOrigin>>,
+    ) {
+        let (asm, origins) = crate::compiler::vm_to_asm::VmToAsm::compile_program_with_origins(
+            program,
+            self.routines,
+            &self.runtime,
+        );
+        match self.peephole {
+            true => {
+                let (asm, _) = crate::compiler::peephole::Peephole::optimize(asm);
+                let origins = vec![None; asm.len()];
+                (asm, origins)
+            }
+            false => (asm, origins),
+        }
+    }
+
+    /// Assemble a program into a Hack binary, one word per line.
+    pub fn assemble(&self, asm: Vec<crate::ast::asm::Assembly>) -> Vec<String> {
+        crate::compiler::assembler::Assembler::assemble_with(asm, self.runtime.statics.start)
+    }
+}
+
+fn read_file(file_path: &str) -> Result<String, Vec<Diagnostic>> {
+    fs::read_to_string(file_path).map_err(|e| vec![Diagnostic::from_io(file_path, e)])
+}
+
+fn write_file(file_path: &str, contents: String) -> Result<(), Vec<Diagnostic>> {
+    fs::write(file_path, contents).map_err(|e| vec![Diagnostic::from_io(file_path, e)])
+}
+
+// Convert the errors of a character-level parser into diagnostics
+fn char_diagnostics(
+    file_path: &str,
+    errors: Vec<chumsky::error::Simple<char, crate::ast::span::Span>>,
+) -> Vec<Diagnostic> {
+    errors
+        .into_iter()
+        .map(|e| Diagnostic::from_simple(file_path, e.map(|c| c.to_string())))
+        .collect()
+}
+
+/// Tokenize a single Jack file
+pub fn tokenize_jack_file(
+    file_path: &str,
+) -> Result<Vec<crate::ast::span::Spanned<crate::ast::token::Token>>, Vec<Diagnostic>> {
+    let contents = read_file(file_path)?;
+    tokenize_jack_source(file_path, &contents)
+}
+
+/// Tokenize Jack source. `file_path` is only used in diagnostics.
+pub fn tokenize_jack_source(
+    file_path: &str,
+    contents: &str,
+) -> Result<Vec<crate::ast::span::Spanned<crate::ast::token::Token>>, Vec<Diagnostic>> {
+    crate::compiler::lexer::tokenize()
+        .parse(crate::ast::span::char_stream(contents))
+        .map_err(|errors| char_diagnostics(file_path, errors))
+}
+
+/// Parse a single Jack file
+pub fn parse_jack_file(file_path: &str) -> Result<crate::ast::jack::Class, Vec<Diagnostic>> {
+    let contents = read_file(file_path)?;
+    parse_jack_source(file_path, &contents)
+}
+
+/// Parse Jack source that does not come from a file, such as the OS classes
+/// embedded in the compiler. `file_path` is only used in diagnostics.
+pub fn parse_jack_source(
+    file_path: &str,
+    contents: &str,
+) -> Result<crate::ast::jack::Class, Vec<Diagnostic>> {
+    let tokens = tokenize_jack_source(file_path, contents)?;
+    let eoi = crate::ast::span::end_of_input(&tokens);
+    crate::compiler::parser::parse_class()
+        .parse(chumsky::Stream::from_iter(eoi, tokens.into_iter()))
+        .map_err(|errors| {
+            errors
+                .into_iter()
+                .map(|e| {
+                    Diagnostic::from_simple_with(
+                        file_path,
+                        e,
+                        |t| format!("`{}`", crate::pretty_printer::lexer::show_token(t)),
+                        crate::pretty_printer::lexer::show_expected_token,
+                    )
+                })
+                .collect()
+        })
+}
+
+/// Parse a single Jack file and run the semantic checks on it
+pub fn check_jack_file(file_path: &str) -> Result<crate::ast::jack::Class, Vec<Diagnostic>> {
+    let contents = read_file(file_path)?;
+    check_jack_source(file_path, &contents)
+}
+
+/// Parse Jack source and run the semantic checks on it. `file_path` is only
+/// used in diagnostics.
+pub fn check_jack_source(
+    file_path: &str,
+    contents: &str,
+) -> Result<crate::ast::jack::Class, Vec<Diagnostic>> {
+    let class = parse_jack_source(file_path, contents)?;
+    let diagnostics = crate::compiler::checker::Checker::check(file_path, &class);
+    if diagnostics.iter().any(|d| d.is_error()) {
+        return Err(diagnostics);
+    }
+    Ok(class)
+}
+
+/// Type check classes that are compiled together into one program. Calls into
+/// `linked` classes are checked against their signatures, but the linked
+/// classes themselves are not checked. Calls into other classes are not
+/// checked.
+pub fn type_check_classes(
+    classes: &[(String, crate::ast::jack::Class)],
+    linked: &[(String, crate::ast::jack::Class)],
+    strictness: Strictness,
+) -> Result<(), Vec<Diagnostic>> {
+    let table = crate::compiler::symbol_table::SymbolTable::new(
+        &classes
+            .iter()
+            .chain(linked)
+            .map(|(_, class)| class)
+            .collect::<Vec<_>>(),
+    );
+    let diagnostics: Vec<Diagnostic> = classes
+        .iter()
+        .flat_map(|(file_path, class)| {
+            crate::compiler::type_checker::TypeChecker::check(file_path, class, &table, strictness)
+        })
+        .collect();
+    if diagnostics.iter().any(|d| d.is_error()) {
+        return Err(diagnostics);
+    }
+    Ok(())
+}
+
+// The OS classes that a program does not define itself
+fn os_classes(
+    classes: &[(String, crate::ast::jack::Class)],
+) -> Result<Vec<(String, crate::ast::jack::Class)>, Vec<Diagnostic>> {
+    crate::os::library::CLASSES
+        .iter()
+        .filter(|(name, _)| !classes.iter().any(|(_, class)| class.class_name == *name))
+        .map(|(name, source)| {
+            let file_path = format!("os/{}.jack", name);
+            parse_jack_source(&file_path, source).map(|class| (file_path, class))
+        })
+        .collect()
+}
+
+fn file_stem(file_path: &str) -> String {
+    Path::new(file_path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or(file_path)
+        .to_string()
+}
+
+/// Compile a single Jack file, linked with the OS, into hack
+pub fn jack_to_hack(
+    file_path: &str,
+    strictness: Strictness,
+) -> Result<Vec<String>, Vec<Diagnostic>> {
+    Compiler::new()
+        .strictness(strictness)
+        .compile_hack(file_path)
+}
+
+// Collect the files with the given extension in a directory, sorted so
+//  that the linked output does not depend on the file system's ordering
+fn files_with_extension(dir_path: &str, extension: &str) -> Result<Vec<PathBuf>, Vec<Diagnostic>> {
+    let mut paths = Vec::new();
+    let entries = fs::read_dir(dir_path).map_err(|e| vec![Diagnostic::from_io(dir_path, e)])?;
+    for entry in entries {
+        let file_path = entry
+            .map_err(|e| vec![Diagnostic::from_io(dir_path, e)])?
+            .path();
+        if file_path.extension().and_then(|s| s.to_str()) == Some(extension) {
+            paths.push(file_path);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// Compile every Jack class in a directory into VM, linked with the OS
+pub fn jack_dir_to_vm(
+    dir_path: &str,
+    strictness: Strictness,
+) -> Result<VmProgram, Vec<Diagnostic>> {
+    Compiler::new().strictness(strictness).compile_vm(dir_path)
+}
+
+/// Compile every Jack class in a directory into one assembly program
+pub fn jack_dir_to_asm(
+    dir_path: &str,
+    strictness: Strictness,
+) -> Result<Vec<crate::ast::asm::Assembly>, Vec<Diagnostic>> {
+    Compiler::new().strictness(strictness).compile_asm(dir_path)
+}
+
+/// Compile every Jack class in a directory into one linked hack program
+pub fn jack_dir_to_hack(
+    dir_path: &str,
+    strictness: Strictness,
+) -> Result<Vec<String>, Vec<Diagnostic>> {
+    Compiler::new()
+        .strictness(strictness)
+        .compile_hack(dir_path)
+}
+
+/// Compile a single Jack file into VM
+pub fn jack_to_vm(
+    file_path: &str,
+    strictness: Strictness,
+) -> Result<Vec<crate::ast::vm::Command>, Vec<Diagnostic>> {
+    let class = check_jack_file(file_path)?;
+    let classes = vec![(file_path.to_string(), class)];
+    type_check_classes(&classes, &[], strictness)?;
+    let (_, class) = classes.into_iter().next().unwrap();
+    let table = crate::compiler::symbol_table::SymbolTable::new(&[&class]);
+    Ok(crate::compiler::jack_to_vm::JackToVm::compile(
+        class, &table,
+    ))
+}
+
+/// Parse a single assembly file
+pub fn parse_asm_file(file_path: &str) -> Result<Vec<crate::ast::asm::Assembly>, Vec<Diagnostic>> {
+    let contents = read_file(file_path)?;
+    parse_asm_source(file_path, &contents)
+}
+
+/// Parse Hack assembly. `file_path` is only used in diagnostics.
+pub fn parse_asm_source(
+    file_path: &str,
+    contents: &str,
+) -> Result<Vec<crate::ast::asm::Assembly>, Vec<Diagnostic>> {
+    crate::compiler::asm_parser::parse_assembly()
+        .parse(crate::ast::span::char_stream(contents))
+        .map_err(|errors| char_diagnostics(file_path, errors))
+}
+
+/// Compile a single ASM file into hack
+pub fn assembler(file_path: &str) -> Result<Vec<String>, Vec<Diagnostic>> {
+    parse_asm_file(file_path).map(crate::compiler::assembler::Assembler::assemble)
+}
+
+/// Load a hack binary into the CPU emulator
+pub fn load_hack_file(file_path: &str) -> Result<crate::emulator::hack::Hack, Vec<Diagnostic>> {
+    let contents = read_file(file_path)?;
+    let lines: Vec<String> = contents.lines().map(|l| l.to_string()).collect();
+    crate::emulator::hack::Hack::from_binary(&lines)
+        .map_err(|e| vec![Diagnostic::error(file_path, None, e)])
+}
+
+/// Parse a single VM file
+pub fn parse_vm_file(file_path: &str) -> Result<Vec<crate::ast::vm::Command>, Vec<Diagnostic>> {
+    let contents = read_file(file_path)?;
+    parse_vm_source(file_path, &contents)
+}
+
+/// Parse VM code. `file_path` is only used in diagnostics.
+pub fn parse_vm_source(
+    file_path: &str,
+    contents: &str,
+) -> Result<Vec<crate::ast::vm::Command>, Vec<Diagnostic>> {
+    crate::compiler::vm_parser::parse_vm()
+        .parse(crate::ast::span::char_stream(contents))
+        .map_err(|errors| char_diagnostics(file_path, errors))
+}
+
+/// Parse a VM file, or every VM file in a directory, naming each after its file
+pub fn parse_vm_path(path: &str) -> Result<VmProgram, Vec<Diagnostic>> {
+    parse_vm_path_with_origins(path).map(|(program, _)| program)
+}
+
+/// Like [`parse_vm_path`], also returning the path of every file with the
+/// line of each of its commands, from which
+/// [`compiler::source_map::SourceMap`] maps assembly back to the VM code.
+pub fn parse_vm_path_with_origins(
+    path: &str,
+) -> Result<(VmProgram, Vec<crate::compiler::source_map::VmSource>), Vec<Diagnostic>> {
+    let metadata = fs::metadata(path).map_err(|e| vec![Diagnostic::from_io(path, e)])?;
+    let file_paths = if metadata.is_file() {
+        vec![PathBuf::from(path)]
+    } else {
+        files_with_extension(path, "vm")?
+    };
+    let files = file_paths
+        .iter()
+        .map(|file_path| {
+            let file_name = file_path.file_stem().unwrap().to_str().unwrap().to_string();
+            let file_path = file_path.to_str().unwrap();
+            let contents = read_file(file_path)?;
+            let commands: Vec<crate::ast::span::Spanned<_>> =
+                crate::compiler::vm_parser::parse_vm_spanned()
+                    .parse(crate::ast::span::char_stream(&contents))
+                    .map_err(|errors| char_diagnostics(file_path, errors))?;
+            let vm = crate::compiler::source_map::VmSource {
+                file: file_path.to_string(),
+                lines: commands.iter().map(|(_, span)| span.start.line).collect(),
+            };
+            let commands = commands.into_iter().map(|(command, _)| command).collect();
+            Ok(((file_name, commands), vm))
+        })
+        .collect::<Result<Vec<_>, Vec<Diagnostic>>>()?;
+    Ok(files.into_iter().unzip())
+}
+
+/// Load a VM file or directory into the VM emulator, linked with the OS
+/// classes it calls but does not define
+pub fn load_vm(path: &str) -> Result<crate::emulator::vm::Vm, Vec<Diagnostic>> {
+    crate::emulator::vm::Vm::new(Compiler::new().link_vm(parse_vm_path(path)?)?)
+        .map_err(|e| vec![Diagnostic::error(path, None, e)])
+}
+
+/// Run a `.tst` test script, writing its `.out` file and comparing it against
+/// the script's `.cmp` file
+pub fn run_test_script(file_path: &str) -> Result<(), Vec<Diagnostic>> {
+    let contents = read_file(file_path)?;
+    let commands = crate::compiler::tst_parser::parse_tst()
+        .parse(crate::ast::span::char_stream(&contents))
+        .map_err(|errors| char_diagnostics(file_path, errors))?;
+    crate::emulator::script::Script::run(file_path, commands)
+}
+
+/// Translate a VM file, or every VM file in a directory, into one assembly
+/// program. The files are translated together: the bootstrap is emitted once,
+/// each file keeps its own statics, and every `call` must name a function that
+/// one of the files or the OS defines. The OS classes that are called but not
+/// defined are linked in.
+pub fn vm_to_asm(path: &str) -> Result<Vec<crate::ast::asm::Assembly>, Vec<Diagnostic>> {
+    let program = Compiler::new().link_vm(parse_vm_path(path)?)?;
+    crate::compiler::vm_to_asm::VmToAsm::verify(
+        &program,
+        &crate::compiler::runtime::RuntimeProfile::default(),
+    )?;
+    Ok(crate::compiler::vm_to_asm::VmToAsm::compile_program(
+        program,
+    ))
+}
@@ -0,0 +1,13 @@
+// The Jack standard library (chapter 12), embedded in the compiler. Programs
+//  are linked against the classes they do not define themselves, so a
+//  directory of user classes compiles to a complete Hack image.
+pub const CLASSES: [(&str, &str); 8] = [
+    ("Array", include_str!("Array.jack")),
+    ("Keyboard", include_str!("Keyboard.jack")),
+    ("Math", include_str!("Math.jack")),
+    ("Memory", include_str!("Memory.jack")),
+    ("Output", include_str!("Output.jack")),
+    ("Screen", include_str!("Screen.jack")),
+    ("String", include_str!("String.jack")),
+    ("Sys", include_str!("Sys.jack")),
+];
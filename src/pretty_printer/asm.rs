@@ -106,6 +106,7 @@ impl Dest {
             Dest::AM => "AM".to_string(),
             Dest::AD => "AD".to_string(),
             Dest::ADM => "ADM".to_string(),
+            Dest::AMD => "AMD".to_string(),
         }
     }
 }
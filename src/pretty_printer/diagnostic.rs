@@ -0,0 +1,70 @@
+use crate::compiler::diagnostic::*;
+
+// Printing:
+//  error: unexpected `}` while parsing let statement
+//   --> Main.jack:3:13
+//    |
+//  3 |     let x = };
+//    |             ^
+//    = expected one of `(`, `-`, ...
+pub fn print_diagnostic(diagnostic: &Diagnostic, source: Option<&str>) -> String {
+    let mut lines = vec![format!(
+        "{}: {}",
+        diagnostic.severity.as_str(),
+        diagnostic.message
+    )];
+
+    match diagnostic.span {
+        None => lines.push(format!(" --> {}", diagnostic.file)),
+        Some(span) => {
+            let line_number = span.start.line.to_string();
+            let gutter = " ".repeat(line_number.len());
+            lines.push(format!(
+                "{}--> {}:{}:{}",
+                gutter, diagnostic.file, span.start.line, span.start.column
+            ));
+            let source_line = source.and_then(|s| s.lines().nth(span.start.line - 1));
+            if let Some(source_line) = source_line {
+                let width = if span.end.line == span.start.line {
+                    span.end.column.saturating_sub(span.start.column)
+                } else {
+                    (source_line.chars().count() + 1).saturating_sub(span.start.column)
+                };
+                let indent: String = source_line
+                    .chars()
+                    .take(span.start.column - 1)
+                    .map(|c| if c == '\t' { '\t' } else { ' ' })
+                    .collect();
+                lines.push(format!("{} |", gutter));
+                lines.push(format!("{} | {}", line_number, source_line));
+                lines.push(format!(
+                    "{} | {}{}",
+                    gutter,
+                    indent,
+                    "^".repeat(width.max(1))
+                ));
+            }
+        }
+    }
+
+    match diagnostic.expected.as_slice() {
+        [] => (),
+        [e] => lines.push(format!("  = expected {}", e)),
+        es => lines.push(format!("  = expected one of {}", es.join(", "))),
+    }
+    diagnostic
+        .notes
+        .iter()
+        .for_each(|note| lines.push(format!("  = note: {}", note)));
+
+    lines.join("\n") + "\n"
+}
+
+impl Severity {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
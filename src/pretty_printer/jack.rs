@@ -117,11 +117,7 @@ impl PrettyPrint for SubroutineReturnType {
 
 impl PrettyPrint for Parameter {
     fn pretty_print(&self, i: usize) -> String {
-        format!(
-            "{} {}",
-            self.r#type.pretty_print(i),
-            self.var_name
-        )
+        format!("{} {}", self.r#type.pretty_print(i), self.var_name)
     }
 }
 
@@ -154,22 +150,22 @@ impl PrettyPrint for VarDec {
 
 impl PrettyPrint for Statement {
     fn pretty_print(&self, i: usize) -> String {
-        match self {
-            Statement::Do(sc) => format!("do {};", sc.pretty_print(i)),
-            Statement::Let(s, oe, e) => {
+        match &self.kind {
+            StatementKind::Do(sc) => format!("do {};", sc.pretty_print(i)),
+            StatementKind::Let(s, oe, e) => {
                 let expr = oe
                     .clone()
                     .map_or(String::new(), |expr| format!("[{}]", expr.pretty_print(i)));
                 format!("let {}{} = {};", s, expr, e.pretty_print(i))
             }
-            Statement::While(e, s) => {
+            StatementKind::While(e, s) => {
                 let stmts = s
                     .iter()
                     .map(|stmt| format!("{}{}", tab(i + 1), stmt.pretty_print(i + 1)))
                     .join("\n");
                 format!("while ({}) {{\n{}\n{}}}", e.pretty_print(i), stmts, tab(i))
             }
-            Statement::If(e, s, os) => {
+            StatementKind::If(e, s, os) => {
                 let stmts = s
                     .iter()
                     .map(|s| format!("{}{}", tab(i + 1), s.pretty_print(i + 1)))
@@ -194,7 +190,7 @@ impl PrettyPrint for Statement {
                     elsey
                 )
             }
-            Statement::Return(oe) => {
+            StatementKind::Return(oe) => {
                 let expr = oe
                     .clone()
                     .map_or(String::new(), |expr| format!(" {}", expr.pretty_print(i)));
@@ -219,14 +215,14 @@ impl PrettyPrint for Expression {
 // FINISH
 impl PrettyPrint for Term {
     fn pretty_print(&self, i: usize) -> String {
-        match self {
-            Term::IntegerConstant(i) => i.to_string(),
-            Term::StringConstant(s) => format!("\"{}\"", s),
-            Term::KeywordConstant(k) => k.pretty_print(i),
-            Term::ParensExpr(e) => format!("({})", e.pretty_print(i)),
-            Term::SubroutineCall(sc) => sc.pretty_print(i),
-            Term::Unary(uop, t) => format!("{}{}", uop.pretty_print(i), t.pretty_print(i)),
-            Term::VarName(s, oe) => {
+        match &self.kind {
+            TermKind::IntegerConstant(i) => i.to_string(),
+            TermKind::StringConstant(s) => format!("\"{}\"", s),
+            TermKind::KeywordConstant(k) => k.pretty_print(i),
+            TermKind::ParensExpr(e) => format!("({})", e.pretty_print(i)),
+            TermKind::SubroutineCall(sc) => sc.pretty_print(i),
+            TermKind::Unary(uop, t) => format!("{}{}", uop.pretty_print(i), t.pretty_print(i)),
+            TermKind::VarName(s, oe) => {
                 let expr = oe
                     .clone()
                     .map_or(String::new(), |expr| format!("[{}]", expr.pretty_print(i)));
@@ -12,10 +12,35 @@ pub fn print_tokens(tokens: Vec<Token>) -> String {
     )
 }
 
-fn print_token(token: Token) -> String {
+pub fn print_token(token: Token) -> String {
     token.as_str().to_string()
 }
 
+// A token the parser expected, used in diagnostics. Identifiers and
+//  constants stand for every token of their kind.
+pub fn show_expected_token(token: &Token) -> String {
+    match token {
+        Token::Integer(_) => "integer constant".to_string(),
+        Token::String(_) => "string constant".to_string(),
+        Token::Identifier(_) => "identifier".to_string(),
+        token => format!("`{}`", show_token(token)),
+    }
+}
+
+// Source form of a token, used in diagnostics
+pub fn show_token(token: &Token) -> String {
+    match token {
+        Token::Keyword(k) => k.as_str().to_string(),
+        Token::Symbol(Symbol::Ampersand) => "&".to_string(),
+        Token::Symbol(Symbol::Lesser) => "<".to_string(),
+        Token::Symbol(Symbol::Greater) => ">".to_string(),
+        Token::Symbol(s) => s.as_str().to_string(),
+        Token::Integer(i) => i.to_string(),
+        Token::String(s) => format!("\"{}\"", s),
+        Token::Identifier(s) => s.to_string(),
+    }
+}
+
 impl Keyword {
     fn as_str(&self) -> &'static str {
         match self {
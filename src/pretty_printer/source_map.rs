@@ -0,0 +1,24 @@
+use crate::compiler::source_map::*;
+
+// One line per ROM address, with tab separated columns: the address, the
+//  assembly line, `File.vm:line` and `File.jack:line:column`, or `-` where
+//  the instruction has no origin
+pub fn print_source_map(map: &SourceMap) -> String {
+    map.entries
+        .iter()
+        .map(print_entry)
+        .map(|line| line + "\n")
+        .collect()
+}
+
+fn print_entry(entry: &MapEntry) -> String {
+    let vm = match &entry.vm {
+        Some(vm) => format!("{}:{}", vm.file, vm.line),
+        None => "-".to_string(),
+    };
+    let jack = match &entry.jack {
+        Some(jack) => format!("{}:{}:{}", jack.file, jack.line, jack.column),
+        None => "-".to_string(),
+    };
+    format!("{}\t{}\t{}\t{}", entry.address, entry.asm_line, vm, jack)
+}
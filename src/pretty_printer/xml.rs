@@ -0,0 +1,296 @@
+use crate::ast::jack::*;
+use crate::ast::token::{Keyword, Symbol, Token};
+use crate::pretty_printer::lexer::print_token;
+
+// Printing:
+//  The parse tree of a class in the XML format of the nand2tetris syntax
+//  analyzer (project 10). Terminals are printed as in `print_tokens`, and
+//  every nonterminal except `type`, `className`, `subroutineName`, `varName`,
+//  `statement` and `subroutineCall` gets its own element, indented by two
+//  spaces per level. Empty elements still span two lines.
+pub fn print_class(class: &Class) -> String {
+    let mut xml = Xml::default();
+    xml.class(class);
+    xml.lines.join("\n") + "\n"
+}
+
+#[derive(Default)]
+struct Xml {
+    lines: Vec<String>,
+    depth: usize,
+}
+
+impl Xml {
+    fn open(&mut self, tag: &str) -> &mut Self {
+        self.line(format!("<{}>", tag));
+        self.depth += 1;
+        self
+    }
+
+    fn close(&mut self, tag: &str) -> &mut Self {
+        self.depth -= 1;
+        self.line(format!("</{}>", tag))
+    }
+
+    fn line(&mut self, line: String) -> &mut Self {
+        self.lines
+            .push(format!("{}{}", "  ".repeat(self.depth), line));
+        self
+    }
+
+    fn token(&mut self, token: Token) -> &mut Self {
+        self.line(print_token(token))
+    }
+
+    fn keyword(&mut self, keyword: Keyword) -> &mut Self {
+        self.token(Token::Keyword(keyword))
+    }
+
+    fn symbol(&mut self, symbol: Symbol) -> &mut Self {
+        self.token(Token::Symbol(symbol))
+    }
+
+    fn identifier(&mut self, identifier: &str) -> &mut Self {
+        self.token(Token::Identifier(identifier.to_string()))
+    }
+
+    // Names separated by commas, as in `var int i, j;`
+    fn identifiers(&mut self, identifiers: &[String]) -> &mut Self {
+        for (i, identifier) in identifiers.iter().enumerate() {
+            if i > 0 {
+                self.symbol(Symbol::Comma);
+            }
+            self.identifier(identifier);
+        }
+        self
+    }
+
+    // Program structure:
+    fn class(&mut self, class: &Class) -> &mut Self {
+        self.open("class")
+            .keyword(Keyword::Class)
+            .identifier(&class.class_name)
+            .symbol(Symbol::LCurly);
+        class
+            .class_dec
+            .class_var_dec
+            .iter()
+            .for_each(|cvd| self.class_var_dec(cvd));
+        class
+            .class_dec
+            .subroutine_dec
+            .iter()
+            .for_each(|sd| self.subroutine_dec(sd));
+        self.symbol(Symbol::RCurly).close("class")
+    }
+
+    fn class_var_dec(&mut self, class_var_dec: &ClassVarDec) {
+        let kind = match class_var_dec.kind {
+            Kind::Static => Keyword::Static,
+            Kind::Field => Keyword::Field,
+        };
+        self.open("classVarDec")
+            .keyword(kind)
+            .r#type(&class_var_dec.r#type)
+            .identifiers(&class_var_dec.vars)
+            .symbol(Symbol::Semicolon)
+            .close("classVarDec");
+    }
+
+    fn r#type(&mut self, r#type: &Type) -> &mut Self {
+        match r#type {
+            Type::Int => self.keyword(Keyword::Int),
+            Type::Char => self.keyword(Keyword::Char),
+            Type::Boolean => self.keyword(Keyword::Boolean),
+            Type::ClassName(name) => self.identifier(name),
+        }
+    }
+
+    fn subroutine_dec(&mut self, subroutine_dec: &SubroutineDec) {
+        let subroutine_type = match subroutine_dec.subroutine_type {
+            SubroutineType::Constructor => Keyword::Constructor,
+            SubroutineType::Function => Keyword::Function,
+            SubroutineType::Method => Keyword::Method,
+        };
+        self.open("subroutineDec").keyword(subroutine_type);
+        match &subroutine_dec.subroutine_return_type {
+            SubroutineReturnType::Void => self.keyword(Keyword::Void),
+            SubroutineReturnType::Type(r#type) => self.r#type(r#type),
+        };
+        self.identifier(&subroutine_dec.subroutine_name)
+            .symbol(Symbol::LParens)
+            .open("parameterList");
+        for (i, parameter) in subroutine_dec.parameter_list.iter().enumerate() {
+            if i > 0 {
+                self.symbol(Symbol::Comma);
+            }
+            self.r#type(&parameter.r#type)
+                .identifier(&parameter.var_name);
+        }
+        self.close("parameterList")
+            .symbol(Symbol::RParens)
+            .open("subroutineBody")
+            .symbol(Symbol::LCurly);
+        for var_dec in &subroutine_dec.subroutine_body.var_decs {
+            self.open("varDec")
+                .keyword(Keyword::Var)
+                .r#type(&var_dec.r#type)
+                .identifiers(&var_dec.var_name)
+                .symbol(Symbol::Semicolon)
+                .close("varDec");
+        }
+        self.statements(&subroutine_dec.subroutine_body.stmts)
+            .symbol(Symbol::RCurly)
+            .close("subroutineBody")
+            .close("subroutineDec");
+    }
+
+    // Statements:
+    fn statements(&mut self, statements: &[Statement]) -> &mut Self {
+        self.open("statements");
+        statements
+            .iter()
+            .for_each(|statement| self.statement(statement));
+        self.close("statements")
+    }
+
+    // A block in curly braces, as in `if` and `while` statements
+    fn block(&mut self, statements: &[Statement]) -> &mut Self {
+        self.symbol(Symbol::LCurly)
+            .statements(statements)
+            .symbol(Symbol::RCurly)
+    }
+
+    fn statement(&mut self, statement: &Statement) {
+        match &statement.kind {
+            StatementKind::Let(name, index, value) => {
+                self.open("letStatement")
+                    .keyword(Keyword::Let)
+                    .identifier(name);
+                if let Some(index) = index {
+                    self.symbol(Symbol::LBracket)
+                        .expression(index)
+                        .symbol(Symbol::RBracket);
+                }
+                self.symbol(Symbol::Equal)
+                    .expression(value)
+                    .symbol(Symbol::Semicolon)
+                    .close("letStatement");
+            }
+            StatementKind::If(condition, then, otherwise) => {
+                self.open("ifStatement")
+                    .keyword(Keyword::If)
+                    .symbol(Symbol::LParens)
+                    .expression(condition)
+                    .symbol(Symbol::RParens)
+                    .block(then);
+                if let Some(otherwise) = otherwise {
+                    self.keyword(Keyword::Else).block(otherwise);
+                }
+                self.close("ifStatement");
+            }
+            StatementKind::While(condition, body) => {
+                self.open("whileStatement")
+                    .keyword(Keyword::While)
+                    .symbol(Symbol::LParens)
+                    .expression(condition)
+                    .symbol(Symbol::RParens)
+                    .block(body)
+                    .close("whileStatement");
+            }
+            StatementKind::Do(call) => {
+                self.open("doStatement")
+                    .keyword(Keyword::Do)
+                    .subroutine_call(call)
+                    .symbol(Symbol::Semicolon)
+                    .close("doStatement");
+            }
+            StatementKind::Return(value) => {
+                self.open("returnStatement").keyword(Keyword::Return);
+                if let Some(value) = value {
+                    self.expression(value);
+                }
+                self.symbol(Symbol::Semicolon).close("returnStatement");
+            }
+        }
+    }
+
+    // Expressions:
+    fn expression(&mut self, expression: &Expression) -> &mut Self {
+        self.open("expression").term(&expression.term);
+        for (op, term) in &expression.bin {
+            self.symbol(binary_op(op)).term(term);
+        }
+        self.close("expression")
+    }
+
+    fn term(&mut self, term: &Term) -> &mut Self {
+        self.open("term");
+        match &term.kind {
+            TermKind::IntegerConstant(i) => self.token(Token::Integer(*i)),
+            TermKind::StringConstant(s) => self.token(Token::String(s.to_string())),
+            TermKind::KeywordConstant(k) => self.keyword(match k {
+                KeywordConstant::True => Keyword::True,
+                KeywordConstant::False => Keyword::False,
+                KeywordConstant::Null => Keyword::Null,
+                KeywordConstant::This => Keyword::This,
+            }),
+            TermKind::VarName(name, None) => self.identifier(name),
+            TermKind::VarName(name, Some(index)) => self
+                .identifier(name)
+                .symbol(Symbol::LBracket)
+                .expression(index)
+                .symbol(Symbol::RBracket),
+            TermKind::Unary(op, term) => {
+                let op = match op {
+                    UnaryOp::Negation => Symbol::Minus,
+                    UnaryOp::Tilde => Symbol::Tilde,
+                };
+                self.symbol(op).term(term)
+            }
+            TermKind::ParensExpr(expression) => self
+                .symbol(Symbol::LParens)
+                .expression(expression)
+                .symbol(Symbol::RParens),
+            TermKind::SubroutineCall(call) => self.subroutine_call(call),
+        };
+        self.close("term")
+    }
+
+    fn subroutine_call(&mut self, call: &SubroutineCall) -> &mut Self {
+        let arguments = match call {
+            SubroutineCall::Call(name, arguments) => {
+                self.identifier(name);
+                arguments
+            }
+            SubroutineCall::ClassCall(receiver, name, arguments) => {
+                self.identifier(receiver)
+                    .symbol(Symbol::Period)
+                    .identifier(name);
+                arguments
+            }
+        };
+        self.symbol(Symbol::LParens).open("expressionList");
+        for (i, argument) in arguments.iter().enumerate() {
+            if i > 0 {
+                self.symbol(Symbol::Comma);
+            }
+            self.expression(argument);
+        }
+        self.close("expressionList").symbol(Symbol::RParens)
+    }
+}
+
+fn binary_op(op: &BinaryOp) -> Symbol {
+    match op {
+        BinaryOp::Plus => Symbol::Plus,
+        BinaryOp::Minus => Symbol::Minus,
+        BinaryOp::Times => Symbol::Asterisk,
+        BinaryOp::Div => Symbol::Slash,
+        BinaryOp::And => Symbol::Ampersand,
+        BinaryOp::Or => Symbol::Bar,
+        BinaryOp::Lesser => Symbol::Lesser,
+        BinaryOp::Greater => Symbol::Greater,
+        BinaryOp::Equal => Symbol::Equal,
+    }
+}
@@ -32,8 +32,10 @@ mod tests {
         let r_tokens = crate::tokenize_jack_file(&jack_path);
         match r_tokens {
             Ok(tokens) => {
-                let token_string = crate::pretty_printer::lexer::print_tokens(tokens);
-                std::fs::write(act_path.clone(), token_string);
+                let token_string = crate::pretty_printer::lexer::print_tokens(
+                    tokens.into_iter().map(|(token, _)| token).collect(),
+                );
+                std::fs::write(act_path.clone(), token_string).expect("Failed to write output");
                 let actual = std::path::Path::new(&act_path);
                 let expected = std::path::Path::new(&exp_path);
                 assert!(
@@ -70,6 +72,46 @@ mod tests {
         lexer("tests/lexer/Square/SquareGame");
     }
 
+    // Testing XML printer:
+    // ----------------------------------------------------------------------------
+    fn xml(file: &str) {
+        let jack_path: String = format!("{}.jack", file);
+        let exp_path: String = format!("{}Exp.xml", file);
+        let act_path: String = format!("{}Act.xml", file);
+
+        let class = crate::parse_jack_file(&jack_path).unwrap();
+        let xml = crate::pretty_printer::xml::print_class(&class);
+        std::fs::write(act_path.clone(), xml).expect("Failed to write output");
+        let actual = std::path::Path::new(&act_path);
+        let expected = std::path::Path::new(&exp_path);
+        assert!(
+            compare_files(actual, expected),
+            "{} and {} do not match",
+            act_path,
+            exp_path
+        )
+    }
+
+    #[test]
+    fn xml_arraytest_main() {
+        xml("tests/parser/ArrayTest/Main");
+    }
+
+    #[test]
+    fn xml_square_main() {
+        xml("tests/parser/Square/Main");
+    }
+
+    #[test]
+    fn xml_square_square() {
+        xml("tests/parser/Square/Square");
+    }
+
+    #[test]
+    fn xml_square_squaregame() {
+        xml("tests/parser/Square/SquareGame");
+    }
+
     // Testing parser:
     // ----------------------------------------------------------------------------
     fn parser(file: &str) {
@@ -81,7 +123,7 @@ mod tests {
         match r_class {
             Ok(class) => {
                 let class_string = class.pretty_print(0);
-                std::fs::write(act_path.clone(), class_string);
+                std::fs::write(act_path.clone(), class_string).expect("Failed to write output");
                 let actual = std::path::Path::new(&act_path);
                 let expected = std::path::Path::new(&exp_path);
                 assert!(
@@ -118,6 +160,125 @@ mod tests {
         parser("tests/parser/Square/SquareGame")
     }
 
+    // Testing spans:
+    // ----------------------------------------------------------------------------
+    fn start(span: crate::ast::span::Span) -> (usize, usize) {
+        (span.start.line, span.start.column)
+    }
+
+    #[test]
+    fn spans_arraytest_main() {
+        let tokens = crate::tokenize_jack_file("tests/parser/ArrayTest/Main.jack").unwrap();
+        assert_eq!(start(tokens[0].1), (9, 1));
+        assert_eq!(start(tokens[1].1), (9, 7));
+
+        let class = crate::parse_jack_file("tests/parser/ArrayTest/Main.jack").unwrap();
+        assert_eq!(start(class.span), (9, 1));
+        let main = &class.class_dec.subroutine_dec[0];
+        assert_eq!(start(main.span), (10, 5));
+        assert_eq!(start(main.subroutine_body.var_decs[0].span), (11, 9));
+        let stmt = &main.subroutine_body.stmts[0];
+        assert_eq!(start(stmt.span), (15, 2));
+        assert_eq!((stmt.span.end.line, stmt.span.end.column), (15, 54));
+        match &stmt.kind {
+            crate::ast::jack::StatementKind::Let(_, _, e) => {
+                assert_eq!(start(e.span), (15, 15));
+                assert_eq!(start(e.term.span), (15, 15));
+            }
+            _ => panic!("Expected a let statement"),
+        }
+    }
+
+    // Testing diagnostics:
+    // ----------------------------------------------------------------------------
+    fn diagnostics(file: &str) {
+        let jack_path: String = format!("{}.jack", file);
+        let exp_path: String = format!("{}Exp.txt", file);
+        let act_path: String = format!("{}Act.txt", file);
+
+        match crate::check_jack_file(&jack_path) {
+            Ok(_) => panic!("Expected diagnostics for Jack file: {}", jack_path),
+            Err(ds) => {
+                let source = std::fs::read_to_string(&jack_path).unwrap();
+                let diagnostic_string = ds
+                    .iter()
+                    .map(|d| crate::pretty_printer::diagnostic::print_diagnostic(d, Some(&source)))
+                    .collect::<Vec<String>>()
+                    .join("\n");
+                std::fs::write(act_path.clone(), diagnostic_string)
+                    .expect("Failed to write output");
+                let actual = std::path::Path::new(&act_path);
+                let expected = std::path::Path::new(&exp_path);
+                assert!(
+                    compare_files(actual, expected),
+                    "{} and {} do not match",
+                    act_path,
+                    exp_path
+                )
+            }
+        }
+    }
+
+    #[test]
+    fn diagnostics_syntax_main() {
+        diagnostics("tests/diagnostics/Syntax/Main")
+    }
+
+    #[test]
+    fn diagnostics_lexical_main() {
+        diagnostics("tests/diagnostics/Lexical/Main")
+    }
+
+    #[test]
+    fn diagnostics_semantic_main() {
+        diagnostics("tests/diagnostics/Semantic/Main")
+    }
+
+    // Testing type checker:
+    // ----------------------------------------------------------------------------
+    fn type_checker(path: &str, strictness: crate::compiler::type_checker::Strictness) {
+        let mode = format!("{:?}", strictness);
+        let exp_path = std::path::Path::new(path).join(format!("{}Exp.txt", mode));
+        let act_path = std::path::Path::new(path).join(format!("{}Act.txt", mode));
+
+        match crate::jack_dir_to_asm(path, strictness) {
+            Ok(_) => panic!("Expected type errors for Jack directory: {}", path),
+            Err(ds) => {
+                let diagnostic_string = ds
+                    .iter()
+                    .map(|d| {
+                        let source = std::fs::read_to_string(&d.file).unwrap();
+                        crate::pretty_printer::diagnostic::print_diagnostic(d, Some(&source))
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n");
+                std::fs::write(&act_path, diagnostic_string).expect("Failed to write output");
+                assert!(
+                    compare_files(&act_path, &exp_path),
+                    "{} and {} do not match",
+                    act_path.display(),
+                    exp_path.display()
+                )
+            }
+        }
+    }
+
+    #[test]
+    fn type_checker_types_lenient() {
+        type_checker(
+            "tests/diagnostics/Types",
+            crate::compiler::type_checker::Strictness::Lenient,
+        )
+    }
+
+    #[test]
+    fn type_checker_types_strict() {
+        type_checker(
+            "tests/diagnostics/Types",
+            crate::compiler::type_checker::Strictness::Strict,
+        )
+    }
+
     // Testing jack_to_vm:
     // ----------------------------------------------------------------------------
     fn jack_to_vm(file: &str) {
@@ -125,11 +286,14 @@ mod tests {
         let exp_path: String = format!("{}Exp.vm", file);
         let act_path: String = format!("{}Act.vm", file);
 
-        let r_commands = crate::jack_to_vm(&jack_path);
+        let r_commands = crate::jack_to_vm(
+            &jack_path,
+            crate::compiler::type_checker::Strictness::Lenient,
+        );
         match r_commands {
             Ok(commands) => {
                 let vm_string = crate::pretty_printer::vm::print_vm(commands);
-                std::fs::write(act_path.clone(), vm_string);
+                std::fs::write(act_path.clone(), vm_string).expect("Failed to write output");
                 let actual = std::path::Path::new(&act_path);
                 let expected = std::path::Path::new(&exp_path);
                 assert!(
@@ -191,6 +355,32 @@ mod tests {
         jack_to_vm("tests/jack_to_vm/ArrayTest/Main")
     }
 
+    // Unqualified calls, and calls through arguments and statics, are resolved
+    //  against the signatures of the classes in the program
+    #[test]
+    fn jack_to_vm_call_resolution() {
+        let source = "class Point {
+    static Point origin;
+    field int x;
+    constructor Point new(int ax) { let x = ax; return this; }
+    function int twice(int a) { return a + a; }
+    method int getX() { return x; }
+    method int sum(Point other) { return getX() + other.getX() + twice(x); }
+    function int fromOrigin() { return origin.getX(); }
+}";
+        let program = crate::Compiler::new()
+            .link_os(false)
+            .compile_sources(&[("Shapes.jack", source)])
+            .unwrap();
+        assert_eq!(program[0].0, "Shapes");
+        let vm = crate::pretty_printer::vm::print_vm(program[0].1.clone());
+        assert!(vm.contains("function Point.new 0"));
+        assert!(vm.contains("push pointer 0\ncall Point.getX 1\n"));
+        assert!(vm.contains("push argument 1\ncall Point.getX 1\n"));
+        assert!(vm.contains("push this 0\ncall Point.twice 1\n"));
+        assert!(vm.contains("push static 0\ncall Point.getX 1\n"));
+    }
+
     // Testing asm_parser:
     // ----------------------------------------------------------------------------
     fn asm_parser(file: &str) {
@@ -202,7 +392,7 @@ mod tests {
         match r_assembly {
             Ok(assembly) => {
                 let asm_string = crate::pretty_printer::asm::print_asm(assembly);
-                std::fs::write(act_path.clone(), asm_string);
+                std::fs::write(act_path.clone(), asm_string).expect("Failed to write output");
                 let actual = std::path::Path::new(&act_path);
                 let expected = std::path::Path::new(&exp_path);
                 assert!(
@@ -254,6 +444,56 @@ mod tests {
         asm_parser("tests/assembler/rect/RectL")
     }
 
+    #[test]
+    fn asm_parser_assembler_syntax_syntax() {
+        asm_parser("tests/assembler/syntax/Syntax")
+    }
+
+    #[test]
+    fn asm_parser_errors() {
+        let cases = [
+            ("@32768", "constant 32768 is out of range 0..=32767", 1, 1),
+            ("@12ab", "invalid constant `12ab`", 1, 1),
+            ("@a-b", "invalid symbol `a-b`", 1, 1),
+            ("(LOOP", "unclosed label", 1, 1),
+            (
+                "@0\n  MA=1",
+                "invalid C-instruction `MA=1`: unknown destination `MA`",
+                2,
+                3,
+            ),
+            (
+                "D=D*A // multiply",
+                "invalid C-instruction `D=D*A`: unknown computation `D*A`",
+                1,
+                1,
+            ),
+            (
+                "0;JMPP",
+                "invalid C-instruction `0;JMPP`: unknown jump `JMPP`",
+                1,
+                1,
+            ),
+            (
+                "\n\nD=;JMP",
+                "invalid C-instruction `D=;JMP`: missing computation",
+                3,
+                1,
+            ),
+        ];
+        for (source, message, line, column) in cases {
+            let errors = crate::parse_asm_source("Test.asm", source).unwrap_err();
+            let span = errors[0].span.unwrap();
+            assert_eq!(errors[0].message, message, "{}", source);
+            assert_eq!(
+                (span.start.line, span.start.column),
+                (line, column),
+                "{}",
+                source
+            );
+        }
+    }
+
     // Testing assembler:
     // ----------------------------------------------------------------------------
     fn assembler(file: &str) {
@@ -265,7 +505,7 @@ mod tests {
         match r_hack {
             Ok(hack) => {
                 let hack_string = hack.join("\n") + "\n";
-                std::fs::write(act_path.clone(), hack_string);
+                std::fs::write(act_path.clone(), hack_string).expect("Failed to write output");
                 let actual = std::path::Path::new(&act_path);
                 let expected = std::path::Path::new(&exp_path);
                 assert!(
@@ -317,6 +557,274 @@ mod tests {
         assembler("tests/assembler/rect/RectL")
     }
 
+    #[test]
+    fn assembler_syntax_syntax() {
+        assembler("tests/assembler/syntax/Syntax")
+    }
+
+    // Testing hack emulator:
+    // ----------------------------------------------------------------------------
+    fn hack(file: &str) -> crate::emulator::hack::Hack {
+        let hack_path: String = format!("{}.hack", file);
+        match crate::load_hack_file(&hack_path) {
+            Ok(hack) => hack,
+            Err(e) => {
+                eprintln!("Error loading {}: {:?}", hack_path, e);
+                panic!("Failed to load hack file: {}", hack_path);
+            }
+        }
+    }
+
+    #[test]
+    fn hack_add() {
+        let mut cpu = hack("tests/assembler/add/AddExp");
+        assert!(cpu.run_until_halt(100), "Add did not halt");
+        assert_eq!(cpu.ram(0), 5);
+    }
+
+    #[test]
+    fn hack_max() {
+        for (r0, r1, max) in [(3, 7, 7), (7, 3, 7), (-4, -9, -4), (5, 5, 5)] {
+            let mut cpu = hack("tests/assembler/max/MaxExp");
+            cpu.set_ram(0, r0).set_ram(1, r1);
+            assert!(cpu.run_until_halt(100), "Max did not halt");
+            assert_eq!(cpu.ram(2), max, "max({}, {})", r0, r1);
+        }
+    }
+
+    #[test]
+    fn hack_maxl_matches_max() {
+        let mut max = hack("tests/assembler/max/MaxExp");
+        let mut max_l = hack("tests/assembler/max/MaxLExp");
+        max.set_ram(0, 12).set_ram(1, 34);
+        max_l.set_ram(0, 12).set_ram(1, 34);
+        max.run(50);
+        max_l.run(50);
+        assert_eq!(max.ram(2), 34);
+        assert_eq!(max_l.ram(2), 34);
+    }
+
+    #[test]
+    fn hack_rect() {
+        let mut cpu = hack("tests/assembler/rect/RectExp");
+        cpu.set_ram(0, 4);
+        assert!(cpu.run_until_halt(1000), "Rect did not halt");
+        let screen = cpu.screen();
+        assert!((0..4).all(|row| screen[row * 32] == 0xFFFF));
+        assert_eq!(screen[4 * 32], 0);
+        assert_eq!(screen[1], 0);
+    }
+
+    #[test]
+    fn hack_keyboard() {
+        let mut cpu = hack("tests/assembler/pong/PongExp");
+        cpu.set_key(130);
+        assert_eq!(cpu.ram(crate::emulator::hack::KBD), 130);
+        cpu.run(10_000);
+        assert!(!cpu.is_halted());
+    }
+
+    // Testing vm emulator:
+    // ----------------------------------------------------------------------------
+    fn vm_emulator(path: &str) -> crate::emulator::vm::Vm {
+        match crate::load_vm(path) {
+            Ok(vm) => vm,
+            Err(e) => {
+                eprintln!("Error loading {}: {:?}", path, e);
+                panic!("Failed to load VM program: {}", path);
+            }
+        }
+    }
+
+    #[test]
+    fn vm_emulator_simpleadd() {
+        let mut vm = vm_emulator("tests/vm_to_asm/SimpleAdd/SimpleAdd.vm");
+        vm.set_ram(0, 256);
+        vm.run(3).unwrap();
+        assert_eq!((vm.ram(0), vm.ram(256)), (257, 15));
+    }
+
+    #[test]
+    fn vm_emulator_basicloop() {
+        let mut vm = vm_emulator("tests/vm_to_asm/BasicLoop/BasicLoop.vm");
+        vm.set_ram(0, 256)
+            .set_ram(1, 300)
+            .set_ram(2, 400)
+            .set_ram(400, 3);
+        vm.run(33).unwrap();
+        assert_eq!((vm.ram(0), vm.ram(256)), (257, 6));
+    }
+
+    #[test]
+    fn vm_emulator_fibonaccielement() {
+        // Starts at Sys.init, the frame of the bootstrap call is left to the caller
+        let mut vm = vm_emulator("tests/vm_to_asm/FibonacciElement");
+        vm.set_ram(0, 261);
+        vm.run(110).unwrap();
+        assert_eq!((vm.ram(0), vm.ram(261)), (262, 3));
+        assert!(vm.is_halted());
+    }
+
+    #[test]
+    fn vm_emulator_nestedcall() {
+        let mut vm = vm_emulator("tests/vm_to_asm/NestedCall");
+        vm.bootstrap().unwrap();
+        assert!(vm.run_until_halt(1000).unwrap(), "NestedCall did not halt");
+        assert_eq!((vm.ram(5), vm.ram(6)), (135, 246));
+        assert_eq!(vm.current_function(), Some("Sys.init"));
+    }
+
+    #[test]
+    fn vm_emulator_staticstest() {
+        let mut vm = vm_emulator("tests/vm_to_asm/StaticsTest");
+        vm.set_ram(0, 261);
+        vm.run(36).unwrap();
+        assert_eq!((vm.ram(0), vm.ram(261), vm.ram(262)), (263, -2, 8));
+    }
+
+    // The VM emulator is the reference for the assembly backend
+    #[test]
+    fn vm_emulator_matches_hack() {
+        let path = "tests/vm_to_asm/FibonacciElement";
+        let mut vm = vm_emulator(path);
+        vm.bootstrap().unwrap();
+        assert!(vm.run_until_halt(10_000).unwrap(), "VM did not halt");
+
+        let asm = crate::vm_to_asm(path).unwrap();
+        let hack = crate::compiler::assembler::Assembler::assemble(asm);
+        let mut cpu = crate::emulator::hack::Hack::from_binary(&hack).unwrap();
+        assert!(cpu.run_until_halt(100_000), "Hack did not halt");
+
+        assert_eq!(vm.ram(0), cpu.ram(0));
+        assert_eq!(vm.ram(261), cpu.ram(261));
+    }
+
+    // Testing test scripts:
+    // ----------------------------------------------------------------------------
+    fn test_script(path: &str) {
+        if let Err(e) = crate::run_test_script(path) {
+            let report = e
+                .iter()
+                .map(|d| crate::pretty_printer::diagnostic::print_diagnostic(d, None))
+                .collect::<String>();
+            panic!("Test script {} failed:\n{}", path, report);
+        }
+    }
+
+    #[test]
+    fn test_script_simpleadd_vme() {
+        test_script("tests/vm_to_asm/SimpleAdd/SimpleAddVME.tst")
+    }
+
+    #[test]
+    fn test_script_stacktest_vme() {
+        test_script("tests/vm_to_asm/StackTest/StackTestVME.tst")
+    }
+
+    #[test]
+    fn test_script_basictest_vme() {
+        test_script("tests/vm_to_asm/BasicTest/BasicTestVME.tst")
+    }
+
+    #[test]
+    fn test_script_pointertest_vme() {
+        test_script("tests/vm_to_asm/PointerTest/PointerTestVME.tst")
+    }
+
+    #[test]
+    fn test_script_statictest_vme() {
+        test_script("tests/vm_to_asm/StaticTest/StaticTestVME.tst")
+    }
+
+    #[test]
+    fn test_script_basicloop_vme() {
+        test_script("tests/vm_to_asm/BasicLoop/BasicLoopVME.tst")
+    }
+
+    #[test]
+    fn test_script_fibonacciseries_vme() {
+        test_script("tests/vm_to_asm/FibonacciSeries/FibonacciSeriesVME.tst")
+    }
+
+    #[test]
+    fn test_script_simplefunction_vme() {
+        test_script("tests/vm_to_asm/SimpleFunction/SimpleFunctionVME.tst")
+    }
+
+    #[test]
+    fn test_script_nestedcall_vme() {
+        test_script("tests/vm_to_asm/NestedCall/NestedCallVME.tst")
+    }
+
+    #[test]
+    fn test_script_fibonaccielement_vme() {
+        test_script("tests/vm_to_asm/FibonacciElement/FibonacciElementVME.tst")
+    }
+
+    #[test]
+    fn test_script_staticstest_vme() {
+        test_script("tests/vm_to_asm/StaticsTest/StaticsTestVME.tst")
+    }
+
+    // `temp[i]` is read where the loaded program keeps its temps, and only
+    //  within their range
+    #[test]
+    fn test_script_temp() {
+        let dir = std::env::temp_dir().join("jack-test-script-temp");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("Temp.vm"), "push constant 7\npop temp 2\n").unwrap();
+        std::fs::write(
+            dir.join("Temp.cmp"),
+            "| RAM[5] | RAM[7] |\n|      5 |      7 |\n",
+        )
+        .unwrap();
+        let script = |commands: &str| {
+            let path = dir.join("Temp.tst");
+            std::fs::write(&path, format!("load Temp.vm, set sp 256, {}", commands)).unwrap();
+            crate::run_test_script(path.to_str().unwrap())
+        };
+        let commands = "compare-to Temp.cmp, output-list RAM[5]%D1.6.1 RAM[7]%D1.6.1;
+            vmstep, vmstep, set temp[0] 5, output;";
+        assert!(script(commands).is_ok());
+        let errors = script("set temp[8] 1;").unwrap_err();
+        assert_eq!(errors[0].message, "temp 8 is out of range");
+    }
+
+    // Numbers that do not fit are reported where they appear
+    #[test]
+    fn test_script_out_of_range() {
+        use chumsky::Parser;
+        let errors = |script: &str| {
+            crate::compiler::tst_parser::parse_tst()
+                .parse(crate::ast::span::char_stream(script))
+                .map(|_| ())
+                .map_err(|errors| crate::char_diagnostics("Test.tst", errors))
+                .unwrap_err()
+                .into_iter()
+                .map(|d| {
+                    let start = d.span.unwrap().start;
+                    format!("{}:{}: {}", start.line, start.column, d.message)
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            errors("set RAM[0] 256,\nset RAM[70000] 1,"),
+            ["2:9: index must be in 0..=65535"]
+        );
+        assert_eq!(
+            errors("set sp 99999999999,"),
+            ["1:8: value must be in -32768..=65535"]
+        );
+        assert_eq!(
+            errors("output-list RAM[0]%D1.99999999999999999999.1;"),
+            ["1:23: format size must be in 0..=65535"]
+        );
+        assert_eq!(
+            errors("repeat 99999999999 { vmstep; }"),
+            ["1:8: repeat count must be in 0..=4294967295"]
+        );
+    }
+
     // Testing vm_parser:
     // ----------------------------------------------------------------------------
     // We modify the file extensions since other tests will
@@ -330,7 +838,7 @@ mod tests {
         match r_commands {
             Ok(commands) => {
                 let vm_string = crate::pretty_printer::vm::print_vm(commands);
-                std::fs::write(act_path.clone(), vm_string);
+                std::fs::write(act_path.clone(), vm_string).expect("Failed to write output");
                 let actual = std::path::Path::new(&act_path);
                 let expected = std::path::Path::new(&exp_path);
                 assert!(
@@ -417,93 +925,1526 @@ mod tests {
         vm_parser("tests/vm_to_asm/StaticTest/StaticTest")
     }
 
+    #[test]
+    fn vm_parser_handwritten() {
+        vm_parser("tests/vm_parser/Handwritten/Handwritten")
+    }
+
+    fn vm_parser_error(source: &str) -> crate::compiler::diagnostic::Diagnostic {
+        let errors = crate::parse_vm_source("Test.vm", source).unwrap_err();
+        errors.into_iter().next().unwrap()
+    }
+
+    #[test]
+    fn vm_parser_errors() {
+        let cases = [
+            (
+                "push constant 32768",
+                "index 32768 is out of range 0..=32767",
+                15,
+            ),
+            ("pop pointer 2", "index 2 is out of range 0..=1", 13),
+            (
+                "push static 32768",
+                "index 32768 is out of range 0..=32767",
+                13,
+            ),
+            ("push constant -1", "expected an index, found `-1`", 15),
+            ("pop constant 0", "cannot pop into the constant segment", 5),
+            ("push heap 0", "unknown segment `heap`", 6),
+            ("add\nmul", "unknown command `mul`", 1),
+            ("add 1", "unexpected `1` after `add`", 5),
+            ("goto 1LOOP", "invalid symbol `1LOOP`", 6),
+            ("call Main.main", "`call` expects 2 argument(s)", 1),
+        ];
+        for (source, message, column) in cases {
+            let error = vm_parser_error(source);
+            assert_eq!(error.message, message, "{}", source);
+            assert_eq!(error.span.unwrap().start.column, column, "{}", source);
+        }
+        let error = vm_parser_error("push constant 1;");
+        assert_eq!(error.span.unwrap().start.column, 16);
+    }
+
     // Testing virtual machine:
     // ----------------------------------------------------------------------------
-    fn vm(path: &str) {
+    // Translate the VM files of a directory to `Dir/Dir.asm`, for the `.tst`
+    //  script of the directory to load
+    fn vm(path: &str, runtime: crate::compiler::runtime::RuntimeProfile) {
         let dir_name = std::path::Path::new(path)
             .file_name()
             .and_then(|s| s.to_str())
             .unwrap_or("Main");
         let act_path = std::path::Path::new(path).join(format!("{}.asm", dir_name));
-        match crate::vm_to_asm(path) {
-            Ok(asm) => {
-                let asm_string = crate::pretty_printer::asm::print_asm(asm);
-                std::fs::write(&act_path, asm_string).expect("Failed to write .asm output");
-            }
-            Err(e) => {
-                eprintln!("Error transforming VM to ASM {}: {:?}", path, e);
-                panic!("Failed to transform VM to ASM: {}", path);
-            }
+        let program = crate::parse_vm_path(path).unwrap();
+        if let Err(e) = crate::compiler::vm_to_asm::VmToAsm::verify(&program, &runtime) {
+            eprintln!("Error transforming VM to ASM {}: {:?}", path, e);
+            panic!("Failed to transform VM to ASM: {}", path);
         }
+        let asm = crate::Compiler::new().runtime(runtime).vm_to_asm(program);
+        let asm_string = crate::pretty_printer::asm::print_asm(asm);
+        std::fs::write(&act_path, asm_string).expect("Failed to write .asm output");
     }
 
-    // The following `vm_to_asm` tests must be ran with the NandToTetris
-    //  provided `CPUEmulator`. Some of the tests are only valid with 
-    // or without bootstrap code. These tests only generate the `.asm` file.
+    // The project 7 tests, and the project 8 tests without `Sys.init`, set the
+    //  registers themselves and expect no bootstrap code
+    fn vm_without_bootstrap(path: &str) {
+        let runtime = crate::compiler::runtime::RuntimeProfile {
+            bootstrap: false,
+            ..Default::default()
+        };
+        vm(path, runtime)
+    }
+
+    fn vm_with_bootstrap(path: &str) {
+        vm(path, crate::compiler::runtime::RuntimeProfile::default())
+    }
+
+    // Each `vm_to_asm` test generates the `.asm` file and runs its `.tst`
+    //  script on the CPU emulator.
     #[test]
     fn vm_to_asm_simpleadd() {
-        // passes without bootstrap code
-        vm("tests/vm_to_asm/SimpleAdd/")
+        vm_without_bootstrap("tests/vm_to_asm/SimpleAdd");
+        test_script("tests/vm_to_asm/SimpleAdd/SimpleAdd.tst")
     }
 
     #[test]
     fn vm_to_asm_stacktest() {
-        // passes without bootstrap code
-        vm("tests/vm_to_asm/StackTest/")
+        vm_without_bootstrap("tests/vm_to_asm/StackTest");
+        test_script("tests/vm_to_asm/StackTest/StackTest.tst")
     }
 
     #[test]
     fn vm_to_asm_basictest() {
-        // passes without bootstrap code
-        vm("tests/vm_to_asm/BasicTest/")
+        vm_without_bootstrap("tests/vm_to_asm/BasicTest");
+        test_script("tests/vm_to_asm/BasicTest/BasicTest.tst")
     }
 
     #[test]
     fn vm_to_asm_pointertest() {
-        // passes without bootstrap code
-        vm("tests/vm_to_asm/PointerTest/")
+        vm_without_bootstrap("tests/vm_to_asm/PointerTest");
+        test_script("tests/vm_to_asm/PointerTest/PointerTest.tst")
     }
 
     #[test]
     fn vm_to_asm_statictest() {
-        // passes without bootstrap code
-        vm("tests/vm_to_asm/StaticTest/")
+        vm_without_bootstrap("tests/vm_to_asm/StaticTest");
+        test_script("tests/vm_to_asm/StaticTest/StaticTest.tst")
     }
 
     #[test]
     fn vm_to_asm_nestedcall() {
         // passes with or without bootstrap code
-        vm("tests/vm_to_asm/NestedCall/")
+        vm_with_bootstrap("tests/vm_to_asm/NestedCall");
+        test_script("tests/vm_to_asm/NestedCall/NestedCall.tst")
     }
 
     #[test]
     fn vm_to_asm_basicloop() {
-        // passes without bootstrap code
-        vm("tests/vm_to_asm/BasicLoop/")
+        vm_without_bootstrap("tests/vm_to_asm/BasicLoop");
+        test_script("tests/vm_to_asm/BasicLoop/BasicLoop.tst")
     }
 
     #[test]
     fn vm_to_asm_fibonacciseries() {
-        // passes with or without bootstrap code
-        vm("tests/vm_to_asm/FibonacciSeries/")
+        vm_without_bootstrap("tests/vm_to_asm/FibonacciSeries");
+        test_script("tests/vm_to_asm/FibonacciSeries/FibonacciSeries.tst")
     }
 
     #[test]
     fn vm_to_asm_simplefunction() {
-        // passes without bootstrap code
-        vm("tests/vm_to_asm/SimpleFunction/")
+        vm_without_bootstrap("tests/vm_to_asm/SimpleFunction");
+        test_script("tests/vm_to_asm/SimpleFunction/SimpleFunction.tst")
     }
 
     #[test]
     fn vm_to_asm_staticstest() {
-        // passes with bootstrap code
-        vm("tests/vm_to_asm/StaticsTest/")
+        vm_with_bootstrap("tests/vm_to_asm/StaticsTest");
+        test_script("tests/vm_to_asm/StaticsTest/StaticsTest.tst")
     }
 
     #[test]
     fn vm_to_asm_fibonaccielement() {
-        // passes with bootstrap code
-        vm("tests/vm_to_asm/FibonacciElement/")
+        vm_with_bootstrap("tests/vm_to_asm/FibonacciElement");
+        test_script("tests/vm_to_asm/FibonacciElement/FibonacciElement.tst")
+    }
+
+    fn vm_to_asm_source(vm: &str) -> Result<String, Vec<crate::compiler::diagnostic::Diagnostic>> {
+        let files = vec![(
+            "Test".to_string(),
+            crate::parse_vm_source("Test.vm", vm).unwrap(),
+        )];
+        crate::compiler::vm_to_asm::VmToAsm::verify(
+            &files,
+            &crate::compiler::runtime::RuntimeProfile::default(),
+        )?;
+        Ok(crate::pretty_printer::asm::print_asm(
+            crate::compiler::vm_to_asm::VmToAsm::compile_program(files),
+        ))
+    }
+
+    #[test]
+    fn vm_to_asm_function_labels() {
+        // The same label in two functions is two labels
+        let asm = vm_to_asm_source(
+            "function Test.f 0\nlabel LOOP\ngoto LOOP\nfunction Test.g 0\nlabel LOOP\ngoto LOOP",
+        )
+        .unwrap();
+        assert!(asm.contains("(Test.f$LOOP)\n@Test.f$LOOP\n0;JMP\n"));
+        assert!(asm.contains("(Test.g$LOOP)\n@Test.g$LOOP\n0;JMP\n"));
+        // Labels before the first function belong to their file, so two
+        //  files may both declare them
+        let asm = vm_to_asm_source("label LOOP\ngoto LOOP").unwrap();
+        assert!(asm.contains("(Test.vm$LOOP)\n@Test.vm$LOOP\n0;JMP\n"));
+        let files: crate::VmProgram = ["A", "B"]
+            .iter()
+            .map(|name| {
+                let vm = "label LOOP\npush constant 0\nif-goto LOOP";
+                (name.to_string(), crate::parse_vm_source(name, vm).unwrap())
+            })
+            .collect();
+        let asm = crate::pretty_printer::asm::print_asm(
+            crate::compiler::vm_to_asm::VmToAsm::compile_program(files.clone()),
+        );
+        assert!(asm.contains("(A.vm$LOOP)") && asm.contains("(B.vm$LOOP)"));
+        assert!(crate::emulator::vm::Vm::new(files).is_ok());
+    }
+
+    #[test]
+    fn vm_to_asm_label_errors() {
+        let messages = |vm: &str| -> Vec<String> {
+            vm_to_asm_source(vm)
+                .unwrap_err()
+                .into_iter()
+                .map(|d| format!("{}: {}", d.file, d.message))
+                .collect()
+        };
+        assert_eq!(
+            messages("function Test.f 0\nlabel L\nlabel L\nreturn"),
+            ["Test.vm: duplicate label `L` in `Test.f`"]
+        );
+        // A label of another function cannot be jumped to, before or after
+        assert_eq!(
+            messages(
+                "function Test.f 0\nlabel L\nreturn\nfunction Test.g 0\nif-goto L\ngoto M\nlabel M\nreturn"
+            ),
+            ["Test.vm: undefined label `L` in `Test.g`"]
+        );
+        assert_eq!(
+            messages("goto L\nfunction Test.f 0\nlabel L\nreturn"),
+            ["Test.vm: undefined label `L` outside any function"]
+        );
+    }
+
+    #[test]
+    fn vm_to_asm_program() {
+        // A directory is one program with a single bootstrap
+        let asm = crate::pretty_printer::asm::print_asm(
+            crate::vm_to_asm("tests/vm_to_asm/StaticsTest").unwrap(),
+        );
+        assert_eq!(asm.matches("(Bootstrap.Sys.init$ret.1)").count(), 1);
+        assert_eq!(asm.matches("@Sys.init\n").count(), 1);
+        assert!(asm.contains("@Class1.0\n") && asm.contains("@Class2.0\n"));
+        // Calls are checked against the functions of every file
+        let files = vec![
+            (
+                "Main".to_string(),
+                crate::parse_vm_source("Main.vm", "function Main.main 0\ncall Util.f 0\nreturn")
+                    .unwrap(),
+            ),
+            (
+                "Util".to_string(),
+                crate::parse_vm_source("Util.vm", "function Util.g 0\ncall Main.main 0\nreturn")
+                    .unwrap(),
+            ),
+        ];
+        let errors: Vec<String> = crate::compiler::vm_to_asm::VmToAsm::verify(
+            &files,
+            &crate::compiler::runtime::RuntimeProfile::default(),
+        )
+        .unwrap_err()
+        .into_iter()
+        .map(|d| format!("{}: {}", d.file, d.message))
+        .collect();
+        assert_eq!(
+            errors,
+            ["Main.vm: undefined function `Util.f` called in `Main.main`"]
+        );
+        // Calls into the OS are only warned about, so programs compiled
+        //  without it still translate
+        let files = vec![(
+            "Main".to_string(),
+            crate::parse_vm_source("Main.vm", "function Main.main 0\ncall Sys.halt 0\nreturn")
+                .unwrap(),
+        )];
+        let warnings = crate::compiler::vm_to_asm::VmToAsm::verify(
+            &files,
+            &crate::compiler::runtime::RuntimeProfile::default(),
+        )
+        .unwrap();
+        assert!(warnings.iter().all(|d| !d.is_error()));
+        assert_eq!(
+            warnings[0].message,
+            "undefined function `Sys.halt` called in `Main.main`"
+        );
+        assert!(crate::Compiler::new()
+            .link_os(false)
+            .compile_asm("tests/jack_to_vm/Square")
+            .is_ok());
+    }
+
+    // Temps and statics are placed where the profile says, on the CPU as on
+    //  the VM emulator, and the bootstrap calls the profile's entry function
+    #[test]
+    fn vm_to_asm_runtime_profile() {
+        let runtime = crate::compiler::runtime::RuntimeProfile {
+            stack_base: 1000,
+            this: Some(3000),
+            entry: "Main.main".to_string(),
+            temp: 20..24,
+            statics: 100..110,
+            ..Default::default()
+        };
+        let vm = "function Main.main 0
+push constant 7
+pop temp 3
+push constant 9
+pop static 0
+push constant 11
+pop this 0
+label END
+goto END";
+        let files = vec![(
+            "Main".to_string(),
+            crate::parse_vm_source("Main.vm", vm).unwrap(),
+        )];
+        crate::compiler::vm_to_asm::VmToAsm::verify(&files, &runtime).unwrap();
+        let mut compiler = crate::Compiler::new();
+        compiler.runtime(runtime.clone());
+        let hack = compiler.assemble(compiler.vm_to_asm(files.clone()));
+        let mut cpu = crate::emulator::hack::Hack::from_binary(&hack).unwrap();
+        assert!(cpu.run_until_halt(1000));
+        let mut emulator =
+            crate::emulator::vm::Vm::with_profile(files.clone(), runtime.clone()).unwrap();
+        emulator.bootstrap().unwrap();
+        assert!(emulator.run_until_halt(1000).unwrap());
+        for (address, value) in [(0, 1005), (23, 7), (100, 9), (3000, 11)] {
+            assert_eq!(cpu.ram(address), value, "CPU RAM[{}]", address);
+            assert_eq!(emulator.ram(address), value, "VM RAM[{}]", address);
+        }
+        // The profile's ranges are checked before translation
+        let errors: Vec<String> = crate::compiler::vm_to_asm::VmToAsm::verify(
+            &files,
+            &crate::compiler::runtime::RuntimeProfile {
+                temp: 5..8,
+                statics: 16..16,
+                ..Default::default()
+            },
+        )
+        .unwrap_err()
+        .into_iter()
+        .map(|d| d.message)
+        .collect();
+        assert_eq!(
+            errors,
+            [
+                "the statics of `Main` do not fit in RAM[16..16]",
+                "temp 3 is out of range in `Main.main`"
+            ]
+        );
+        // Indices beyond the default layout are fine if the profile has room
+        let vm = "push constant 1\npop temp 9\npush constant 2\npop static 250";
+        let files = vec![(
+            "Main".to_string(),
+            crate::parse_vm_source("Main.vm", vm).unwrap(),
+        )];
+        crate::compiler::vm_to_asm::VmToAsm::verify(
+            &files,
+            &crate::compiler::runtime::RuntimeProfile {
+                temp: 5..15,
+                statics: 16..300,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    }
+
+    // Testing peephole optimizer:
+    // ----------------------------------------------------------------------------
+    fn peephole(asm: &str) -> String {
+        let assembly = crate::parse_asm_source("Test.asm", asm).unwrap();
+        let (optimized, _) = crate::compiler::peephole::Peephole::optimize(assembly);
+        crate::pretty_printer::asm::print_asm(optimized)
+    }
+
+    #[test]
+    fn peephole_patterns() {
+        // push constant 7, pop static 0
+        assert_eq!(
+            peephole("@7\nD=A\n@SP\nA=M\nM=D\n@SP\nM=M+1\n@SP\nAM=M-1\nD=M\n@F.0\nM=D"),
+            "@7\nD=A\n@F.0\nM=D\n"
+        );
+        // push constant 7, add
+        assert_eq!(
+            peephole("@7\nD=A\n@SP\nA=M\nM=D\n@SP\nM=M+1\n@SP\nAM=M-1\nD=M\nA=A-1\nM=D+M"),
+            "@7\nD=A\n@SP\nA=M-1\nM=D+M\n"
+        );
+        // goto L, label L
+        assert_eq!(peephole("@L\n0;JMP\n(L)\n@R0"), "(L)\n@R0\n");
+        assert_eq!(peephole("@L\nD;JNE\n(K)\n(L)\nD=0"), "(K)\n(L)\nD=0\n");
+        // Unreachable code after a jump
+        assert_eq!(
+            peephole("@L\n0;JMP\n@SP\nM=M+1\n(K)\nD=0\n(L)"),
+            "@L\n0;JMP\n(K)\nD=0\n(L)\n"
+        );
+        // Jumps that are not to the next instruction, and loads that are used
+        let unchanged = "@L\n0;JMP\n(K)\n@L\nD;JGT\n@SP\nA=M\nM=D\n(L)\n";
+        assert_eq!(peephole(unchanged), unchanged);
+    }
+
+    // The state of a VM program after running on the CPU emulator
+    struct CpuRun {
+        ram: Vec<i16>,
+        report: crate::compiler::peephole::PeepholeReport,
+        cycles: usize,
+        rom: usize,
+    }
+
+    // Run a VM program as translated by `compiler` on the CPU emulator. The
+    //  state kept is the segment pointers, temps, statics and the value on top
+    //  of the stack; the rest of the stack holds return addresses, which move
+    //  when the code changes.
+    fn cpu_run(path: &str, compiler: &crate::Compiler) -> CpuRun {
+        cpu_run_program(path, crate::parse_vm_path(path).unwrap(), compiler)
+    }
+
+    fn cpu_run_program(
+        path: &str,
+        program: crate::VmProgram,
+        compiler: &crate::Compiler,
+    ) -> CpuRun {
+        let (asm, report) = compiler.vm_to_asm_with_report(program);
+        let hack = crate::compiler::assembler::Assembler::assemble(asm);
+        let mut cpu = crate::emulator::hack::Hack::from_binary(&hack).unwrap();
+        assert!(cpu.run_until_halt(1_000_000), "{} does not halt", path);
+        let sp = cpu.ram(0) as u16;
+        let ram = (0..13)
+            .chain(16..256)
+            .chain(sp - 1..sp)
+            .map(|address| cpu.ram(address))
+            .collect();
+        CpuRun {
+            ram,
+            report,
+            cycles: cpu.cycles(),
+            rom: hack.len(),
+        }
+    }
+
+    fn peephole_agrees(path: &str) {
+        let plain = cpu_run(path, &crate::Compiler::new());
+        let optimized = cpu_run(path, crate::Compiler::new().peephole(true));
+        assert_eq!(plain.ram, optimized.ram, "{}", path);
+        assert_eq!(plain.report.saved(), 0);
+        assert_eq!(optimized.report.before, plain.report.after);
+        assert!(optimized.report.saved() > 0);
+        assert!(optimized.cycles < plain.cycles);
+    }
+
+    #[test]
+    fn peephole_nestedcall() {
+        peephole_agrees("tests/vm_to_asm/NestedCall")
+    }
+
+    #[test]
+    fn peephole_staticstest() {
+        peephole_agrees("tests/vm_to_asm/StaticsTest")
+    }
+
+    #[test]
+    fn peephole_fibonaccielement() {
+        peephole_agrees("tests/vm_to_asm/FibonacciElement")
+    }
+
+    // Testing shared routines:
+    // ----------------------------------------------------------------------------
+    fn shared_routines_agree(path: &str) {
+        use crate::compiler::vm_to_asm::Routines;
+        let inline = cpu_run(path, &crate::Compiler::new());
+        let shared = cpu_run(path, crate::Compiler::new().routines(Routines::Shared));
+        assert_eq!(inline.ram, shared.ram, "{}", path);
+        let both = cpu_run(
+            path,
+            crate::Compiler::new()
+                .routines(Routines::Shared)
+                .peephole(true),
+        );
+        assert_eq!(inline.ram, both.ram, "{}", path);
+        assert!(shared.rom < inline.rom);
+    }
+
+    #[test]
+    fn shared_routines_nestedcall() {
+        shared_routines_agree("tests/vm_to_asm/NestedCall")
+    }
+
+    #[test]
+    fn shared_routines_staticstest() {
+        shared_routines_agree("tests/vm_to_asm/StaticsTest")
+    }
+
+    #[test]
+    fn shared_routines_fibonaccielement() {
+        shared_routines_agree("tests/vm_to_asm/FibonacciElement")
+    }
+
+    #[test]
+    fn shared_routines_comparisons() {
+        shared_routines_agree("tests/vm_to_asm/Comparisons");
+        let shared = cpu_run(
+            "tests/vm_to_asm/Comparisons",
+            crate::Compiler::new().routines(crate::compiler::vm_to_asm::Routines::Shared),
+        );
+        // RAM[16..22] follows the pointers and temps, and the sum is on top
+        assert_eq!(shared.ram[13..19], [-1, 0, -1, 0, -1, 0]);
+        assert_eq!(shared.ram.last(), Some(&-1));
+    }
+
+    #[test]
+    fn shared_routines_shrink_the_os() {
+        use crate::compiler::vm_to_asm::Routines;
+        let program = crate::jack_dir_to_vm(
+            "tests/jack_to_vm/Square",
+            crate::compiler::type_checker::Strictness::Lenient,
+        )
+        .unwrap();
+        let size = |routines| {
+            let asm = crate::Compiler::new()
+                .routines(routines)
+                .vm_to_asm(program.clone());
+            crate::compiler::assembler::Assembler::assemble(asm).len()
+        };
+        // A third of the ROM goes to inline calls, returns and comparisons
+        assert!(size(Routines::Shared) * 3 < size(Routines::Inline) * 2);
+    }
+
+    // Testing VM optimizer:
+    // ----------------------------------------------------------------------------
+    fn vm_optimizer(vm: &str) -> String {
+        let commands = crate::parse_vm_source("Test.vm", vm).unwrap();
+        let optimized = crate::compiler::vm_optimizer::VmOptimizer::optimize(commands);
+        crate::pretty_printer::vm::print_vm(optimized)
+    }
+
+    #[test]
+    fn vm_optimizer_patterns() {
+        // Constant folding, with negative results pushed as in Jack
+        assert_eq!(
+            vm_optimizer("push constant 3\npush constant 4\nadd\npop local 0"),
+            "push constant 7\npop local 0\n"
+        );
+        assert_eq!(
+            vm_optimizer("push constant 3\npush constant 4\nsub\nneg\npush constant 1\nneg\nadd"),
+            "push constant 0\n"
+        );
+        assert_eq!(
+            vm_optimizer("push constant 2\npush constant 0\nnot\nlt\nnot\npop temp 0"),
+            "push constant 0\nnot\npop temp 0\n"
+        );
+        // Comparisons wrap around as they do on the CPU: 20000 - -20000
+        //  overflows to a negative number
+        assert_eq!(
+            vm_optimizer("push constant 20000\npush constant 20000\nneg\ngt\npop temp 0"),
+            "push constant 0\npop temp 0\n"
+        );
+        // Branches on constants
+        assert_eq!(
+            vm_optimizer("push constant 5\npush constant 5\neq\nif-goto L\npush constant 0\nif-goto K\nlabel L"),
+            "label L\n"
+        );
+        // push X / pop X, not / not
+        assert_eq!(
+            vm_optimizer("push local 0\npop local 0\npush this 1\nnot\nnot\npop that 0"),
+            "push this 1\npop that 0\n"
+        );
+        // Unreachable code after goto and return
+        assert_eq!(
+            vm_optimizer("goto L\npush constant 1\nlabel K\nreturn\npush constant 0\nfunction F.g 0\nlabel L\nreturn"),
+            "goto L\nlabel K\nreturn\nfunction F.g 0\nlabel L\nreturn\n"
+        );
+        // A while loop tests its condition at the bottom
+        let while_loop = "label W\npush local 0\npush constant 10\nlt\nnot\nif-goto END\n\
+            push local 0\npush constant 1\nadd\npop local 0\ngoto W\nlabel END";
+        assert_eq!(
+            vm_optimizer(while_loop),
+            "goto W\nlabel W_BODY\npush local 0\npush constant 1\nadd\npop local 0\n\
+            label W\npush local 0\npush constant 10\nlt\nif-goto W_BODY\nlabel END\n"
+        );
+        // The body label does not clash with the labels of the file
+        let declared = format!("{}\nlabel W_BODY\nreturn", while_loop);
+        assert_eq!(
+            vm_optimizer(&declared).lines().take(2).collect::<Vec<_>>(),
+            ["goto W", "label W_BODY1"]
+        );
+        // A condition that may not be a boolean keeps its `not`
+        let unchanged = "label W\npush local 0\nnot\nif-goto END\ngoto W\nlabel END\n";
+        assert_eq!(vm_optimizer(unchanged), unchanged);
+    }
+
+    // The segment pointers, temps, statics and value on top of the stack after
+    //  running a program on the VM emulator
+    fn vm_run(files: crate::VmProgram, steps: usize) -> Vec<i16> {
+        let mut vm = crate::emulator::vm::Vm::new(files).unwrap();
+        vm.bootstrap().unwrap();
+        assert!(vm.run_until_halt(steps).unwrap());
+        let sp = vm.ram(0) as u16;
+        (0..13)
+            .chain(16..256)
+            .chain(sp - 1..sp)
+            .map(|address| vm.ram(address))
+            .collect()
+    }
+
+    fn vm_optimizer_agrees(path: &str) {
+        let program = crate::parse_vm_path(path).unwrap();
+        let optimized =
+            crate::compiler::vm_optimizer::VmOptimizer::optimize_program(program.clone());
+        assert_eq!(
+            vm_run(program, 1_000_000),
+            vm_run(optimized, 1_000_000),
+            "{}",
+            path
+        );
+    }
+
+    #[test]
+    fn vm_optimizer_fibonaccielement() {
+        vm_optimizer_agrees("tests/vm_to_asm/FibonacciElement")
+    }
+
+    #[test]
+    fn vm_optimizer_staticstest() {
+        vm_optimizer_agrees("tests/vm_to_asm/StaticsTest")
+    }
+
+    #[test]
+    fn vm_optimizer_comparisons() {
+        vm_optimizer_agrees("tests/vm_to_asm/Comparisons")
+    }
+
+    // 32767 - -1 overflows, so `32767 > -1` is false wherever it is computed
+    #[test]
+    fn vm_optimizer_overflowing_comparison() {
+        let sys = "function Sys.init 0\n\
+            push constant 32767\npush constant 1\nneg\ngt\npop temp 0\n\
+            push constant 32767\npush constant 1\nneg\nlt\npop temp 1\n\
+            label HALT\ngoto HALT\n";
+        let program = vec![(
+            "Sys".to_string(),
+            crate::parse_vm_source("Sys", sys).unwrap(),
+        )];
+        let optimized =
+            crate::compiler::vm_optimizer::VmOptimizer::optimize_program(program.clone());
+        let printed = crate::pretty_printer::vm::print_vm(optimized[0].1.clone());
+        assert!(
+            !printed.contains("gt") && !printed.contains("lt"),
+            "{}",
+            printed
+        );
+        let temps = |files| {
+            let mut vm = crate::emulator::vm::Vm::new(files).unwrap();
+            vm.bootstrap().unwrap().run(100).unwrap();
+            (vm.ram(5), vm.ram(6))
+        };
+        assert_eq!(temps(program.clone()), (0, -1));
+        assert_eq!(temps(optimized), (0, -1));
+        let hack = crate::Compiler::new().assemble(crate::Compiler::new().vm_to_asm(program));
+        let mut cpu = crate::emulator::hack::Hack::from_binary(&hack).unwrap();
+        assert!(cpu.run_until_halt(1_000));
+        assert_eq!((cpu.ram(5), cpu.ram(6)), (0, -1));
+    }
+
+    // The OS runs the same with fewer steps, and its while loops are rotated
+    #[test]
+    fn vm_optimizer_os_arithmetic() {
+        let path = "tests/os/Arithmetic";
+        let run = |optimize_vm| {
+            let files = crate::Compiler::new()
+                .optimize_vm(optimize_vm)
+                .compile_vm(path)
+                .unwrap();
+            let mut vm = crate::emulator::vm::Vm::new(files).unwrap();
+            vm.bootstrap().unwrap();
+            while vm.current_function() != Some("Sys.halt") {
+                vm.step().unwrap();
+            }
+            let results: Vec<i16> = (8000..8016).map(|address| vm.ram(address)).collect();
+            (results, vm.steps())
+        };
+        let (plain, plain_steps) = run(false);
+        let (optimized, optimized_steps) = run(true);
+        assert_eq!(plain, optimized);
+        assert!(optimized_steps < plain_steps);
+    }
+
+    // Testing dead code elimination:
+    // ----------------------------------------------------------------------------
+    #[test]
+    fn dead_code_call_graph() {
+        let program = vec![
+            (
+                "Sys".to_string(),
+                crate::parse_vm_source(
+                    "Sys.vm",
+                    "function Sys.init 0\ncall Main.main 0\nlabel END\ngoto END",
+                )
+                .unwrap(),
+            ),
+            (
+                "Main".to_string(),
+                crate::parse_vm_source(
+                    "Main.vm",
+                    "function Main.main 0\ncall Main.loop 0\nreturn\n\
+                    function Main.loop 0\ncall Main.loop 0\nreturn\n\
+                    function Main.unused 0\ncall Util.helper 0\nreturn",
+                )
+                .unwrap(),
+            ),
+            (
+                "Util".to_string(),
+                crate::parse_vm_source("Util.vm", "function Util.helper 0\nreturn").unwrap(),
+            ),
+        ];
+        let (files, report) =
+            crate::compiler::dead_code::DeadCode::eliminate(program.clone(), "Sys.init");
+        assert_eq!(report.kept, 3);
+        assert_eq!(report.removed, ["Main.unused", "Util.helper"]);
+        let names: Vec<&str> = files.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["Sys", "Main"]);
+        assert_eq!(files[1].1.len(), 6);
+        // Without an entry function there is nothing to start from
+        let (files, report) = crate::compiler::dead_code::DeadCode::eliminate(program, "Main.go");
+        assert_eq!(files.len(), 3);
+        assert!(report.removed.is_empty());
     }
-}
 
+    #[test]
+    fn dead_code_os_arithmetic() {
+        let path = "tests/os/Arithmetic";
+        let program = crate::Compiler::new().compile_vm(path).unwrap();
+        let (files, report) =
+            crate::compiler::dead_code::DeadCode::eliminate(program.clone(), "Sys.init");
+        assert!(report.removed.contains(&"Screen.drawCircle".to_string()));
+        assert!(!report.removed.contains(&"Math.sqrt".to_string()));
+        let rom = |files| crate::Compiler::new().vm_to_asm(files).len();
+        // Most of the ROM goes to `Output.init` and its font, which is kept
+        assert!(rom(files.clone()) * 10 < rom(program) * 9);
+        let mut vm = crate::emulator::vm::Vm::new(files).unwrap();
+        vm.bootstrap().unwrap().run(300_000).unwrap();
+        let results: Vec<i16> = (8000..8016).map(|address| vm.ram(address)).collect();
+        assert_eq!(
+            results,
+            [5535, -56, 123, -14, 100, 4, 3, -4, 32767, 5, -1234, 57, 4, -1, 42, 42]
+        );
+    }
+
+    // Testing inliner:
+    // ----------------------------------------------------------------------------
+    fn inliner(
+        files: &[(&str, &str)],
+        limits: crate::compiler::inliner::InlineLimits,
+    ) -> Vec<String> {
+        let program = files
+            .iter()
+            .map(|(name, vm)| (name.to_string(), crate::parse_vm_source(name, vm).unwrap()))
+            .collect();
+        let (inlined, _) = crate::compiler::inliner::Inliner::inline(program, limits);
+        inlined
+            .into_iter()
+            .map(|(_, commands)| crate::pretty_printer::vm::print_vm(commands))
+            .collect()
+    }
+
+    #[test]
+    fn inliner_patterns() {
+        let limits = crate::compiler::inliner::InlineLimits {
+            max_size: 13,
+            max_caller_size: 1000,
+        };
+        // Arguments and locals become locals of the caller, and a method's
+        //  pointer is restored after its body
+        let main = "function Main.main 1\npush local 0\ncall Point.getX 1\n\
+            push constant 3\ncall Math.abs 1\nadd\nreturn\n";
+        let point = "function Point.getX 0\npush argument 0\npop pointer 0\npush this 0\nreturn\n";
+        let math = "function Math.abs 1\npush argument 0\npop local 0\npush local 0\n\
+            push constant 0\nlt\nnot\nif-goto POSITIVE\npush local 0\nneg\nreturn\n\
+            label POSITIVE\npush local 0\nreturn\n";
+        let inlined = inliner(&[("Main", main), ("Point", point), ("Math", math)], limits);
+        assert_eq!(
+            inlined[0],
+            "function Main.main 3\npush local 0\n\
+            pop local 1\npush pointer 0\npop local 2\n\
+            push local 1\npop pointer 0\npush this 0\n\
+            push local 2\npop pointer 0\n\
+            push constant 3\n\
+            pop local 1\npush constant 0\npop local 2\n\
+            push local 1\npop local 2\npush local 2\npush constant 0\nlt\nnot\n\
+            if-goto INLINE1_POSITIVE\npush local 2\nneg\ngoto INLINE1$END\n\
+            label INLINE1_POSITIVE\npush local 2\nlabel INLINE1$END\n\
+            add\nreturn\n"
+        );
+        // Recursive functions, bodies over the size limit and statics of
+        //  another file are left as calls
+        let main = "function Main.main 0\npush constant 5\ncall Main.count 1\n\
+            call Main.id 1\ncall Counter.next 0\nreturn\n\
+            function Main.count 0\npush argument 0\ncall Main.count 1\nreturn\n\
+            function Main.id 0\npush argument 0\nreturn\n";
+        let counter = "function Counter.next 0\npush static 0\nreturn\n";
+        let limits = crate::compiler::inliner::InlineLimits {
+            max_size: 1,
+            ..limits
+        };
+        let inlined = inliner(&[("Main", main), ("Counter", counter)], limits);
+        assert_eq!(
+            inlined[0].lines().take(6).collect::<Vec<_>>(),
+            [
+                "function Main.main 0",
+                "push constant 5",
+                "call Main.count 1",
+                "call Main.id 1",
+                "call Counter.next 0",
+                "return"
+            ]
+        );
+    }
+
+    #[test]
+    fn inliner_end_label() {
+        // The callee's own `END` label and the end of its inlined body must
+        //  not clash when it returns from more than one place, nor with the
+        //  labels the caller already declares
+        let sys = "function Sys.init 0\npush constant 1\ncall Sys.pick 1\npop temp 2\n\
+            label INLINE0_END\nlabel INLINE0$END\ngoto INLINE0_END\n\
+            function Sys.pick 0\npush argument 0\nif-goto END\npush constant 10\nreturn\n\
+            label END\npush constant 20\nreturn\n";
+        let program = vec![(
+            "Sys".to_string(),
+            crate::parse_vm_source("Sys", sys).unwrap(),
+        )];
+        let (inlined, report) = crate::compiler::inliner::Inliner::inline(
+            program,
+            crate::compiler::inliner::InlineLimits::default(),
+        );
+        assert_eq!(report.inlined, 1);
+        let profile = crate::compiler::runtime::RuntimeProfile::default();
+        assert!(crate::compiler::vm_to_asm::VmToAsm::verify(&inlined, &profile).is_ok());
+        let mut vm = crate::emulator::vm::Vm::new(inlined).unwrap();
+        vm.bootstrap().unwrap().run(100).unwrap();
+        assert_eq!(vm.ram(7), 20);
+    }
+
+    #[test]
+    fn inliner_nestedcall() {
+        let path = "tests/vm_to_asm/NestedCall";
+        let program = crate::parse_vm_path(path).unwrap();
+        let (inlined, report) = crate::compiler::inliner::Inliner::inline(
+            program.clone(),
+            crate::compiler::inliner::InlineLimits::default(),
+        );
+        assert_eq!(report.inlined, 1);
+        let plain = cpu_run_program(path, program, &crate::Compiler::new());
+        let optimized = cpu_run_program(path, inlined, &crate::Compiler::new());
+        assert_eq!(plain.ram, optimized.ram);
+        assert!(optimized.cycles < plain.cycles);
+    }
+
+    #[test]
+    fn inliner_os_arithmetic() {
+        let path = "tests/os/Arithmetic";
+        let program = crate::Compiler::new().compile_vm(path).unwrap();
+        let (inlined, report) = crate::compiler::inliner::Inliner::inline(
+            program.clone(),
+            crate::compiler::inliner::InlineLimits::default(),
+        );
+        assert!(report.inlined > 0);
+        let run = |files| {
+            let mut vm = crate::emulator::vm::Vm::new(files).unwrap();
+            vm.bootstrap().unwrap();
+            // `Sys.halt` may be inlined, so run until `Main.main` returns
+            for function in ["Main.main", "Sys.init"] {
+                while vm.current_function() != Some(function) {
+                    vm.step().unwrap();
+                }
+            }
+            let results: Vec<i16> = (8000..8016).map(|address| vm.ram(address)).collect();
+            (results, vm.steps())
+        };
+        let (plain, plain_steps) = run(program);
+        let (results, steps) = run(inlined);
+        assert_eq!(plain, results);
+        // Inlined calls run more VM commands, but far fewer instructions
+        assert!(steps < plain_steps * 11 / 10);
+    }
+
+    // Testing linker:
+    // ----------------------------------------------------------------------------
+    fn linker(path: &str) {
+        let dir_name = std::path::Path::new(path)
+            .file_name()
+            .and_then(|s| s.to_str())
+            .unwrap_or("Main");
+        let act_path = std::path::Path::new(path).join(format!("{}Act.asm", dir_name));
+        match crate::jack_dir_to_asm(path, crate::compiler::type_checker::Strictness::Lenient) {
+            Ok(asm) => {
+                let asm_string = crate::pretty_printer::asm::print_asm(asm);
+                std::fs::write(&act_path, &asm_string).expect("Failed to write .asm output");
+                assert_eq!(
+                    asm_string.matches("@Sys.init\n").count(),
+                    1,
+                    "{} must contain exactly one bootstrap",
+                    act_path.display()
+                )
+            }
+            Err(e) => {
+                eprintln!("Error linking {}: {:?}", path, e);
+                panic!("Failed to link Jack directory: {}", path);
+            }
+        }
+    }
+
+    #[test]
+    fn linker_square() {
+        linker("tests/jack_to_vm/Square")
+    }
+    // Testing the OS:
+    // ----------------------------------------------------------------------------
+    // Programs are linked with the OS and run on the VM emulator. `Sys.halt`
+    //  loops forever, so they run for a fixed number of steps.
+    fn os_program(path: &str, steps: usize) -> crate::emulator::vm::Vm {
+        let files =
+            match crate::jack_dir_to_vm(path, crate::compiler::type_checker::Strictness::Lenient) {
+                Ok(files) => files,
+                Err(e) => {
+                    eprintln!("Error compiling {}: {:?}", path, e);
+                    panic!("Failed to compile Jack directory: {}", path);
+                }
+            };
+        let mut vm = crate::emulator::vm::Vm::new(files)
+            .unwrap_or_else(|e| panic!("Failed to load {}: {}", path, e));
+        vm.bootstrap()
+            .and_then(|vm| vm.run(steps))
+            .unwrap_or_else(|e| panic!("Failed to run {}: {}", path, e));
+        assert_eq!(vm.current_function(), Some("Sys.halt"));
+        vm
+    }
+
+    #[test]
+    fn os_arithmetic() {
+        let vm = os_program("tests/os/Arithmetic", 300_000);
+        let results: Vec<i16> = (8000..8016).map(|address| vm.ram(address)).collect();
+        assert_eq!(
+            results,
+            [5535, -56, 123, -14, 100, 4, 3, -4, 32767, 5, -1234, 57, 4, -1, 42, 42]
+        );
+    }
+
+    #[test]
+    fn os_output() {
+        let vm = os_program("tests/os/Arithmetic", 300_000);
+        let screen = crate::emulator::hack::SCREEN;
+        // `H` in the low byte and `i` in the high byte of the first word
+        assert_eq!(vm.ram(screen), 51 | (12 << 8));
+        assert_eq!(vm.ram(screen + 32 * 3), 51 | (14 << 8));
+        assert_eq!(vm.ram(screen + 32 * 200), -1);
+        assert_eq!(vm.ram(screen + 32 * 201), -1);
+        assert_eq!(vm.ram(screen + 32 * 202), 0);
+    }
+
+    // A `Main.main` linked with the OS and run on the VM emulator until it halts
+    fn os_main(main: &str) -> crate::emulator::vm::Vm {
+        let source = format!(
+            "class Main {{ function void main() {{ {} return; }} }}",
+            main
+        );
+        let files = crate::Compiler::new()
+            .compile_sources(&[("Main.jack", &source)])
+            .unwrap();
+        let mut vm = crate::emulator::vm::Vm::new(files).unwrap();
+        vm.bootstrap().unwrap().run(300_000).unwrap();
+        assert_eq!(vm.current_function(), Some("Sys.halt"));
+        vm
+    }
+
+    // The smallest int has no positive counterpart, but is still printed
+    #[test]
+    fn os_string_set_int() {
+        let vm = os_main(
+            "var String s; var int i;\
+            let s = String.new(6); do s.setInt(-32767 - 1); let i = 0;\
+            while (i < s.length()) { do Memory.poke(8000 + i, s.charAt(i)); let i = i + 1; }",
+        );
+        let chars: String = (8000..8006)
+            .map(|address| vm.ram(address) as u8 as char)
+            .collect();
+        assert_eq!(chars, "-32768");
+    }
+
+    // Division by and of the smallest int, which has no absolute value
+    #[test]
+    fn os_divide_min() {
+        let vm = os_main(
+            "var Array a; var int min; let a = 8000; let min = -32767 - 1;\
+            let a[0] = min / 1; let a[1] = min / -2; let a[2] = min / 7;\
+            let a[3] = min / -7; let a[4] = min / min; let a[5] = 32767 / min;",
+        );
+        let results: Vec<i16> = (8000..8006).map(|address| vm.ram(address)).collect();
+        assert_eq!(results, [-32768, 16384, -4681, 4681, 1, 0]);
+    }
+
+    // Straight lines are drawn in full, in either direction
+    #[test]
+    fn os_draw_line() {
+        let vm = os_main(
+            "do Screen.drawLine(0, 0, 40, 0); do Screen.drawLine(40, 2, 0, 2);\
+            do Screen.drawLine(0, 4, 0, 6); do Screen.drawLine(0, 8, 2, 10);",
+        );
+        let screen = crate::emulator::hack::SCREEN;
+        let row = |y: u16| {
+            (0..3)
+                .map(|i| vm.ram(screen + 32 * y + i))
+                .collect::<Vec<_>>()
+        };
+        // Pixels 0 to 40 span two full words and 9 pixels of a third
+        assert_eq!(row(0), [-1, -1, 0x1ff]);
+        assert_eq!(row(2), [-1, -1, 0x1ff]);
+        assert_eq!([row(4)[0], row(5)[0], row(6)[0], row(7)[0]], [1, 1, 1, 0]);
+        // Other lines step along one axis at a time
+        assert_eq!([row(8)[0], row(9)[0], row(10)[0]], [1, 3, 6]);
+    }
+
+    // With labels scoped to their functions, programs linked with the OS also
+    //  run on the CPU emulator
+    #[test]
+    fn os_arithmetic_cpu() {
+        let hack = crate::jack_dir_to_hack(
+            "tests/os/Arithmetic",
+            crate::compiler::type_checker::Strictness::Lenient,
+        )
+        .unwrap();
+        let mut cpu = crate::emulator::hack::Hack::from_binary(&hack).unwrap();
+        cpu.run(5_000_000);
+        let results: Vec<i16> = (8000..8016).map(|address| cpu.ram(address)).collect();
+        assert_eq!(
+            results,
+            [5535, -56, 123, -14, 100, 4, 3, -4, 32767, 5, -1234, 57, 4, -1, 42, 42]
+        );
+        let screen = crate::emulator::hack::SCREEN;
+        assert_eq!(cpu.ram(screen), 51 | (12 << 8));
+    }
+
+    // The OS is not checked when it is linked, so it must pass the checks here
+    #[test]
+    fn os_classes_check() {
+        let classes: Vec<(String, crate::ast::jack::Class)> = crate::os::library::CLASSES
+            .iter()
+            .map(|(name, source)| {
+                let file_path = format!("os/{}.jack", name);
+                let class = crate::parse_jack_source(&file_path, source)
+                    .unwrap_or_else(|e| panic!("Failed to parse {}: {:?}", file_path, e));
+                let diagnostics = crate::compiler::checker::Checker::check(&file_path, &class);
+                assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+                (file_path, class)
+            })
+            .collect();
+        let main = crate::parse_jack_file("tests/os/Arithmetic/Main.jack").unwrap();
+        let linked = vec![("Main.jack".to_string(), main)];
+        let result = crate::type_check_classes(
+            &classes,
+            &linked,
+            crate::compiler::type_checker::Strictness::Lenient,
+        );
+        assert!(result.is_ok(), "{:?}", result.err());
+    }
+
+    // The linked OS must leave room in the ROM for the programs using it
+    #[test]
+    fn os_fits_in_rom() {
+        for path in ["tests/jack_to_vm/Square", "tests/os/Arithmetic"] {
+            let hack =
+                crate::jack_dir_to_hack(path, crate::compiler::type_checker::Strictness::Lenient)
+                    .unwrap_or_else(|e| panic!("Failed to compile {}: {:?}", path, e));
+            assert!(
+                crate::emulator::hack::Hack::from_binary(&hack).is_ok(),
+                "{} does not fit in the ROM",
+                path
+            );
+        }
+    }
+    // Testing operator precedence:
+    // ----------------------------------------------------------------------------
+    const PRECEDENCE: &str = "class Main {
+    function int main() {
+        var int a, b;
+        let a = 2 + 3 * 4;
+        let b = a * 2 + 1;
+        if (a & b = 1 + a) { return Main.f(a - b / 2); }
+        return a;
+    }
+    function int f(int x) { return x; }
+}";
+
+    #[test]
+    fn precedence_climbing() {
+        let class = crate::parse_jack_source("Main.jack", PRECEDENCE).unwrap();
+        let climbed = crate::compiler::precedence::climb_class(class).pretty_print(0);
+        assert!(climbed.contains("let a = 2 + (3 * 4);"), "{}", climbed);
+        assert!(climbed.contains("let b = a * 2 + 1;"), "{}", climbed);
+        assert!(climbed.contains("if (a & (b = (1 + a)))"), "{}", climbed);
+        assert!(climbed.contains("Main.f(a - (b / 2))"), "{}", climbed);
+
+        let program = crate::Compiler::new()
+            .link_os(false)
+            .precedence(crate::compiler::precedence::Precedence::Climbing)
+            .compile_sources(&[("Main.jack", PRECEDENCE)])
+            .unwrap();
+        let vm = crate::pretty_printer::vm::print_vm(program[0].1.clone());
+        assert!(vm.starts_with(
+            "function Main.main 2\npush constant 2\npush constant 3\npush constant 4\n\
+            call Math.multiply 2\nadd\npop local 0\n"
+        ));
+    }
+
+    #[test]
+    fn precedence_warnings() {
+        let (_, warnings) = crate::Compiler::new()
+            .link_os(false)
+            .compile_sources_with_warnings(&[("Main.jack", PRECEDENCE)])
+            .unwrap();
+        let messages: Vec<(&str, &str)> = warnings
+            .iter()
+            .map(|w| (w.message.as_str(), w.notes[0].as_str()))
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                (
+                    "`2 + 3 * 4` is evaluated from left to right, as `(2 + 3) * 4`",
+                    "with operator precedence it would be `2 + (3 * 4)`"
+                ),
+                (
+                    "`a & b = 1 + a` is evaluated from left to right, as `((a & b) = 1) + a`",
+                    "with operator precedence it would be `a & (b = (1 + a))`"
+                ),
+                (
+                    "`a - b / 2` is evaluated from left to right, as `(a - b) / 2`",
+                    "with operator precedence it would be `a - (b / 2)`"
+                ),
+            ]
+        );
+        assert!(warnings.iter().all(|w| !w.is_error()));
+        assert_eq!(warnings[0].span.unwrap().start.line, 4);
+        // With the OS linked, only the program's own classes are warned about
+        let (_, warnings) = crate::Compiler::new()
+            .compile_sources_with_warnings(&[("Main.jack", PRECEDENCE)])
+            .unwrap();
+        assert_eq!(warnings.len(), 3);
+        assert!(warnings.iter().all(|w| !w.file.starts_with("os/")));
+        // The dialect raises no warnings
+        let (_, warnings) = crate::Compiler::new()
+            .precedence(crate::compiler::precedence::Precedence::Climbing)
+            .compile_sources_with_warnings(&[("Main.jack", PRECEDENCE)])
+            .unwrap();
+        assert!(warnings.is_empty());
+    }
+
+    // Testing source maps:
+    // ----------------------------------------------------------------------------
+    // Every ROM address maps back through the printed assembly and VM code to
+    //  the Jack statement it was compiled from
+    #[test]
+    fn source_map_jack() {
+        use crate::compiler::source_map::JackLocation;
+        let path = "tests/jack_to_vm/Factorial/Main.jack";
+        let (hack, map) = crate::Compiler::new().compile_hack_with_map(path).unwrap();
+        assert_eq!(map.entries.len(), hack.len());
+        assert!(map
+            .entries
+            .iter()
+            .enumerate()
+            .all(|(i, entry)| entry.address as usize == i));
+        // The bootstrap comes from no VM command
+        assert_eq!(map.lookup(0).unwrap().vm, None);
+
+        let asm = crate::pretty_printer::asm::print_asm(
+            crate::Compiler::new().compile_asm(path).unwrap(),
+        );
+        let asm: Vec<&str> = asm.lines().collect();
+        assert!(map
+            .entries
+            .iter()
+            .all(|entry| !asm[entry.asm_line - 1].starts_with('(')));
+        let program = crate::Compiler::new().compile_vm(path).unwrap();
+        let vm = crate::pretty_printer::vm::print_vm(program[0].1.clone());
+        let vm: Vec<&str> = vm.lines().collect();
+        // The VM commands with instructions compiled from the statement at
+        //  `line` and `column`
+        let commands = |line: usize, column: usize| {
+            let location = Some(JackLocation {
+                file: path.to_string(),
+                line,
+                column,
+            });
+            let mut commands: Vec<&str> = map
+                .entries
+                .iter()
+                .filter(|entry| entry.jack == location)
+                .map(|entry| {
+                    let vm_location = entry.vm.as_ref().unwrap();
+                    assert_eq!(vm_location.file, "Main.vm");
+                    vm[vm_location.line - 1]
+                })
+                .collect();
+            commands.dedup();
+            commands
+        };
+        assert_eq!(commands(16, 7), ["call Output.println 0", "pop temp 0"]);
+        assert_eq!(commands(24, 9), ["push constant 1", "return"]);
+        // The commands of an `if` around its branches come from the `if`
+        assert_eq!(
+            commands(23, 7),
+            [
+                "push argument 0",
+                "push constant 0",
+                "eq",
+                "if-goto IF_TRUE0",
+                "goto IF_FALSE0",
+                "goto IF_END0"
+            ]
+        );
+        // Optimizers rewrite the code the map would refer to
+        let errors = crate::Compiler::new()
+            .peephole(true)
+            .compile_hack_with_map(path)
+            .unwrap_err();
+        assert_eq!(
+            errors[0].message,
+            "a source map cannot be made with the `peephole` optimization"
+        );
+    }
+
+    // VM programs map back to the lines of their files, comments included
+    #[test]
+    fn source_map_vm() {
+        use crate::compiler::source_map::{SourceMap, VmLocation};
+        let path = "tests/vm_to_asm/BasicLoop/BasicLoop.vm";
+        let (files, vm) = crate::parse_vm_path_with_origins(path).unwrap();
+        let mut compiler = crate::Compiler::new();
+        compiler.runtime(crate::compiler::runtime::RuntimeProfile {
+            bootstrap: false,
+            ..Default::default()
+        });
+        let (asm, origins) = compiler.vm_to_asm_with_origins(files.clone());
+        let map = SourceMap::new(&vm, &[], &asm, &origins);
+        let line = |address: u16| map.lookup(address).unwrap().vm.clone();
+        assert_eq!(
+            line(0),
+            Some(VmLocation {
+                file: path.to_string(),
+                line: 10,
+            })
+        );
+        // `label LOOP` on line 12 takes no ROM
+        let mut lines: Vec<usize> = map
+            .entries
+            .iter()
+            .map(|entry| entry.vm.as_ref().unwrap().line)
+            .collect();
+        lines.dedup();
+        assert_eq!(lines[..6], [10, 11, 13, 14, 15, 16]);
+        assert!(map.entries.iter().all(|entry| entry.jack.is_none()));
+        assert_eq!(
+            crate::pretty_printer::source_map::print_source_map(&map)
+                .lines()
+                .next(),
+            Some(format!("0\t1\t{}:10\t-", path).as_str())
+        );
+        // The peephole optimizer merges the instructions of commands
+        let (asm, origins) = compiler.peephole(true).vm_to_asm_with_origins(files);
+        assert_eq!(origins.len(), asm.len());
+        assert!(origins.iter().all(|origin| origin.is_none()));
+    }
+
+    // Testing the library API:
+    // ----------------------------------------------------------------------------
+    #[test]
+    fn library_compile_sources() {
+        use crate::ast::vm::{Command, Function};
+        let source = "class Main { function void main() { do Output.printInt(7); return; } }";
+        let program = crate::Compiler::new()
+            .link_os(false)
+            .compile_sources(&[("Main.jack", source)])
+            .unwrap();
+        assert_eq!(program.len(), 1);
+        assert_eq!(
+            program[0].1[0],
+            Command::Function(Function::Body("Main.main".to_string(), 0))
+        );
+
+        let linked = crate::Compiler::new()
+            .compile_sources(&[("Main.jack", source)])
+            .unwrap();
+        assert_eq!(linked.len(), 1 + crate::os::library::CLASSES.len());
+    }
+
+    #[test]
+    fn library_compile_sources_reports_every_file() {
+        let errors = crate::Compiler::new()
+            .compile_sources(&[
+                ("A.jack", "class A { function void f() { return } }"),
+                (
+                    "B.jack",
+                    "class B { function void f() { let x = 1; return; } }",
+                ),
+            ])
+            .unwrap_err();
+        assert!(errors.iter().any(|d| d.file == "A.jack"));
+        assert!(errors.iter().any(|d| d.file == "B.jack"));
+    }
+
+    #[test]
+    fn library_ast_constructors() {
+        use crate::ast::jack::*;
+        let body = SubroutineBody {
+            var_decs: vec![],
+            stmts: vec![Statement::new(StatementKind::Return(Some(
+                Expression::new(
+                    Term::new(TermKind::IntegerConstant(1)),
+                    vec![(BinaryOp::Plus, Term::new(TermKind::IntegerConstant(2)))],
+                ),
+            )))],
+        };
+        let class = Class::new(
+            "Main",
+            ClassDec {
+                class_var_dec: vec![],
+                subroutine_dec: vec![SubroutineDec::new(
+                    SubroutineType::Function,
+                    SubroutineReturnType::Type(Type::Int),
+                    "three",
+                    vec![],
+                    body,
+                )],
+            },
+        );
+        let table = crate::compiler::symbol_table::SymbolTable::new(&[&class]);
+        let vm = crate::compiler::jack_to_vm::JackToVm::compile(class, &table);
+        let vm_string = crate::pretty_printer::vm::print_vm(vm);
+        assert!(vm_string.contains("function Main.three 0"));
+        assert!(vm_string.contains("add"));
+    }
+    // Testing the command line:
+    // ----------------------------------------------------------------------------
+    fn cli_options(args: &[&str]) -> Result<crate::cli::Invocation, String> {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        crate::cli::Options::parse(&args)
+    }
+
+    #[test]
+    fn cli_parse_options() {
+        use crate::cli::{Command, Emit, Invocation, Optimization, Options};
+        assert_eq!(
+            cli_options(&[
+                "asm",
+                "--strict",
+                "Prog",
+                "-o",
+                "out.asm",
+                "--emit",
+                "vm,tokens",
+                "--optimize",
+                "inline,vm,peephole,shared-routines",
+                "--inline-size",
+                "20",
+                "--precedence"
+            ]),
+            Ok(Invocation::Execute(Options {
+                command: Command::Asm,
+                path: "Prog".to_string(),
+                output: Some("out.asm".to_string()),
+                emit: vec![Emit::Vm, Emit::Tokens],
+                optimizations: vec![
+                    Optimization::Inline,
+                    Optimization::Vm,
+                    Optimization::Peephole,
+                    Optimization::SharedRoutines
+                ],
+                inline: crate::compiler::inliner::InlineLimits {
+                    max_size: 20,
+                    max_caller_size: 1000,
+                },
+                strictness: crate::compiler::type_checker::Strictness::Strict,
+                precedence: crate::compiler::precedence::Precedence::Climbing,
+                steps: 10_000_000,
+                runtime: crate::compiler::runtime::RuntimeProfile::default(),
+            }))
+        );
+        match cli_options(&[
+            "run",
+            "--no-bootstrap",
+            "--stack-base",
+            "317",
+            "--lcl",
+            "317",
+            "--arg",
+            "310",
+            "--entry",
+            "Main.main",
+            "--temp",
+            "5..9",
+            "--static",
+            "32..64",
+            "Prog",
+        ]) {
+            Ok(Invocation::Execute(options)) => assert_eq!(
+                options.runtime,
+                crate::compiler::runtime::RuntimeProfile {
+                    bootstrap: false,
+                    stack_base: 317,
+                    lcl: Some(317),
+                    arg: Some(310),
+                    this: None,
+                    that: None,
+                    entry: "Main.main".to_string(),
+                    temp: 5..9,
+                    statics: 32..64,
+                }
+            ),
+            other => panic!("unexpected {:?}", other),
+        }
+        // A lone path is compiled to hack
+        match cli_options(&["Prog.jack"]) {
+            Ok(Invocation::Execute(options)) => assert_eq!(options.command, Command::Hack),
+            other => panic!("unexpected {:?}", other),
+        }
+        match cli_options(&[
+            "hack",
+            "--emit",
+            "map",
+            "--optimize",
+            "shared-routines",
+            "Prog",
+        ]) {
+            Ok(Invocation::Execute(options)) => assert_eq!(options.emit, [Emit::Map]),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert_eq!(cli_options(&["run", "-h"]), Ok(Invocation::Help));
+        assert_eq!(cli_options(&["--version"]), Ok(Invocation::Version));
+    }
+
+    #[test]
+    fn cli_parse_errors() {
+        assert_eq!(cli_options(&[]), Err("missing path".to_string()));
+        assert_eq!(
+            cli_options(&["run", "--static", "256..16", "Prog"]),
+            Err(
+                "`--static` expects a range of addresses like `16..256`, found `256..16`"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            cli_options(&["run", "--stack-base", "-1", "Prog"]),
+            Err("`--stack-base` expects an address, found `-1`".to_string())
+        );
+        assert_eq!(
+            cli_options(&["compile", "Prog"]),
+            Err("unknown command `compile`".to_string())
+        );
+        assert_eq!(
+            cli_options(&["vm", "-o"]),
+            Err("`-o` expects a value".to_string())
+        );
+        assert_eq!(
+            cli_options(&["vm", "--emit", "html", "Prog"]),
+            Err("unknown stage `html`, expected `tokens`, `xml`, `vm`, `asm` or `map`".to_string())
+        );
+        assert_eq!(
+            cli_options(&["hack", "--emit", "map", "--optimize", "peephole", "Prog"]),
+            Err("`--emit map` cannot be combined with the `peephole` optimization".to_string())
+        );
+        assert_eq!(
+            cli_options(&["hack", "--optimize", "fast", "Prog"]),
+            Err(
+                "unknown optimization `fast`, expected `inline`, `dead-code`, `vm`, `peephole` or `shared-routines`"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            cli_options(&["hack", "--inline-size", "small", "Prog"]),
+            Err("`--inline-size` expects a number, found `small`".to_string())
+        );
+        assert_eq!(
+            cli_options(&["vm", "--fast", "Prog"]),
+            Err("unknown option `--fast`".to_string())
+        );
+    }
+
+    fn cli(args: &[&str]) -> Result<(), Vec<crate::compiler::diagnostic::Diagnostic>> {
+        match cli_options(args) {
+            Ok(crate::cli::Invocation::Execute(options)) => crate::cli::execute(&options),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn cli_tokenize() {
+        let act_path = "tests/lexer/ArrayTest/MainCliAct.xml";
+        assert!(cli(&[
+            "tokenize",
+            "tests/lexer/ArrayTest/Main.jack",
+            "-o",
+            act_path
+        ])
+        .is_ok());
+        assert!(compare_files(
+            std::path::Path::new(act_path),
+            std::path::Path::new("tests/lexer/ArrayTest/MainExp.xml")
+        ));
+    }
+
+    #[test]
+    fn cli_hack_from_asm() {
+        let act_path = "tests/assembler/max/MaxCliAct.hack";
+        assert!(cli(&["hack", "tests/assembler/max/Max.asm", "-o", act_path]).is_ok());
+        let actual = std::fs::read_to_string(act_path).unwrap();
+        let expected = std::fs::read_to_string("tests/assembler/max/MaxExp.hack").unwrap();
+        assert_eq!(actual.trim_end(), expected.trim_end());
+    }
+
+    #[test]
+    fn cli_rejects_unsupported_input() {
+        let errors = cli(&["fmt", "tests/assembler/max/Max.asm"]).unwrap_err();
+        assert_eq!(errors[0].message, "`fmt` cannot be applied to this input");
+    }
+
+    // The `vm` command leaves out the OS, which is linked back in when its
+    //  output is read again
+    #[test]
+    fn cli_vm_round_trip() {
+        let dir = std::env::temp_dir().join("jack-cli-vm-round-trip");
+        std::fs::create_dir_all(&dir).unwrap();
+        let dir = dir.to_str().unwrap();
+        assert!(cli(&["vm", "-o", dir, "tests/jack_to_vm/Square"]).is_ok());
+        let hack_path = format!("{}/Square.hack", dir);
+        assert!(cli(&["hack", dir, "-o", &hack_path]).is_ok());
+        let hack: Vec<String> = std::fs::read_to_string(&hack_path)
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect();
+        let mut cpu = crate::emulator::hack::Hack::from_binary(&hack).unwrap();
+        cpu.run(5_000_000);
+        // The square is drawn in the top left corner, 31 pixels wide
+        let screen = crate::emulator::hack::SCREEN;
+        assert_eq!((cpu.ram(screen), cpu.ram(screen + 1)), (-1, 0x7fff));
+    }
+}
@@ -0,0 +1,12 @@
+// Every dest ordering, trailing comments and symbols with `.$:_`
+   @32767        // the largest constant
+AMD=M+1
+	ADM = D | A ; JMP
+MD=D;JGT
+DM=-1//no space before the comment
+
+   (Main.loop$WHILE:0)
+@Main.loop$WHILE:0   
+0;JMP // loop
+@_tmp.x
+D=!M
\ No newline at end of file
@@ -0,0 +1,10 @@
+@32767
+AMD=M+1
+ADM=D|A;JMP
+MD=D;JGT
+DM=-1
+(Main.loop$WHILE:0)
+@Main.loop$WHILE:0
+0;JMP
+@_tmp.x
+D=!M
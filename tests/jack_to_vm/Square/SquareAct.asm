@@ -0,0 +1,33343 @@
+@256
+D=A
+@SP
+M=D
+@Bootstrap.Sys.init$ret.1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@5
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Sys.init
+0;JMP
+(Bootstrap.Sys.init$ret.1)
+(Main.main)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@Main.SquareGame.new$ret.2
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@5
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@SquareGame.new
+0;JMP
+(Main.SquareGame.new$ret.2)
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Main.SquareGame.run$ret.3
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@SquareGame.run
+0;JMP
+(Main.SquareGame.run$ret.3)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Main.SquareGame.dispose$ret.4
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@SquareGame.dispose
+0;JMP
+(Main.SquareGame.dispose$ret.4)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Main.more)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+@Main.more$IF_TRUE0
+D;JNE
+@Main.more$IF_FALSE0
+0;JMP
+(Main.more$IF_TRUE0)
+@15
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Main.String.new$ret.5
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.new
+0;JMP
+(Main.String.new$ret.5)
+@115
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Main.String.appendChar$ret.6
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.appendChar
+0;JMP
+(Main.String.appendChar$ret.6)
+@116
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Main.String.appendChar$ret.7
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.appendChar
+0;JMP
+(Main.String.appendChar$ret.7)
+@114
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Main.String.appendChar$ret.8
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.appendChar
+0;JMP
+(Main.String.appendChar$ret.8)
+@105
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Main.String.appendChar$ret.9
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.appendChar
+0;JMP
+(Main.String.appendChar$ret.9)
+@110
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Main.String.appendChar$ret.10
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.appendChar
+0;JMP
+(Main.String.appendChar$ret.10)
+@103
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Main.String.appendChar$ret.11
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.appendChar
+0;JMP
+(Main.String.appendChar$ret.11)
+@32
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Main.String.appendChar$ret.12
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.appendChar
+0;JMP
+(Main.String.appendChar$ret.12)
+@99
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Main.String.appendChar$ret.13
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.appendChar
+0;JMP
+(Main.String.appendChar$ret.13)
+@111
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Main.String.appendChar$ret.14
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.appendChar
+0;JMP
+(Main.String.appendChar$ret.14)
+@110
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Main.String.appendChar$ret.15
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.appendChar
+0;JMP
+(Main.String.appendChar$ret.15)
+@115
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Main.String.appendChar$ret.16
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.appendChar
+0;JMP
+(Main.String.appendChar$ret.16)
+@116
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Main.String.appendChar$ret.17
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.appendChar
+0;JMP
+(Main.String.appendChar$ret.17)
+@97
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Main.String.appendChar$ret.18
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.appendChar
+0;JMP
+(Main.String.appendChar$ret.18)
+@110
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Main.String.appendChar$ret.19
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.appendChar
+0;JMP
+(Main.String.appendChar$ret.19)
+@116
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Main.String.appendChar$ret.20
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.appendChar
+0;JMP
+(Main.String.appendChar$ret.20)
+@2
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@2
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Main.more$IF_END0
+0;JMP
+(Main.more$IF_FALSE0)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=-M
+@Main.Math.multiply$ret.21
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.multiply
+0;JMP
+(Main.Math.multiply$ret.21)
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=-M
+@Main.Math.divide$ret.22
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.divide
+0;JMP
+(Main.Math.divide$ret.22)
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D|M
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Main.more$IF_END0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Square.new)
+@3
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Square.Memory.alloc$ret.23
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Memory.alloc
+0;JMP
+(Square.Memory.alloc$ret.23)
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@2
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Square.Square.draw$ret.24
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Square.draw
+0;JMP
+(Square.Square.draw$ret.24)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Square.dispose)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Square.Memory.deAlloc$ret.25
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Memory.deAlloc
+0;JMP
+(Square.Memory.deAlloc$ret.25)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Square.draw)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=!M
+@Square.Screen.setColor$ret.26
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.setColor
+0;JMP
+(Square.Screen.setColor$ret.26)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@Square.Screen.drawRectangle$ret.27
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@9
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.drawRectangle
+0;JMP
+(Square.Screen.drawRectangle$ret.27)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Square.erase)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Square.Screen.setColor$ret.28
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.setColor
+0;JMP
+(Square.Screen.setColor$ret.28)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@Square.Screen.drawRectangle$ret.29
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@9
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.drawRectangle
+0;JMP
+(Square.Screen.drawRectangle$ret.29)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Square.incSize)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@254
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_30
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_30
+0;JMP
+(LT_true_30)
+@SP
+A=M-1
+M=-1
+(LT_end_30)
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@510
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_31
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_31
+0;JMP
+(LT_true_31)
+@SP
+A=M-1
+M=-1
+(LT_end_31)
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D&M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Square.incSize$IF_TRUE0
+D;JNE
+@Square.incSize$IF_FALSE0
+0;JMP
+(Square.incSize$IF_TRUE0)
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Square.Square.erase$ret.32
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Square.erase
+0;JMP
+(Square.Square.erase$ret.32)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@2
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Square.Square.draw$ret.33
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Square.draw
+0;JMP
+(Square.Square.draw$ret.33)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(Square.incSize$IF_FALSE0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Square.decSize)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_34
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_34
+0;JMP
+(GT_true_34)
+@SP
+A=M-1
+M=-1
+(GT_end_34)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Square.decSize$IF_TRUE0
+D;JNE
+@Square.decSize$IF_FALSE0
+0;JMP
+(Square.decSize$IF_TRUE0)
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Square.Square.erase$ret.35
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Square.erase
+0;JMP
+(Square.Square.erase$ret.35)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@2
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Square.Square.draw$ret.36
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Square.draw
+0;JMP
+(Square.Square.draw$ret.36)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(Square.decSize$IF_FALSE0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Square.moveUp)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_37
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_37
+0;JMP
+(GT_true_37)
+@SP
+A=M-1
+M=-1
+(GT_end_37)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Square.moveUp$IF_TRUE0
+D;JNE
+@Square.moveUp$IF_FALSE0
+0;JMP
+(Square.moveUp$IF_TRUE0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Square.Screen.setColor$ret.38
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.setColor
+0;JMP
+(Square.Screen.setColor$ret.38)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@Square.Screen.drawRectangle$ret.39
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@9
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.drawRectangle
+0;JMP
+(Square.Screen.drawRectangle$ret.39)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@1
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=!M
+@Square.Screen.setColor$ret.40
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.setColor
+0;JMP
+(Square.Screen.setColor$ret.40)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@Square.Screen.drawRectangle$ret.41
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@9
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.drawRectangle
+0;JMP
+(Square.Screen.drawRectangle$ret.41)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(Square.moveUp$IF_FALSE0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Square.moveDown)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@254
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_42
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_42
+0;JMP
+(LT_true_42)
+@SP
+A=M-1
+M=-1
+(LT_end_42)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Square.moveDown$IF_TRUE0
+D;JNE
+@Square.moveDown$IF_FALSE0
+0;JMP
+(Square.moveDown$IF_TRUE0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Square.Screen.setColor$ret.43
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.setColor
+0;JMP
+(Square.Screen.setColor$ret.43)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@Square.Screen.drawRectangle$ret.44
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@9
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.drawRectangle
+0;JMP
+(Square.Screen.drawRectangle$ret.44)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=!M
+@Square.Screen.setColor$ret.45
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.setColor
+0;JMP
+(Square.Screen.setColor$ret.45)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@Square.Screen.drawRectangle$ret.46
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@9
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.drawRectangle
+0;JMP
+(Square.Screen.drawRectangle$ret.46)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(Square.moveDown$IF_FALSE0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Square.moveLeft)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_47
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_47
+0;JMP
+(GT_true_47)
+@SP
+A=M-1
+M=-1
+(GT_end_47)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Square.moveLeft$IF_TRUE0
+D;JNE
+@Square.moveLeft$IF_FALSE0
+0;JMP
+(Square.moveLeft$IF_TRUE0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Square.Screen.setColor$ret.48
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.setColor
+0;JMP
+(Square.Screen.setColor$ret.48)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@Square.Screen.drawRectangle$ret.49
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@9
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.drawRectangle
+0;JMP
+(Square.Screen.drawRectangle$ret.49)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@0
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=!M
+@Square.Screen.setColor$ret.50
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.setColor
+0;JMP
+(Square.Screen.setColor$ret.50)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@Square.Screen.drawRectangle$ret.51
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@9
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.drawRectangle
+0;JMP
+(Square.Screen.drawRectangle$ret.51)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(Square.moveLeft$IF_FALSE0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Square.moveRight)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@510
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_52
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_52
+0;JMP
+(LT_true_52)
+@SP
+A=M-1
+M=-1
+(LT_end_52)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Square.moveRight$IF_TRUE0
+D;JNE
+@Square.moveRight$IF_FALSE0
+0;JMP
+(Square.moveRight$IF_TRUE0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Square.Screen.setColor$ret.53
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.setColor
+0;JMP
+(Square.Screen.setColor$ret.53)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@Square.Screen.drawRectangle$ret.54
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@9
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.drawRectangle
+0;JMP
+(Square.Screen.drawRectangle$ret.54)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=!M
+@Square.Screen.setColor$ret.55
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.setColor
+0;JMP
+(Square.Screen.setColor$ret.55)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@Square.Screen.drawRectangle$ret.56
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@9
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.drawRectangle
+0;JMP
+(Square.Screen.drawRectangle$ret.56)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(Square.moveRight$IF_FALSE0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(SquareGame.new)
+@2
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SquareGame.Memory.alloc$ret.57
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Memory.alloc
+0;JMP
+(SquareGame.Memory.alloc$ret.57)
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@30
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SquareGame.Square.new$ret.58
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@8
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Square.new
+0;JMP
+(SquareGame.Square.new$ret.58)
+@0
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(SquareGame.dispose)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SquareGame.Square.dispose$ret.59
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Square.dispose
+0;JMP
+(SquareGame.Square.dispose$ret.59)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SquareGame.Memory.deAlloc$ret.60
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Memory.deAlloc
+0;JMP
+(SquareGame.Memory.deAlloc$ret.60)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(SquareGame.moveSquare)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_61
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_61
+0;JMP
+(EQ_true_61)
+@SP
+A=M-1
+M=-1
+(EQ_end_61)
+@SP
+AM=M-1
+D=M
+A=A-1
+@SquareGame.moveSquare$IF_TRUE0
+D;JNE
+@SquareGame.moveSquare$IF_FALSE0
+0;JMP
+(SquareGame.moveSquare$IF_TRUE0)
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SquareGame.Square.moveUp$ret.62
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Square.moveUp
+0;JMP
+(SquareGame.Square.moveUp$ret.62)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(SquareGame.moveSquare$IF_FALSE0)
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_63
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_63
+0;JMP
+(EQ_true_63)
+@SP
+A=M-1
+M=-1
+(EQ_end_63)
+@SP
+AM=M-1
+D=M
+A=A-1
+@SquareGame.moveSquare$IF_TRUE1
+D;JNE
+@SquareGame.moveSquare$IF_FALSE1
+0;JMP
+(SquareGame.moveSquare$IF_TRUE1)
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SquareGame.Square.moveDown$ret.64
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Square.moveDown
+0;JMP
+(SquareGame.Square.moveDown$ret.64)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(SquareGame.moveSquare$IF_FALSE1)
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_65
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_65
+0;JMP
+(EQ_true_65)
+@SP
+A=M-1
+M=-1
+(EQ_end_65)
+@SP
+AM=M-1
+D=M
+A=A-1
+@SquareGame.moveSquare$IF_TRUE2
+D;JNE
+@SquareGame.moveSquare$IF_FALSE2
+0;JMP
+(SquareGame.moveSquare$IF_TRUE2)
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SquareGame.Square.moveLeft$ret.66
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Square.moveLeft
+0;JMP
+(SquareGame.Square.moveLeft$ret.66)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(SquareGame.moveSquare$IF_FALSE2)
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@4
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_67
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_67
+0;JMP
+(EQ_true_67)
+@SP
+A=M-1
+M=-1
+(EQ_end_67)
+@SP
+AM=M-1
+D=M
+A=A-1
+@SquareGame.moveSquare$IF_TRUE3
+D;JNE
+@SquareGame.moveSquare$IF_FALSE3
+0;JMP
+(SquareGame.moveSquare$IF_TRUE3)
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SquareGame.Square.moveRight$ret.68
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Square.moveRight
+0;JMP
+(SquareGame.Square.moveRight$ret.68)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(SquareGame.moveSquare$IF_FALSE3)
+@5
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SquareGame.Sys.wait$ret.69
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Sys.wait
+0;JMP
+(SquareGame.Sys.wait$ret.69)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(SquareGame.run)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(SquareGame.run$WHILE_EXP0)
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=!M
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@SquareGame.run$WHILE_END0
+D;JNE
+(SquareGame.run$WHILE_EXP1)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_70
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_70
+0;JMP
+(EQ_true_70)
+@SP
+A=M-1
+M=-1
+(EQ_end_70)
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@SquareGame.run$WHILE_END1
+D;JNE
+@SquareGame.Keyboard.keyPressed$ret.71
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@5
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Keyboard.keyPressed
+0;JMP
+(SquareGame.Keyboard.keyPressed$ret.71)
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SquareGame.SquareGame.moveSquare$ret.72
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@SquareGame.moveSquare
+0;JMP
+(SquareGame.SquareGame.moveSquare$ret.72)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SquareGame.run$WHILE_EXP1
+0;JMP
+(SquareGame.run$WHILE_END1)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@81
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_73
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_73
+0;JMP
+(EQ_true_73)
+@SP
+A=M-1
+M=-1
+(EQ_end_73)
+@SP
+AM=M-1
+D=M
+A=A-1
+@SquareGame.run$IF_TRUE0
+D;JNE
+@SquareGame.run$IF_FALSE0
+0;JMP
+(SquareGame.run$IF_TRUE0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=!M
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(SquareGame.run$IF_FALSE0)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@90
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_74
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_74
+0;JMP
+(EQ_true_74)
+@SP
+A=M-1
+M=-1
+(EQ_end_74)
+@SP
+AM=M-1
+D=M
+A=A-1
+@SquareGame.run$IF_TRUE1
+D;JNE
+@SquareGame.run$IF_FALSE1
+0;JMP
+(SquareGame.run$IF_TRUE1)
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SquareGame.Square.decSize$ret.75
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Square.decSize
+0;JMP
+(SquareGame.Square.decSize$ret.75)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(SquareGame.run$IF_FALSE1)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@88
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_76
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_76
+0;JMP
+(EQ_true_76)
+@SP
+A=M-1
+M=-1
+(EQ_end_76)
+@SP
+AM=M-1
+D=M
+A=A-1
+@SquareGame.run$IF_TRUE2
+D;JNE
+@SquareGame.run$IF_FALSE2
+0;JMP
+(SquareGame.run$IF_TRUE2)
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SquareGame.Square.incSize$ret.77
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Square.incSize
+0;JMP
+(SquareGame.Square.incSize$ret.77)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(SquareGame.run$IF_FALSE2)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@131
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_78
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_78
+0;JMP
+(EQ_true_78)
+@SP
+A=M-1
+M=-1
+(EQ_end_78)
+@SP
+AM=M-1
+D=M
+A=A-1
+@SquareGame.run$IF_TRUE3
+D;JNE
+@SquareGame.run$IF_FALSE3
+0;JMP
+(SquareGame.run$IF_TRUE3)
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(SquareGame.run$IF_FALSE3)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@133
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_79
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_79
+0;JMP
+(EQ_true_79)
+@SP
+A=M-1
+M=-1
+(EQ_end_79)
+@SP
+AM=M-1
+D=M
+A=A-1
+@SquareGame.run$IF_TRUE4
+D;JNE
+@SquareGame.run$IF_FALSE4
+0;JMP
+(SquareGame.run$IF_TRUE4)
+@2
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(SquareGame.run$IF_FALSE4)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@130
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_80
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_80
+0;JMP
+(EQ_true_80)
+@SP
+A=M-1
+M=-1
+(EQ_end_80)
+@SP
+AM=M-1
+D=M
+A=A-1
+@SquareGame.run$IF_TRUE5
+D;JNE
+@SquareGame.run$IF_FALSE5
+0;JMP
+(SquareGame.run$IF_TRUE5)
+@3
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(SquareGame.run$IF_FALSE5)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@132
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_81
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_81
+0;JMP
+(EQ_true_81)
+@SP
+A=M-1
+M=-1
+(EQ_end_81)
+@SP
+AM=M-1
+D=M
+A=A-1
+@SquareGame.run$IF_TRUE6
+D;JNE
+@SquareGame.run$IF_FALSE6
+0;JMP
+(SquareGame.run$IF_TRUE6)
+@4
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(SquareGame.run$IF_FALSE6)
+(SquareGame.run$WHILE_EXP2)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_82
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_82
+0;JMP
+(EQ_true_82)
+@SP
+A=M-1
+M=-1
+(EQ_end_82)
+@SP
+A=M-1
+M=!M
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@SquareGame.run$WHILE_END2
+D;JNE
+@SquareGame.Keyboard.keyPressed$ret.83
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@5
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Keyboard.keyPressed
+0;JMP
+(SquareGame.Keyboard.keyPressed$ret.83)
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SquareGame.SquareGame.moveSquare$ret.84
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@SquareGame.moveSquare
+0;JMP
+(SquareGame.SquareGame.moveSquare$ret.84)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SquareGame.run$WHILE_EXP2
+0;JMP
+(SquareGame.run$WHILE_END2)
+@SquareGame.run$WHILE_EXP0
+0;JMP
+(SquareGame.run$WHILE_END0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Array.new)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Array.Memory.alloc$ret.85
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Memory.alloc
+0;JMP
+(Array.Memory.alloc$ret.85)
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Array.dispose)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Array.Memory.deAlloc$ret.86
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Memory.deAlloc
+0;JMP
+(Array.Memory.deAlloc$ret.86)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Keyboard.init)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Keyboard.keyPressed)
+@24576
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Keyboard.Memory.peek$ret.87
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Memory.peek
+0;JMP
+(Keyboard.Memory.peek$ret.87)
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Keyboard.readChar)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+(Keyboard.readChar$WHILE_EXP0)
+@Keyboard.Keyboard.keyPressed$ret.88
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@5
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Keyboard.keyPressed
+0;JMP
+(Keyboard.Keyboard.keyPressed$ret.88)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_89
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_89
+0;JMP
+(EQ_true_89)
+@SP
+A=M-1
+M=-1
+(EQ_end_89)
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Keyboard.readChar$WHILE_END0
+D;JNE
+@Keyboard.readChar$WHILE_EXP0
+0;JMP
+(Keyboard.readChar$WHILE_END0)
+@Keyboard.Keyboard.keyPressed$ret.90
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@5
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Keyboard.keyPressed
+0;JMP
+(Keyboard.Keyboard.keyPressed$ret.90)
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Keyboard.readChar$WHILE_EXP1)
+@Keyboard.Keyboard.keyPressed$ret.91
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@5
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Keyboard.keyPressed
+0;JMP
+(Keyboard.Keyboard.keyPressed$ret.91)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_92
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_92
+0;JMP
+(EQ_true_92)
+@SP
+A=M-1
+M=-1
+(EQ_end_92)
+@SP
+A=M-1
+M=!M
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Keyboard.readChar$WHILE_END1
+D;JNE
+@Keyboard.readChar$WHILE_EXP1
+0;JMP
+(Keyboard.readChar$WHILE_END1)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Keyboard.Output.printChar$ret.93
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.printChar
+0;JMP
+(Keyboard.Output.printChar$ret.93)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Keyboard.readLine)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Keyboard.Output.printString$ret.94
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.printString
+0;JMP
+(Keyboard.Output.printString$ret.94)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@64
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Keyboard.String.new$ret.95
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.new
+0;JMP
+(Keyboard.String.new$ret.95)
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Keyboard.Keyboard.readChar$ret.96
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@5
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Keyboard.readChar
+0;JMP
+(Keyboard.Keyboard.readChar$ret.96)
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Keyboard.readLine$WHILE_EXP0)
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@128
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_97
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_97
+0;JMP
+(EQ_true_97)
+@SP
+A=M-1
+M=-1
+(EQ_end_97)
+@SP
+A=M-1
+M=!M
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Keyboard.readLine$WHILE_END0
+D;JNE
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@129
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_98
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_98
+0;JMP
+(EQ_true_98)
+@SP
+A=M-1
+M=-1
+(EQ_end_98)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Keyboard.readLine$IF_TRUE0
+D;JNE
+@Keyboard.readLine$IF_FALSE0
+0;JMP
+(Keyboard.readLine$IF_TRUE0)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Keyboard.String.length$ret.99
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.length
+0;JMP
+(Keyboard.String.length$ret.99)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_100
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_100
+0;JMP
+(GT_true_100)
+@SP
+A=M-1
+M=-1
+(GT_end_100)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Keyboard.readLine$IF_TRUE1
+D;JNE
+@Keyboard.readLine$IF_FALSE1
+0;JMP
+(Keyboard.readLine$IF_TRUE1)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Keyboard.String.eraseLastChar$ret.101
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.eraseLastChar
+0;JMP
+(Keyboard.String.eraseLastChar$ret.101)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(Keyboard.readLine$IF_FALSE1)
+@Keyboard.readLine$IF_END0
+0;JMP
+(Keyboard.readLine$IF_FALSE0)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Keyboard.String.length$ret.102
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.length
+0;JMP
+(Keyboard.String.length$ret.102)
+@64
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_103
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_103
+0;JMP
+(LT_true_103)
+@SP
+A=M-1
+M=-1
+(LT_end_103)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Keyboard.readLine$IF_TRUE2
+D;JNE
+@Keyboard.readLine$IF_FALSE2
+0;JMP
+(Keyboard.readLine$IF_TRUE2)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Keyboard.String.appendChar$ret.104
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.appendChar
+0;JMP
+(Keyboard.String.appendChar$ret.104)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(Keyboard.readLine$IF_FALSE2)
+(Keyboard.readLine$IF_END0)
+@Keyboard.Keyboard.readChar$ret.105
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@5
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Keyboard.readChar
+0;JMP
+(Keyboard.Keyboard.readChar$ret.105)
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Keyboard.readLine$WHILE_EXP0
+0;JMP
+(Keyboard.readLine$WHILE_END0)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Keyboard.readInt)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Keyboard.Keyboard.readLine$ret.106
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Keyboard.readLine
+0;JMP
+(Keyboard.Keyboard.readLine$ret.106)
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Keyboard.String.intValue$ret.107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.intValue
+0;JMP
+(Keyboard.String.intValue$ret.107)
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Keyboard.String.dispose$ret.108
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.dispose
+0;JMP
+(Keyboard.String.dispose$ret.108)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Math.init)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@16
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Math.Array.new$ret.109
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Array.new
+0;JMP
+(Math.Array.new$ret.109)
+@SP
+AM=M-1
+D=M
+@Math.0
+M=D
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Math.init$WHILE_EXP0)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@16
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_110
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_110
+0;JMP
+(LT_true_110)
+@SP
+A=M-1
+M=-1
+(LT_end_110)
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Math.init$WHILE_END0
+D;JNE
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Math.0
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Math.init$WHILE_EXP0
+0;JMP
+(Math.init$WHILE_END0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Math.bit)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Math.0
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D&M
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_111
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_111
+0;JMP
+(EQ_true_111)
+@SP
+A=M-1
+M=-1
+(EQ_end_111)
+@SP
+A=M-1
+M=!M
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Math.abs)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_112
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_112
+0;JMP
+(LT_true_112)
+@SP
+A=M-1
+M=-1
+(LT_end_112)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Math.abs$IF_TRUE0
+D;JNE
+@Math.abs$IF_FALSE0
+0;JMP
+(Math.abs$IF_TRUE0)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=-M
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Math.abs$IF_FALSE0)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Math.multiply)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Math.multiply$WHILE_EXP0)
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@16
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_113
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_113
+0;JMP
+(LT_true_113)
+@SP
+A=M-1
+M=-1
+(LT_end_113)
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Math.multiply$WHILE_END0
+D;JNE
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Math.Math.bit$ret.114
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.bit
+0;JMP
+(Math.Math.bit$ret.114)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Math.multiply$IF_TRUE0
+D;JNE
+@Math.multiply$IF_FALSE0
+0;JMP
+(Math.multiply$IF_TRUE0)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Math.multiply$IF_FALSE0)
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@2
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Math.multiply$WHILE_EXP0
+0;JMP
+(Math.multiply$WHILE_END0)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Math.divide)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_115
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_115
+0;JMP
+(EQ_true_115)
+@SP
+A=M-1
+M=-1
+(EQ_end_115)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Math.divide$IF_TRUE0
+D;JNE
+@Math.divide$IF_FALSE0
+0;JMP
+(Math.divide$IF_TRUE0)
+@3
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Math.Sys.error$ret.116
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Sys.error
+0;JMP
+(Math.Sys.error$ret.116)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(Math.divide$IF_FALSE0)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Math.Math.abs$ret.117
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.abs
+0;JMP
+(Math.Math.abs$ret.117)
+@SP
+A=M-1
+M=-M
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Math.Math.abs$ret.118
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.abs
+0;JMP
+(Math.Math.abs$ret.118)
+@SP
+A=M-1
+M=-M
+@Math.Math.divideNegative$ret.119
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.divideNegative
+0;JMP
+(Math.Math.divideNegative$ret.119)
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_120
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_120
+0;JMP
+(LT_true_120)
+@SP
+A=M-1
+M=-1
+(LT_end_120)
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_121
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_121
+0;JMP
+(LT_true_121)
+@SP
+A=M-1
+M=-1
+(LT_end_121)
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_122
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_122
+0;JMP
+(EQ_true_122)
+@SP
+A=M-1
+M=-1
+(EQ_end_122)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Math.divide$IF_TRUE1
+D;JNE
+@Math.divide$IF_FALSE1
+0;JMP
+(Math.divide$IF_TRUE1)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Math.divide$IF_FALSE1)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=-M
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Math.divideNegative)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_123
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_123
+0;JMP
+(LT_true_123)
+@SP
+A=M-1
+M=-1
+(LT_end_123)
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_124
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_124
+0;JMP
+(LT_true_124)
+@SP
+A=M-1
+M=-1
+(LT_end_124)
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D|M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Math.divideNegative$IF_TRUE0
+D;JNE
+@Math.divideNegative$IF_FALSE0
+0;JMP
+(Math.divideNegative$IF_TRUE0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Math.divideNegative$IF_FALSE0)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@Math.Math.divideNegative$ret.125
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.divideNegative
+0;JMP
+(Math.Math.divideNegative$ret.125)
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Math.Math.multiply$ret.126
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.multiply
+0;JMP
+(Math.Math.multiply$ret.126)
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_127
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_127
+0;JMP
+(GT_true_127)
+@SP
+A=M-1
+M=-1
+(GT_end_127)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Math.divideNegative$IF_TRUE1
+D;JNE
+@Math.divideNegative$IF_FALSE1
+0;JMP
+(Math.divideNegative$IF_TRUE1)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Math.divideNegative$IF_FALSE1)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Math.sqrt)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_128
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_128
+0;JMP
+(LT_true_128)
+@SP
+A=M-1
+M=-1
+(LT_end_128)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Math.sqrt$IF_TRUE0
+D;JNE
+@Math.sqrt$IF_FALSE0
+0;JMP
+(Math.sqrt$IF_TRUE0)
+@4
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Math.Sys.error$ret.129
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Sys.error
+0;JMP
+(Math.Sys.error$ret.129)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(Math.sqrt$IF_FALSE0)
+@7
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Math.sqrt$WHILE_EXP0)
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_130
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_130
+0;JMP
+(LT_true_130)
+@SP
+A=M-1
+M=-1
+(LT_end_130)
+@SP
+A=M-1
+M=!M
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Math.sqrt$WHILE_END0
+D;JNE
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Math.0
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@2
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Math.Math.multiply$ret.131
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.multiply
+0;JMP
+(Math.Math.multiply$ret.131)
+@3
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@3
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_132
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_132
+0;JMP
+(GT_true_132)
+@SP
+A=M-1
+M=-1
+(GT_end_132)
+@SP
+A=M-1
+M=!M
+@3
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_133
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_133
+0;JMP
+(GT_true_133)
+@SP
+A=M-1
+M=-1
+(GT_end_133)
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D&M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Math.sqrt$IF_TRUE1
+D;JNE
+@Math.sqrt$IF_FALSE1
+0;JMP
+(Math.sqrt$IF_TRUE1)
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Math.sqrt$IF_FALSE1)
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Math.sqrt$WHILE_EXP0
+0;JMP
+(Math.sqrt$WHILE_END0)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Math.max)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_134
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_134
+0;JMP
+(GT_true_134)
+@SP
+A=M-1
+M=-1
+(GT_end_134)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Math.max$IF_TRUE0
+D;JNE
+@Math.max$IF_FALSE0
+0;JMP
+(Math.max$IF_TRUE0)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Math.max$IF_FALSE0)
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Math.min)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_135
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_135
+0;JMP
+(LT_true_135)
+@SP
+A=M-1
+M=-1
+(LT_end_135)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Math.min$IF_TRUE0
+D;JNE
+@Math.min$IF_FALSE0
+0;JMP
+(Math.min$IF_TRUE0)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Math.min$IF_FALSE0)
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Memory.init)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@Memory.0
+M=D
+@2048
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@Memory.1
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Memory.1
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@14334
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Memory.1
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Memory.peek)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Memory.0
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Memory.poke)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Memory.0
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Memory.alloc)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_136
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_136
+0;JMP
+(LT_true_136)
+@SP
+A=M-1
+M=-1
+(LT_end_136)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Memory.alloc$IF_TRUE0
+D;JNE
+@Memory.alloc$IF_FALSE0
+0;JMP
+(Memory.alloc$IF_TRUE0)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_137
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_137
+0;JMP
+(LT_true_137)
+@SP
+A=M-1
+M=-1
+(LT_end_137)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Memory.alloc$IF_TRUE1
+D;JNE
+@Memory.alloc$IF_FALSE1
+0;JMP
+(Memory.alloc$IF_TRUE1)
+@5
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Memory.Sys.error$ret.138
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Sys.error
+0;JMP
+(Memory.Sys.error$ret.138)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(Memory.alloc$IF_FALSE1)
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@ARG
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Memory.alloc$IF_FALSE0)
+@Memory.1
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Memory.alloc$WHILE_EXP0)
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_139
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_139
+0;JMP
+(EQ_true_139)
+@SP
+A=M-1
+M=-1
+(EQ_end_139)
+@SP
+A=M-1
+M=!M
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Memory.alloc$WHILE_END0
+D;JNE
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_140
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_140
+0;JMP
+(GT_true_140)
+@SP
+A=M-1
+M=-1
+(GT_end_140)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Memory.alloc$IF_TRUE2
+D;JNE
+@Memory.alloc$IF_FALSE2
+0;JMP
+(Memory.alloc$IF_TRUE2)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@2
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=-M
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Memory.alloc$IF_FALSE2)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_141
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_141
+0;JMP
+(EQ_true_141)
+@SP
+A=M-1
+M=-1
+(EQ_end_141)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Memory.alloc$IF_TRUE3
+D;JNE
+@Memory.alloc$IF_FALSE3
+0;JMP
+(Memory.alloc$IF_TRUE3)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_142
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_142
+0;JMP
+(EQ_true_142)
+@SP
+A=M-1
+M=-1
+(EQ_end_142)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Memory.alloc$IF_TRUE4
+D;JNE
+@Memory.alloc$IF_FALSE4
+0;JMP
+(Memory.alloc$IF_TRUE4)
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@Memory.1
+M=D
+@Memory.alloc$IF_END4
+0;JMP
+(Memory.alloc$IF_FALSE4)
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Memory.alloc$IF_END4)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Memory.alloc$IF_FALSE3)
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Memory.alloc$WHILE_EXP0
+0;JMP
+(Memory.alloc$WHILE_END0)
+@6
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Memory.Sys.error$ret.143
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Sys.error
+0;JMP
+(Memory.Sys.error$ret.143)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Memory.deAlloc)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=-M
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@Memory.1
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@Memory.1
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Output.init)
+@16384
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@Output.1
+M=D
+@6
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.String.new$ret.144
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.new
+0;JMP
+(Output.String.new$ret.144)
+@SP
+AM=M-1
+D=M
+@Output.5
+M=D
+@Output.Output.initMap$ret.145
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@5
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.initMap
+0;JMP
+(Output.Output.initMap$ret.145)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Output.initMap)
+@96
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Array.new$ret.146
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Array.new
+0;JMP
+(Output.Array.new$ret.146)
+@SP
+AM=M-1
+D=M
+@Output.0
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7692
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7710
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3072
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.147
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.147)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@13878
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@20
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@4608
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@16146
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@4626
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@4671
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@18
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.148
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.148)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@7692
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@819
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12318
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7731
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13091
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3096
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13062
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@49
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.149
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.149)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@7692
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3102
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6966
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6939
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@54
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.150
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.150)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@3096
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1542
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1542
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3078
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@24
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3078
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6168
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6168
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3096
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.151
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.151)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13056
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@16158
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13086
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3072
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@16140
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.152
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.152)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3072
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1548
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@16128
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.153
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.153)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3072
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12320
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3096
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@774
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.154
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.154)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@7692
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7731
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3596
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3087
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@63
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.155
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.155)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@13086
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6192
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1548
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13059
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@63
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13086
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12336
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12316
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13104
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@30
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.156
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.156)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@6160
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6684
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@16153
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6168
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@60
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@831
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7939
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12336
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13104
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@30
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.157
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.157)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@1564
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@771
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13087
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@30
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12607
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12336
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3096
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.158
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.158)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@13086
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13086
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@30
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13086
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12350
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6192
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@14
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.159
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.159)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.160
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.160)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3096
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@774
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3078
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@24
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@16128
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@63
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.161
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.161)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1539
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6156
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1548
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13086
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6195
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3072
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.162
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.162)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@13086
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@15155
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@15163
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@795
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@30
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7692
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13119
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@51
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.163
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.163)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@13087
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13087
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@31
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13852
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@803
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@771
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13859
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@28
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.164
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.164)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@6927
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6963
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@15
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13119
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2851
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2831
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13091
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@63
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.165
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.165)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@13119
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2851
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2831
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@771
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13852
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@803
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13115
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13875
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@44
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.166
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.166)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13119
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@51
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3102
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@30
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.167
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.167)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@6204
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6168
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6168
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6939
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@14
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6963
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6927
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@51
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.168
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.168)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@771
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@771
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@771
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13091
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@63
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13089
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@16191
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@51
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.169
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.169)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@14135
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@15167
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13115
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@51
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13086
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@30
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.170
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.170)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@13087
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@799
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@771
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13086
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@15167
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12318
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.171
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.171)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@13087
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6943
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@51
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13086
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1587
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12316
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@30
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.172
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.172)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@16191
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3117
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@30
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@30
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.173
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.173)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7731
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3102
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@16179
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@16191
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@18
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.174
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.174)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7710
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7692
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13086
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@51
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3102
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@30
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.175
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.175)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@13119
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6193
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1548
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13091
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@63
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1566
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1542
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1542
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1542
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@30
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.176
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.176)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@769
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3078
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12312
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@32
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6174
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6168
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6168
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6168
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@30
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.177
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.177)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@7176
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@54
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@16128
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.178
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.178)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@3078
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@24
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3584
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7704
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6939
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@54
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.179
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.179)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@771
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3843
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13083
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@30
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7680
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@819
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13059
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@30
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.180
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.180)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@12336
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@15408
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13110
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@30
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7680
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@16179
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13059
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@30
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.181
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.181)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@13852
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1574
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1551
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1542
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@15
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13086
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12350
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7731
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.182
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.182)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@771
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6915
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13111
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@51
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3584
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@30
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.183
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.183)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@12336
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@14336
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12336
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12336
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7731
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@771
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13059
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3867
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6927
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@51
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.184
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.184)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@3086
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@30
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7424
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@11071
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@11051
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@43
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.185
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.185)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7424
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@51
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7680
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@30
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.186
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.186)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7680
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7987
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@771
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7680
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@15923
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12336
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.187
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.187)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7424
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13111
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@771
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7680
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1587
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13080
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@30
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.188
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.188)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@1540
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3846
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1542
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13830
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@28
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6912
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6939
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6939
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@54
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.189
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.189)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13056
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7731
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13056
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@16191
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@18
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.190
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.190)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13056
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3102
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7692
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@51
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13056
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13107
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12350
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3864
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.191
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.191)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@16128
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3099
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@13062
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@63
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3128
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3079
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@56
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.192
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.192)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@12
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3079
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3128
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3084
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.193
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.193)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@11558
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@25
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@16191
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@16191
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@16191
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@16191
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@63
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.create$ret.194
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@15
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.create
+0;JMP
+(Output.Output.create$ret.194)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Output.create)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@4
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.unpack$ret.195
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@10
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.unpack
+0;JMP
+(Output.Output.unpack$ret.195)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@5
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@6
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@7
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@8
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@9
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.unpack$ret.196
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@10
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.unpack
+0;JMP
+(Output.Output.unpack$ret.196)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Output.unpack)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@11
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Array.new$ret.197
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Array.new
+0;JMP
+(Output.Array.new$ret.197)
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Output.4
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.0
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Output.4
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@Output.4
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@255
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D&M
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.highByte$ret.198
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.highByte
+0;JMP
+(Output.Output.highByte$ret.198)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@2
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@255
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D&M
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@3
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.highByte$ret.199
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.highByte
+0;JMP
+(Output.Output.highByte$ret.199)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@4
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@2
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@255
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D&M
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@5
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@2
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.highByte$ret.200
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.highByte
+0;JMP
+(Output.Output.highByte$ret.200)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@6
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@3
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@255
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D&M
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@7
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@3
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.highByte$ret.201
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.highByte
+0;JMP
+(Output.Output.highByte$ret.201)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@8
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@4
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@255
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D&M
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@9
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@4
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.highByte$ret.202
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.highByte
+0;JMP
+(Output.Output.highByte$ret.202)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@10
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Output.highByte)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@256
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Output.highByte$WHILE_EXP0)
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@128
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_203
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_203
+0;JMP
+(LT_true_203)
+@SP
+A=M-1
+M=-1
+(LT_end_203)
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Output.highByte$WHILE_END0
+D;JNE
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D&M
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_204
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_204
+0;JMP
+(EQ_true_204)
+@SP
+A=M-1
+M=-1
+(EQ_end_204)
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Output.highByte$IF_TRUE0
+D;JNE
+@Output.highByte$IF_FALSE0
+0;JMP
+(Output.highByte$IF_TRUE0)
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Output.highByte$IF_FALSE0)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@2
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Output.highByte$WHILE_EXP0
+0;JMP
+(Output.highByte$WHILE_END0)
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Output.getMap)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@32
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_205
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_205
+0;JMP
+(LT_true_205)
+@SP
+A=M-1
+M=-1
+(LT_end_205)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@126
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_206
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_206
+0;JMP
+(GT_true_206)
+@SP
+A=M-1
+M=-1
+(GT_end_206)
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D|M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Output.getMap$IF_TRUE0
+D;JNE
+@Output.getMap$IF_FALSE0
+0;JMP
+(Output.getMap$IF_TRUE0)
+@127
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@ARG
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Output.getMap$IF_FALSE0)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@32
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@Output.0
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Output.drawChar)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.getMap$ret.207
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.getMap
+0;JMP
+(Output.Output.getMap$ret.207)
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Output.2
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@352
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Math.multiply$ret.208
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.multiply
+0;JMP
+(Output.Math.multiply$ret.208)
+@Output.3
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Math.divide$ret.209
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.divide
+0;JMP
+(Output.Math.divide$ret.209)
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Output.drawChar$WHILE_EXP0)
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@11
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_210
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_210
+0;JMP
+(LT_true_210)
+@SP
+A=M-1
+M=-1
+(LT_end_210)
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Output.drawChar$WHILE_END0
+D;JNE
+@Output.3
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D&M
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_211
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_211
+0;JMP
+(EQ_true_211)
+@SP
+A=M-1
+M=-1
+(EQ_end_211)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Output.drawChar$IF_TRUE0
+D;JNE
+@Output.drawChar$IF_FALSE0
+0;JMP
+(Output.drawChar$IF_TRUE0)
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.1
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.1
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@256
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=-M
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D&M
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D|M
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Output.drawChar$IF_END0
+0;JMP
+(Output.drawChar$IF_FALSE0)
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.1
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.1
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@255
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D&M
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@256
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Math.multiply$ret.212
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.multiply
+0;JMP
+(Output.Math.multiply$ret.212)
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D|M
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Output.drawChar$IF_END0)
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@32
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@2
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Output.drawChar$WHILE_EXP0
+0;JMP
+(Output.drawChar$WHILE_END0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Output.moveCursor)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_213
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_213
+0;JMP
+(LT_true_213)
+@SP
+A=M-1
+M=-1
+(LT_end_213)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@22
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_214
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_214
+0;JMP
+(GT_true_214)
+@SP
+A=M-1
+M=-1
+(GT_end_214)
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D|M
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_215
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_215
+0;JMP
+(LT_true_215)
+@SP
+A=M-1
+M=-1
+(LT_end_215)
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D|M
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@63
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_216
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_216
+0;JMP
+(GT_true_216)
+@SP
+A=M-1
+M=-1
+(GT_end_216)
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D|M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Output.moveCursor$IF_TRUE0
+D;JNE
+@Output.moveCursor$IF_FALSE0
+0;JMP
+(Output.moveCursor$IF_TRUE0)
+@20
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Sys.error$ret.217
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Sys.error
+0;JMP
+(Output.Sys.error$ret.217)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(Output.moveCursor$IF_FALSE0)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@Output.2
+M=D
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@Output.3
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Output.printChar)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@128
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_218
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_218
+0;JMP
+(EQ_true_218)
+@SP
+A=M-1
+M=-1
+(EQ_end_218)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Output.printChar$IF_TRUE0
+D;JNE
+@Output.printChar$IF_FALSE0
+0;JMP
+(Output.printChar$IF_TRUE0)
+@Output.Output.println$ret.219
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@5
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.println
+0;JMP
+(Output.Output.println$ret.219)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Output.printChar$IF_FALSE0)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@129
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_220
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_220
+0;JMP
+(EQ_true_220)
+@SP
+A=M-1
+M=-1
+(EQ_end_220)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Output.printChar$IF_TRUE1
+D;JNE
+@Output.printChar$IF_FALSE1
+0;JMP
+(Output.printChar$IF_TRUE1)
+@Output.Output.backSpace$ret.221
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@5
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.backSpace
+0;JMP
+(Output.Output.backSpace$ret.221)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Output.printChar$IF_FALSE1)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.drawChar$ret.222
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.drawChar
+0;JMP
+(Output.Output.drawChar$ret.222)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@Output.3
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@Output.3
+M=D
+@Output.3
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@64
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_223
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_223
+0;JMP
+(EQ_true_223)
+@SP
+A=M-1
+M=-1
+(EQ_end_223)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Output.printChar$IF_TRUE2
+D;JNE
+@Output.printChar$IF_FALSE2
+0;JMP
+(Output.printChar$IF_TRUE2)
+@Output.Output.println$ret.224
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@5
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.println
+0;JMP
+(Output.Output.println$ret.224)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(Output.printChar$IF_FALSE2)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Output.printString)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.String.length$ret.225
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.length
+0;JMP
+(Output.String.length$ret.225)
+@2
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Output.printString$WHILE_EXP0)
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_226
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_226
+0;JMP
+(LT_true_226)
+@SP
+A=M-1
+M=-1
+(LT_end_226)
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Output.printString$WHILE_END0
+D;JNE
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.String.charAt$ret.227
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.charAt
+0;JMP
+(Output.String.charAt$ret.227)
+@Output.Output.printChar$ret.228
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.printChar
+0;JMP
+(Output.Output.printChar$ret.228)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Output.printString$WHILE_EXP0
+0;JMP
+(Output.printString$WHILE_END0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Output.printInt)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@Output.5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.String.setInt$ret.229
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.setInt
+0;JMP
+(Output.String.setInt$ret.229)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.printString$ret.230
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.printString
+0;JMP
+(Output.Output.printString$ret.230)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Output.println)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@Output.3
+M=D
+@Output.2
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@Output.2
+M=D
+@Output.2
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@23
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_231
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_231
+0;JMP
+(EQ_true_231)
+@SP
+A=M-1
+M=-1
+(EQ_end_231)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Output.println$IF_TRUE0
+D;JNE
+@Output.println$IF_FALSE0
+0;JMP
+(Output.println$IF_TRUE0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@Output.2
+M=D
+(Output.println$IF_FALSE0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Output.backSpace)
+@Output.3
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_232
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_232
+0;JMP
+(EQ_true_232)
+@SP
+A=M-1
+M=-1
+(EQ_end_232)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Output.backSpace$IF_TRUE0
+D;JNE
+@Output.backSpace$IF_FALSE0
+0;JMP
+(Output.backSpace$IF_TRUE0)
+@Output.2
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_233
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_233
+0;JMP
+(GT_true_233)
+@SP
+A=M-1
+M=-1
+(GT_end_233)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Output.backSpace$IF_TRUE1
+D;JNE
+@Output.backSpace$IF_FALSE1
+0;JMP
+(Output.backSpace$IF_TRUE1)
+@Output.2
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@SP
+AM=M-1
+D=M
+@Output.2
+M=D
+@63
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@Output.3
+M=D
+(Output.backSpace$IF_FALSE1)
+@Output.backSpace$IF_END0
+0;JMP
+(Output.backSpace$IF_FALSE0)
+@Output.3
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@SP
+AM=M-1
+D=M
+@Output.3
+M=D
+(Output.backSpace$IF_END0)
+@32
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Output.Output.drawChar$ret.234
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.drawChar
+0;JMP
+(Output.Output.drawChar$ret.234)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Screen.init)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@16384
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@Screen.0
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+@Screen.2
+M=D
+@16
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Screen.Array.new$ret.235
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Array.new
+0;JMP
+(Screen.Array.new$ret.235)
+@SP
+AM=M-1
+D=M
+@Screen.1
+M=D
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Screen.init$WHILE_EXP0)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@16
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_236
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_236
+0;JMP
+(LT_true_236)
+@SP
+A=M-1
+M=-1
+(LT_end_236)
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Screen.init$WHILE_END0
+D;JNE
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Screen.1
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Screen.init$WHILE_EXP0
+0;JMP
+(Screen.init$WHILE_END0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Screen.clearScreen)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+(Screen.clearScreen$WHILE_EXP0)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@8192
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_237
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_237
+0;JMP
+(LT_true_237)
+@SP
+A=M-1
+M=-1
+(LT_end_237)
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Screen.clearScreen$WHILE_END0
+D;JNE
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Screen.0
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Screen.clearScreen$WHILE_EXP0
+0;JMP
+(Screen.clearScreen$WHILE_END0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Screen.setColor)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@Screen.2
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Screen.drawPixel)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_238
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_238
+0;JMP
+(LT_true_238)
+@SP
+A=M-1
+M=-1
+(LT_end_238)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@511
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_239
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_239
+0;JMP
+(GT_true_239)
+@SP
+A=M-1
+M=-1
+(GT_end_239)
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D|M
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_240
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_240
+0;JMP
+(LT_true_240)
+@SP
+A=M-1
+M=-1
+(LT_end_240)
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D|M
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@255
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_241
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_241
+0;JMP
+(GT_true_241)
+@SP
+A=M-1
+M=-1
+(GT_end_241)
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D|M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Screen.drawPixel$IF_TRUE0
+D;JNE
+@Screen.drawPixel$IF_FALSE0
+0;JMP
+(Screen.drawPixel$IF_TRUE0)
+@7
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Screen.Sys.error$ret.242
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Sys.error
+0;JMP
+(Screen.Sys.error$ret.242)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(Screen.drawPixel$IF_FALSE0)
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@32
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Screen.Math.multiply$ret.243
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.multiply
+0;JMP
+(Screen.Math.multiply$ret.243)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@16
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Screen.Math.divide$ret.244
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.divide
+0;JMP
+(Screen.Math.divide$ret.244)
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@15
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D&M
+@Screen.1
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Screen.2
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+@Screen.drawPixel$IF_TRUE1
+D;JNE
+@Screen.drawPixel$IF_FALSE1
+0;JMP
+(Screen.drawPixel$IF_TRUE1)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Screen.0
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Screen.0
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D|M
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Screen.drawPixel$IF_END1
+0;JMP
+(Screen.drawPixel$IF_FALSE1)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Screen.0
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Screen.0
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D&M
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Screen.drawPixel$IF_END1)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Screen.drawLine)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@2
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_245
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_245
+0;JMP
+(LT_true_245)
+@SP
+A=M-1
+M=-1
+(LT_end_245)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Screen.drawLine$IF_TRUE0
+D;JNE
+@Screen.drawLine$IF_FALSE0
+0;JMP
+(Screen.drawLine$IF_TRUE0)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@2
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@ARG
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@3
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@ARG
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@3
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@ARG
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@3
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3
+D=A
+@ARG
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Screen.drawLine$IF_FALSE0)
+@2
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@3
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@Screen.Math.abs$ret.246
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.abs
+0;JMP
+(Screen.Math.abs$ret.246)
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@3
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_247
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_247
+0;JMP
+(LT_true_247)
+@SP
+A=M-1
+M=-1
+(LT_end_247)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Screen.drawLine$IF_TRUE1
+D;JNE
+@Screen.drawLine$IF_FALSE1
+0;JMP
+(Screen.drawLine$IF_TRUE1)
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=-M
+@2
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Screen.drawLine$IF_FALSE1)
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_248
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_248
+0;JMP
+(EQ_true_248)
+@SP
+A=M-1
+M=-1
+(EQ_end_248)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Screen.drawLine$IF_TRUE2
+D;JNE
+@Screen.drawLine$IF_FALSE2
+0;JMP
+(Screen.drawLine$IF_TRUE2)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Screen.Screen.drawHorizontalLine$ret.249
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@8
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.drawHorizontalLine
+0;JMP
+(Screen.Screen.drawHorizontalLine$ret.249)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Screen.drawLine$IF_FALSE2)
+(Screen.drawLine$WHILE_EXP0)
+@3
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_250
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_250
+0;JMP
+(GT_true_250)
+@SP
+A=M-1
+M=-1
+(GT_end_250)
+@SP
+A=M-1
+M=!M
+@4
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_251
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_251
+0;JMP
+(GT_true_251)
+@SP
+A=M-1
+M=-1
+(GT_end_251)
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D&M
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Screen.drawLine$WHILE_END0
+D;JNE
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@4
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Screen.Math.multiply$ret.252
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.multiply
+0;JMP
+(Screen.Math.multiply$ret.252)
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@Screen.Screen.drawPixel$ret.253
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.drawPixel
+0;JMP
+(Screen.Screen.drawPixel$ret.253)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@5
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_254
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_254
+0;JMP
+(LT_true_254)
+@SP
+A=M-1
+M=-1
+(LT_end_254)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Screen.drawLine$IF_TRUE3
+D;JNE
+@Screen.drawLine$IF_FALSE3
+0;JMP
+(Screen.drawLine$IF_TRUE3)
+@3
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@3
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@5
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@5
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Screen.drawLine$IF_END3
+0;JMP
+(Screen.drawLine$IF_FALSE3)
+@4
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@4
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@5
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@5
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Screen.drawLine$IF_END3)
+@Screen.drawLine$WHILE_EXP0
+0;JMP
+(Screen.drawLine$WHILE_END0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Screen.drawHorizontalLine)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Screen.drawHorizontalLine$WHILE_EXP0)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_255
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_255
+0;JMP
+(GT_true_255)
+@SP
+A=M-1
+M=-1
+(GT_end_255)
+@SP
+A=M-1
+M=!M
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Screen.drawHorizontalLine$WHILE_END0
+D;JNE
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Screen.Screen.drawPixel$ret.256
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.drawPixel
+0;JMP
+(Screen.Screen.drawPixel$ret.256)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Screen.drawHorizontalLine$WHILE_EXP0
+0;JMP
+(Screen.drawHorizontalLine$WHILE_END0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Screen.drawRectangle)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_257
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_257
+0;JMP
+(GT_true_257)
+@SP
+A=M-1
+M=-1
+(GT_end_257)
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_258
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_258
+0;JMP
+(GT_true_258)
+@SP
+A=M-1
+M=-1
+(GT_end_258)
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D|M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Screen.drawRectangle$IF_TRUE0
+D;JNE
+@Screen.drawRectangle$IF_FALSE0
+0;JMP
+(Screen.drawRectangle$IF_TRUE0)
+@9
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Screen.Sys.error$ret.259
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Sys.error
+0;JMP
+(Screen.Sys.error$ret.259)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(Screen.drawRectangle$IF_FALSE0)
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Screen.drawRectangle$WHILE_EXP0)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_260
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_260
+0;JMP
+(GT_true_260)
+@SP
+A=M-1
+M=-1
+(GT_end_260)
+@SP
+A=M-1
+M=!M
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Screen.drawRectangle$WHILE_END0
+D;JNE
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Screen.Screen.drawHorizontalLine$ret.261
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@8
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.drawHorizontalLine
+0;JMP
+(Screen.Screen.drawHorizontalLine$ret.261)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Screen.drawRectangle$WHILE_EXP0
+0;JMP
+(Screen.drawRectangle$WHILE_END0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Screen.drawCircle)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@2
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_262
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_262
+0;JMP
+(LT_true_262)
+@SP
+A=M-1
+M=-1
+(LT_end_262)
+@2
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@181
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_263
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_263
+0;JMP
+(GT_true_263)
+@SP
+A=M-1
+M=-1
+(GT_end_263)
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D|M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Screen.drawCircle$IF_TRUE0
+D;JNE
+@Screen.drawCircle$IF_FALSE0
+0;JMP
+(Screen.drawCircle$IF_TRUE0)
+@13
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Screen.Sys.error$ret.264
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Sys.error
+0;JMP
+(Screen.Sys.error$ret.264)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(Screen.drawCircle$IF_FALSE0)
+@2
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Screen.Math.multiply$ret.265
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.multiply
+0;JMP
+(Screen.Math.multiply$ret.265)
+@2
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@2
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=-M
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Screen.drawCircle$WHILE_EXP0)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_266
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_266
+0;JMP
+(GT_true_266)
+@SP
+A=M-1
+M=-1
+(GT_end_266)
+@SP
+A=M-1
+M=!M
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Screen.drawCircle$WHILE_END0
+D;JNE
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Screen.Math.multiply$ret.267
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.multiply
+0;JMP
+(Screen.Math.multiply$ret.267)
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@Screen.Math.sqrt$ret.268
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.sqrt
+0;JMP
+(Screen.Math.sqrt$ret.268)
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@Screen.Screen.drawHorizontalLine$ret.269
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@8
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.drawHorizontalLine
+0;JMP
+(Screen.Screen.drawHorizontalLine$ret.269)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Screen.drawCircle$WHILE_EXP0
+0;JMP
+(Screen.drawCircle$WHILE_END0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(String.new)
+@3
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@String.Memory.alloc$ret.270
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Memory.alloc
+0;JMP
+(String.Memory.alloc$ret.270)
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_271
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_271
+0;JMP
+(LT_true_271)
+@SP
+A=M-1
+M=-1
+(LT_end_271)
+@SP
+AM=M-1
+D=M
+A=A-1
+@String.new$IF_TRUE0
+D;JNE
+@String.new$IF_FALSE0
+0;JMP
+(String.new$IF_TRUE0)
+@14
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@String.Sys.error$ret.272
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Sys.error
+0;JMP
+(String.Sys.error$ret.272)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(String.new$IF_FALSE0)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_273
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_273
+0;JMP
+(GT_true_273)
+@SP
+A=M-1
+M=-1
+(GT_end_273)
+@SP
+AM=M-1
+D=M
+A=A-1
+@String.new$IF_TRUE1
+D;JNE
+@String.new$IF_FALSE1
+0;JMP
+(String.new$IF_TRUE1)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@String.Array.new$ret.274
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Array.new
+0;JMP
+(String.Array.new$ret.274)
+@0
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(String.new$IF_FALSE1)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(String.dispose)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_275
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_275
+0;JMP
+(GT_true_275)
+@SP
+A=M-1
+M=-1
+(GT_end_275)
+@SP
+AM=M-1
+D=M
+A=A-1
+@String.dispose$IF_TRUE0
+D;JNE
+@String.dispose$IF_FALSE0
+0;JMP
+(String.dispose$IF_TRUE0)
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@String.Array.dispose$ret.276
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Array.dispose
+0;JMP
+(String.Array.dispose$ret.276)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(String.dispose$IF_FALSE0)
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@String.Memory.deAlloc$ret.277
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Memory.deAlloc
+0;JMP
+(String.Memory.deAlloc$ret.277)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(String.length)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(String.charAt)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_278
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_278
+0;JMP
+(LT_true_278)
+@SP
+A=M-1
+M=-1
+(LT_end_278)
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_279
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_279
+0;JMP
+(LT_true_279)
+@SP
+A=M-1
+M=-1
+(LT_end_279)
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D|M
+@SP
+AM=M-1
+D=M
+A=A-1
+@String.charAt$IF_TRUE0
+D;JNE
+@String.charAt$IF_FALSE0
+0;JMP
+(String.charAt$IF_TRUE0)
+@15
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@String.Sys.error$ret.280
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Sys.error
+0;JMP
+(String.Sys.error$ret.280)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(String.charAt$IF_FALSE0)
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(String.setCharAt)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_281
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_281
+0;JMP
+(LT_true_281)
+@SP
+A=M-1
+M=-1
+(LT_end_281)
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_282
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_282
+0;JMP
+(LT_true_282)
+@SP
+A=M-1
+M=-1
+(LT_end_282)
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D|M
+@SP
+AM=M-1
+D=M
+A=A-1
+@String.setCharAt$IF_TRUE0
+D;JNE
+@String.setCharAt$IF_FALSE0
+0;JMP
+(String.setCharAt$IF_TRUE0)
+@16
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@String.Sys.error$ret.283
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Sys.error
+0;JMP
+(String.Sys.error$ret.283)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(String.setCharAt$IF_FALSE0)
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@2
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(String.appendChar)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_284
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_284
+0;JMP
+(LT_true_284)
+@SP
+A=M-1
+M=-1
+(LT_end_284)
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@String.appendChar$IF_TRUE0
+D;JNE
+@String.appendChar$IF_FALSE0
+0;JMP
+(String.appendChar$IF_TRUE0)
+@17
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@String.Sys.error$ret.285
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Sys.error
+0;JMP
+(String.Sys.error$ret.285)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(String.appendChar$IF_FALSE0)
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(String.eraseLastChar)
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_286
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_286
+0;JMP
+(EQ_true_286)
+@SP
+A=M-1
+M=-1
+(EQ_end_286)
+@SP
+AM=M-1
+D=M
+A=A-1
+@String.eraseLastChar$IF_TRUE0
+D;JNE
+@String.eraseLastChar$IF_FALSE0
+0;JMP
+(String.eraseLastChar$IF_TRUE0)
+@18
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@String.Sys.error$ret.287
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Sys.error
+0;JMP
+(String.Sys.error$ret.287)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(String.eraseLastChar$IF_FALSE0)
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@1
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(String.intValue)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_288
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_288
+0;JMP
+(GT_true_288)
+@SP
+A=M-1
+M=-1
+(GT_end_288)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@45
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@EQ_true_289
+D;JEQ
+@SP
+A=M-1
+M=0
+@EQ_end_289
+0;JMP
+(EQ_true_289)
+@SP
+A=M-1
+M=-1
+(EQ_end_289)
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D&M
+@SP
+AM=M-1
+D=M
+A=A-1
+@String.intValue$IF_TRUE0
+D;JNE
+@String.intValue$IF_FALSE0
+0;JMP
+(String.intValue$IF_TRUE0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=!M
+@3
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(String.intValue$IF_FALSE0)
+(String.intValue$WHILE_EXP0)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_290
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_290
+0;JMP
+(LT_true_290)
+@SP
+A=M-1
+M=-1
+(LT_end_290)
+@4
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D&M
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@String.intValue$WHILE_END0
+D;JNE
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@48
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@2
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_291
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_291
+0;JMP
+(LT_true_291)
+@SP
+A=M-1
+M=-1
+(LT_end_291)
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@9
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_292
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_292
+0;JMP
+(GT_true_292)
+@SP
+A=M-1
+M=-1
+(GT_end_292)
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D|M
+@SP
+AM=M-1
+D=M
+A=A-1
+@String.intValue$IF_TRUE1
+D;JNE
+@String.intValue$IF_FALSE1
+0;JMP
+(String.intValue$IF_TRUE1)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=!M
+@4
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@String.intValue$IF_END1
+0;JMP
+(String.intValue$IF_FALSE1)
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@10
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@String.Math.multiply$ret.293
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.multiply
+0;JMP
+(String.Math.multiply$ret.293)
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(String.intValue$IF_END1)
+@String.intValue$WHILE_EXP0
+0;JMP
+(String.intValue$WHILE_END0)
+@3
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+@String.intValue$IF_TRUE2
+D;JNE
+@String.intValue$IF_FALSE2
+0;JMP
+(String.intValue$IF_TRUE2)
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=-M
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(String.intValue$IF_FALSE2)
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(String.setInt)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@THIS
+M=D
+@5
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@String.Array.new$ret.294
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Array.new
+0;JMP
+(String.Array.new$ret.294)
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@String.Math.abs$ret.295
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.abs
+0;JMP
+(String.Math.abs$ret.295)
+@SP
+A=M-1
+M=-M
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_296
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_296
+0;JMP
+(LT_true_296)
+@SP
+A=M-1
+M=-1
+(LT_end_296)
+@SP
+AM=M-1
+D=M
+A=A-1
+@String.setInt$IF_TRUE0
+D;JNE
+@String.setInt$IF_FALSE0
+0;JMP
+(String.setInt$IF_TRUE0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(String.setInt$IF_FALSE0)
+(String.setInt$WHILE_EXP0)
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_297
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_297
+0;JMP
+(LT_true_297)
+@SP
+A=M-1
+M=-1
+(LT_end_297)
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@String.setInt$WHILE_END0
+D;JNE
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@10
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@String.Math.divide$ret.298
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.divide
+0;JMP
+(String.Math.divide$ret.298)
+@2
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@3
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@10
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@String.Math.multiply$ret.299
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.multiply
+0;JMP
+(String.Math.multiply$ret.299)
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@3
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@3
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@2
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@String.setInt$WHILE_EXP0
+0;JMP
+(String.setInt$WHILE_END0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_300
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_300
+0;JMP
+(LT_true_300)
+@SP
+A=M-1
+M=-1
+(LT_end_300)
+@SP
+AM=M-1
+D=M
+A=A-1
+@String.setInt$IF_TRUE1
+D;JNE
+@String.setInt$IF_FALSE1
+0;JMP
+(String.setInt$IF_TRUE1)
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(String.setInt$IF_FALSE1)
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@3
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@2
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_301
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_301
+0;JMP
+(GT_true_301)
+@SP
+A=M-1
+M=-1
+(GT_end_301)
+@SP
+AM=M-1
+D=M
+A=A-1
+@String.setInt$IF_TRUE2
+D;JNE
+@String.setInt$IF_FALSE2
+0;JMP
+(String.setInt$IF_TRUE2)
+@19
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@String.Sys.error$ret.302
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Sys.error
+0;JMP
+(String.Sys.error$ret.302)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(String.setInt$IF_FALSE2)
+@1
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_303
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_303
+0;JMP
+(LT_true_303)
+@SP
+A=M-1
+M=-1
+(LT_end_303)
+@SP
+AM=M-1
+D=M
+A=A-1
+@String.setInt$IF_TRUE3
+D;JNE
+@String.setInt$IF_FALSE3
+0;JMP
+(String.setInt$IF_TRUE3)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@45
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(String.setInt$IF_FALSE3)
+(String.setInt$WHILE_EXP1)
+@3
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@GT_true_304
+D;JGT
+@SP
+A=M-1
+M=0
+@GT_end_304
+0;JMP
+(GT_true_304)
+@SP
+A=M-1
+M=-1
+(GT_end_304)
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@String.setInt$WHILE_END1
+D;JNE
+@3
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=M-D
+@3
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@3
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@0
+D=A
+@THAT
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@48
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@SP
+AM=M-1
+D=M
+@THAT
+M=D
+@5
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@THAT
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@1
+D=A
+@THIS
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@THIS
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@String.setInt$WHILE_EXP1
+0;JMP
+(String.setInt$WHILE_END1)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@String.Array.dispose$ret.305
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Array.dispose
+0;JMP
+(String.Array.dispose$ret.305)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(String.newLine)
+@128
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(String.backSpace)
+@129
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(String.doubleQuote)
+@34
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Sys.init)
+@Sys.Memory.init$ret.306
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@5
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Memory.init
+0;JMP
+(Sys.Memory.init$ret.306)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@Sys.Math.init$ret.307
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@5
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Math.init
+0;JMP
+(Sys.Math.init$ret.307)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@Sys.Output.init$ret.308
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@5
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.init
+0;JMP
+(Sys.Output.init$ret.308)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@Sys.Screen.init$ret.309
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@5
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Screen.init
+0;JMP
+(Sys.Screen.init$ret.309)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@Sys.Keyboard.init$ret.310
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@5
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Keyboard.init
+0;JMP
+(Sys.Keyboard.init$ret.310)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@Sys.Main.main$ret.311
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@5
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Main.main
+0;JMP
+(Sys.Main.main$ret.311)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@Sys.Sys.halt$ret.312
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@5
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Sys.halt
+0;JMP
+(Sys.Sys.halt$ret.312)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Sys.halt)
+(Sys.halt$WHILE_EXP0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+A=M-1
+M=!M
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Sys.halt$WHILE_END0
+D;JNE
+@Sys.halt$WHILE_EXP0
+0;JMP
+(Sys.halt$WHILE_END0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Sys.wait)
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@SP
+A=M
+M=0
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_313
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_313
+0;JMP
+(LT_true_313)
+@SP
+A=M-1
+M=-1
+(LT_end_313)
+@SP
+AM=M-1
+D=M
+A=A-1
+@Sys.wait$IF_TRUE0
+D;JNE
+@Sys.wait$IF_FALSE0
+0;JMP
+(Sys.wait$IF_TRUE0)
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Sys.Sys.error$ret.314
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Sys.error
+0;JMP
+(Sys.Sys.error$ret.314)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+(Sys.wait$IF_FALSE0)
+(Sys.wait$WHILE_EXP0)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_315
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_315
+0;JMP
+(LT_true_315)
+@SP
+A=M-1
+M=-1
+(LT_end_315)
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Sys.wait$WHILE_END0
+D;JNE
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+(Sys.wait$WHILE_EXP1)
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@100
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+D=M-D
+@LT_true_316
+D;JLT
+@SP
+A=M-1
+M=0
+@LT_end_316
+0;JMP
+(LT_true_316)
+@SP
+A=M-1
+M=-1
+(LT_end_316)
+@SP
+A=M-1
+M=!M
+@SP
+AM=M-1
+D=M
+A=A-1
+@Sys.wait$WHILE_END1
+D;JNE
+@1
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@1
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Sys.wait$WHILE_EXP1
+0;JMP
+(Sys.wait$WHILE_END1)
+@0
+D=A
+@LCL
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@1
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+AM=M-1
+D=M
+A=A-1
+M=D+M
+@0
+D=A
+@LCL
+D=D+M
+@R13
+M=D
+@SP
+AM=M-1
+D=M
+@R13
+A=M
+M=D
+@Sys.wait$WHILE_EXP0
+0;JMP
+(Sys.wait$WHILE_END0)
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
+(Sys.error)
+@3
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Sys.String.new$ret.317
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.new
+0;JMP
+(Sys.String.new$ret.317)
+@69
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Sys.String.appendChar$ret.318
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.appendChar
+0;JMP
+(Sys.String.appendChar$ret.318)
+@82
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Sys.String.appendChar$ret.319
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.appendChar
+0;JMP
+(Sys.String.appendChar$ret.319)
+@82
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Sys.String.appendChar$ret.320
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@7
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@String.appendChar
+0;JMP
+(Sys.String.appendChar$ret.320)
+@Sys.Output.printString$ret.321
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.printString
+0;JMP
+(Sys.Output.printString$ret.321)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@ARG
+A=D+M
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@Sys.Output.printInt$ret.322
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@6
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Output.printInt
+0;JMP
+(Sys.Output.printInt$ret.322)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@Sys.Sys.halt$ret.323
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@ARG
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THIS
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@THAT
+D=M
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@SP
+D=M
+@5
+D=D-A
+@ARG
+M=D
+@SP
+D=M
+@LCL
+M=D
+@Sys.halt
+0;JMP
+(Sys.Sys.halt$ret.323)
+@SP
+AM=M-1
+D=M
+@5
+M=D
+@0
+D=A
+@SP
+A=M
+M=D
+@SP
+M=M+1
+@LCL
+D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
+@SP
+AM=M-1
+D=M
+@ARG
+A=M
+M=D
+@ARG
+D=M+1
+@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@ARG
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@LCL
+M=D
+@R14
+A=M
+0;JMP
@@ -1,55 +1,3 @@
-@256
-D=A
-@SP
-M=D
-@BasicLoop.Sys.init$ret.1
-D=A
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@LCL
-D=M
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@ARG
-D=M
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@THIS
-D=M
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@THAT
-D=M
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@SP
-D=M
-@5
-D=D-A
-@ARG
-M=D
-@SP
-D=M
-@LCL
-M=D
-@Sys.init
-0;JMP
-(BasicLoop.Sys.init$ret.1)
 @0
 D=A
 @SP
@@ -69,7 +17,7 @@ D=M
 @R13
 A=M
 M=D
-($LOOP)
+(BasicLoop.vm$LOOP)
 @0
 D=A
 @ARG
@@ -155,7 +103,7 @@ M=M+1
 AM=M-1
 D=M
 A=A-1
-@$LOOP
+@BasicLoop.vm$LOOP
 D;JNE
 @0
 D=A
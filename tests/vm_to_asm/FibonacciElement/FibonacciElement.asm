@@ -2,7 +2,7 @@
 D=A
 @SP
 M=D
-@Main.Sys.init$ret.1
+@Bootstrap.Sys.init$ret.1
 D=A
 @SP
 A=M
@@ -49,7 +49,7 @@ D=M
 M=D
 @Sys.init
 0;JMP
-(Main.Sys.init$ret.1)
+(Bootstrap.Sys.init$ret.1)
 (Main.fibonacci)
 @0
 D=A
@@ -73,27 +73,27 @@ AM=M-1
 D=M
 A=A-1
 D=M-D
-@LT_true_1
+@LT_true_2
 D;JLT
 @SP
 A=M-1
 M=0
-@LT_end_1
+@LT_end_2
 0;JMP
-(LT_true_1)
+(LT_true_2)
 @SP
 A=M-1
 M=-1
-(LT_end_1)
+(LT_end_2)
 @SP
 AM=M-1
 D=M
 A=A-1
-@$N_LT_2
+@Main.fibonacci$N_LT_2
 D;JNE
-@$N_GE_2
+@Main.fibonacci$N_GE_2
 0;JMP
-($N_LT_2)
+(Main.fibonacci$N_LT_2)
 @0
 D=A
 @ARG
@@ -150,7 +150,7 @@ M=D
 @R14
 A=M
 0;JMP
-($N_GE_2)
+(Main.fibonacci$N_GE_2)
 @0
 D=A
 @ARG
@@ -173,7 +173,7 @@ AM=M-1
 D=M
 A=A-1
 M=M-D
-@Main.Main.fibonacci$ret.2
+@Main.Main.fibonacci$ret.3
 D=A
 @SP
 A=M
@@ -220,7 +220,7 @@ D=M
 M=D
 @Main.fibonacci
 0;JMP
-(Main.Main.fibonacci$ret.2)
+(Main.Main.fibonacci$ret.3)
 @0
 D=A
 @ARG
@@ -243,7 +243,7 @@ AM=M-1
 D=M
 A=A-1
 M=M-D
-@Main.Main.fibonacci$ret.3
+@Main.Main.fibonacci$ret.4
 D=A
 @SP
 A=M
@@ -290,7 +290,7 @@ D=M
 M=D
 @Main.fibonacci
 0;JMP
-(Main.Main.fibonacci$ret.3)
+(Main.Main.fibonacci$ret.4)
 @SP
 AM=M-1
 D=M
@@ -342,58 +342,6 @@ M=D
 @R14
 A=M
 0;JMP
-@256
-D=A
-@SP
-M=D
-@Sys.Sys.init$ret.1
-D=A
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@LCL
-D=M
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@ARG
-D=M
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@THIS
-D=M
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@THAT
-D=M
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@SP
-D=M
-@5
-D=D-A
-@ARG
-M=D
-@SP
-D=M
-@LCL
-M=D
-@Sys.init
-0;JMP
-(Sys.Sys.init$ret.1)
 (Sys.init)
 @4
 D=A
@@ -402,7 +350,7 @@ A=M
 M=D
 @SP
 M=M+1
-@Sys.Main.fibonacci$ret.1
+@Sys.Main.fibonacci$ret.5
 D=A
 @SP
 A=M
@@ -449,7 +397,7 @@ D=M
 M=D
 @Main.fibonacci
 0;JMP
-(Sys.Main.fibonacci$ret.1)
-($END)
-@$END
+(Sys.Main.fibonacci$ret.5)
+(Sys.init$END)
+@Sys.init$END
 0;JMP
@@ -1,55 +1,3 @@
-@256
-D=A
-@SP
-M=D
-@FibonacciSeries.Sys.init$ret.1
-D=A
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@LCL
-D=M
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@ARG
-D=M
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@THIS
-D=M
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@THAT
-D=M
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@SP
-D=M
-@5
-D=D-A
-@ARG
-M=D
-@SP
-D=M
-@LCL
-M=D
-@Sys.init
-0;JMP
-(FibonacciSeries.Sys.init$ret.1)
 @1
 D=A
 @ARG
@@ -137,7 +85,7 @@ D=M
 @R13
 A=M
 M=D
-($LOOP)
+(FibonacciSeries.vm$LOOP)
 @0
 D=A
 @ARG
@@ -152,11 +100,11 @@ M=M+1
 AM=M-1
 D=M
 A=A-1
-@$COMPUTE_ELEMENT
+@FibonacciSeries.vm$COMPUTE_ELEMENT
 D;JNE
-@$END
+@FibonacciSeries.vm$END
 0;JMP
-($COMPUTE_ELEMENT)
+(FibonacciSeries.vm$COMPUTE_ELEMENT)
 @0
 D=A
 @THAT
@@ -252,6 +200,6 @@ D=M
 @R13
 A=M
 M=D
-@$LOOP
+@FibonacciSeries.vm$LOOP
 0;JMP
-($END)
+(FibonacciSeries.vm$END)
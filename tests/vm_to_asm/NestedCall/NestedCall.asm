@@ -2,7 +2,7 @@
 D=A
 @SP
 M=D
-@Sys.Sys.init$ret.1
+@Bootstrap.Sys.init$ret.1
 D=A
 @SP
 A=M
@@ -49,7 +49,7 @@ D=M
 M=D
 @Sys.init
 0;JMP
-(Sys.Sys.init$ret.1)
+(Bootstrap.Sys.init$ret.1)
 (Sys.init)
 @4000
 D=A
@@ -75,7 +75,7 @@ AM=M-1
 D=M
 @THAT
 M=D
-@Sys.Sys.main$ret.1
+@Sys.Sys.main$ret.2
 D=A
 @SP
 A=M
@@ -122,14 +122,14 @@ D=M
 M=D
 @Sys.main
 0;JMP
-(Sys.Sys.main$ret.1)
+(Sys.Sys.main$ret.2)
 @SP
 AM=M-1
 D=M
 @6
 M=D
-($LOOP)
-@$LOOP
+(Sys.init$LOOP)
+@Sys.init$LOOP
 0;JMP
 (Sys.main)
 @SP
@@ -245,7 +245,7 @@ A=M
 M=D
 @SP
 M=M+1
-@Sys.Sys.add12$ret.1
+@Sys.Sys.add12$ret.3
 D=A
 @SP
 A=M
@@ -292,7 +292,7 @@ D=M
 M=D
 @Sys.add12
 0;JMP
-(Sys.Sys.add12$ret.1)
+(Sys.Sys.add12$ret.3)
 @SP
 AM=M-1
 D=M
@@ -1,55 +1,3 @@
-@256
-D=A
-@SP
-M=D
-@StackTest.Sys.init$ret.1
-D=A
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@LCL
-D=M
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@ARG
-D=M
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@THIS
-D=M
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@THAT
-D=M
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@SP
-D=M
-@5
-D=D-A
-@ARG
-M=D
-@SP
-D=M
-@LCL
-M=D
-@Sys.init
-0;JMP
-(StackTest.Sys.init$ret.1)
 @17
 D=A
 @SP
@@ -69,18 +17,18 @@ AM=M-1
 D=M
 A=A-1
 D=M-D
-@EQ_true_2
+@EQ_true_1
 D;JEQ
 @SP
 A=M-1
 M=0
-@EQ_end_2
+@EQ_end_1
 0;JMP
-(EQ_true_2)
+(EQ_true_1)
 @SP
 A=M-1
 M=-1
-(EQ_end_2)
+(EQ_end_1)
 @17
 D=A
 @SP
@@ -100,18 +48,18 @@ AM=M-1
 D=M
 A=A-1
 D=M-D
-@EQ_true_3
+@EQ_true_2
 D;JEQ
 @SP
 A=M-1
 M=0
-@EQ_end_3
+@EQ_end_2
 0;JMP
-(EQ_true_3)
+(EQ_true_2)
 @SP
 A=M-1
 M=-1
-(EQ_end_3)
+(EQ_end_2)
 @16
 D=A
 @SP
@@ -131,18 +79,18 @@ AM=M-1
 D=M
 A=A-1
 D=M-D
-@EQ_true_4
+@EQ_true_3
 D;JEQ
 @SP
 A=M-1
 M=0
-@EQ_end_4
+@EQ_end_3
 0;JMP
-(EQ_true_4)
+(EQ_true_3)
 @SP
 A=M-1
 M=-1
-(EQ_end_4)
+(EQ_end_3)
 @892
 D=A
 @SP
@@ -162,18 +110,18 @@ AM=M-1
 D=M
 A=A-1
 D=M-D
-@LT_true_5
+@LT_true_4
 D;JLT
 @SP
 A=M-1
 M=0
-@LT_end_5
+@LT_end_4
 0;JMP
-(LT_true_5)
+(LT_true_4)
 @SP
 A=M-1
 M=-1
-(LT_end_5)
+(LT_end_4)
 @891
 D=A
 @SP
@@ -193,18 +141,18 @@ AM=M-1
 D=M
 A=A-1
 D=M-D
-@LT_true_6
+@LT_true_5
 D;JLT
 @SP
 A=M-1
 M=0
-@LT_end_6
+@LT_end_5
 0;JMP
-(LT_true_6)
+(LT_true_5)
 @SP
 A=M-1
 M=-1
-(LT_end_6)
+(LT_end_5)
 @891
 D=A
 @SP
@@ -224,18 +172,18 @@ AM=M-1
 D=M
 A=A-1
 D=M-D
-@LT_true_7
+@LT_true_6
 D;JLT
 @SP
 A=M-1
 M=0
-@LT_end_7
+@LT_end_6
 0;JMP
-(LT_true_7)
+(LT_true_6)
 @SP
 A=M-1
 M=-1
-(LT_end_7)
+(LT_end_6)
 @32767
 D=A
 @SP
@@ -255,18 +203,18 @@ AM=M-1
 D=M
 A=A-1
 D=M-D
-@GT_true_8
+@GT_true_7
 D;JGT
 @SP
 A=M-1
 M=0
-@GT_end_8
+@GT_end_7
 0;JMP
-(GT_true_8)
+(GT_true_7)
 @SP
 A=M-1
 M=-1
-(GT_end_8)
+(GT_end_7)
 @32766
 D=A
 @SP
@@ -286,18 +234,18 @@ AM=M-1
 D=M
 A=A-1
 D=M-D
-@GT_true_9
+@GT_true_8
 D;JGT
 @SP
 A=M-1
 M=0
-@GT_end_9
+@GT_end_8
 0;JMP
-(GT_true_9)
+(GT_true_8)
 @SP
 A=M-1
 M=-1
-(GT_end_9)
+(GT_end_8)
 @32766
 D=A
 @SP
@@ -317,18 +265,18 @@ AM=M-1
 D=M
 A=A-1
 D=M-D
-@GT_true_10
+@GT_true_9
 D;JGT
 @SP
 A=M-1
 M=0
-@GT_end_10
+@GT_end_9
 0;JMP
-(GT_true_10)
+(GT_true_9)
 @SP
 A=M-1
 M=-1
-(GT_end_10)
+(GT_end_9)
 @57
 D=A
 @SP
@@ -2,7 +2,7 @@
 D=A
 @SP
 M=D
-@Class1.Sys.init$ret.1
+@Bootstrap.Sys.init$ret.1
 D=A
 @SP
 A=M
@@ -49,7 +49,7 @@ D=M
 M=D
 @Sys.init
 0;JMP
-(Class1.Sys.init$ret.1)
+(Bootstrap.Sys.init$ret.1)
 (Class1.set)
 @0
 D=A
@@ -200,141 +200,172 @@ M=D
 @R14
 A=M
 0;JMP
-@256
-D=A
-@SP
-M=D
-@Sys.Sys.init$ret.1
+(Class2.set)
+@0
 D=A
+@ARG
+A=D+M
+D=M
 @SP
 A=M
 M=D
 @SP
 M=M+1
-@LCL
-D=M
 @SP
-A=M
+AM=M-1
+D=M
+@Class2.0
 M=D
-@SP
-M=M+1
+@1
+D=A
 @ARG
+A=D+M
 D=M
 @SP
 A=M
 M=D
 @SP
 M=M+1
-@THIS
+@SP
+AM=M-1
 D=M
+@Class2.1
+M=D
+@0
+D=A
 @SP
 A=M
 M=D
 @SP
 M=M+1
-@THAT
+@LCL
 D=M
+@R13
+M=D
+@5
+A=D-A
+D=M
+@R14
+M=D
 @SP
+AM=M-1
+D=M
+@ARG
 A=M
 M=D
+@ARG
+D=M+1
 @SP
-M=M+1
-@SP
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THAT
+M=D
+@R13
+D=M-1
+AM=D
+D=M
+@THIS
+M=D
+@R13
+D=M-1
+AM=D
 D=M
-@5
-D=D-A
 @ARG
 M=D
-@SP
+@R13
+D=M-1
+AM=D
 D=M
 @LCL
 M=D
-@Sys.init
+@R14
+A=M
 0;JMP
-(Sys.Sys.init$ret.1)
-(Sys.init)
-@6
-D=A
+(Class2.get)
+@Class2.0
+D=M
 @SP
 A=M
 M=D
 @SP
 M=M+1
-@8
-D=A
+@Class2.1
+D=M
 @SP
 A=M
 M=D
 @SP
 M=M+1
-@Sys.Class1.set$ret.1
-D=A
 @SP
-A=M
-M=D
-@SP
-M=M+1
+AM=M-1
+D=M
+A=A-1
+M=M-D
 @LCL
 D=M
-@SP
-A=M
+@R13
 M=D
-@SP
-M=M+1
-@ARG
+@5
+A=D-A
 D=M
-@SP
-A=M
+@R14
 M=D
 @SP
-M=M+1
-@THIS
+AM=M-1
 D=M
-@SP
+@ARG
 A=M
 M=D
+@ARG
+D=M+1
 @SP
-M=M+1
+M=D
+@R13
+D=M-1
+AM=D
+D=M
 @THAT
+M=D
+@R13
+D=M-1
+AM=D
 D=M
-@SP
-A=M
+@THIS
 M=D
-@SP
-M=M+1
-@SP
+@R13
+D=M-1
+AM=D
 D=M
-@7
-D=D-A
 @ARG
 M=D
-@SP
+@R13
+D=M-1
+AM=D
 D=M
 @LCL
 M=D
-@Class1.set
+@R14
+A=M
 0;JMP
-(Sys.Class1.set$ret.1)
-@SP
-AM=M-1
-D=M
-@5
-M=D
-@23
+(Sys.init)
+@6
 D=A
 @SP
 A=M
 M=D
 @SP
 M=M+1
-@15
+@8
 D=A
 @SP
 A=M
 M=D
 @SP
 M=M+1
-@Sys.Class2.set$ret.2
+@Sys.Class1.set$ret.2
 D=A
 @SP
 A=M
@@ -379,63 +410,29 @@ M=D
 D=M
 @LCL
 M=D
-@Class2.set
+@Class1.set
 0;JMP
-(Sys.Class2.set$ret.2)
+(Sys.Class1.set$ret.2)
 @SP
 AM=M-1
 D=M
 @5
 M=D
-@Sys.Class1.get$ret.3
+@23
 D=A
 @SP
 A=M
 M=D
 @SP
 M=M+1
-@LCL
-D=M
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@ARG
-D=M
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@THIS
-D=M
-@SP
-A=M
-M=D
-@SP
-M=M+1
-@THAT
-D=M
+@15
+D=A
 @SP
 A=M
 M=D
 @SP
 M=M+1
-@SP
-D=M
-@5
-D=D-A
-@ARG
-M=D
-@SP
-D=M
-@LCL
-M=D
-@Class1.get
-0;JMP
-(Sys.Class1.get$ret.3)
-@Sys.Class2.get$ret.4
+@Sys.Class2.set$ret.3
 D=A
 @SP
 A=M
@@ -472,7 +469,7 @@ M=D
 M=M+1
 @SP
 D=M
-@5
+@7
 D=D-A
 @ARG
 M=D
@@ -480,17 +477,15 @@ M=D
 D=M
 @LCL
 M=D
-@Class2.get
-0;JMP
-(Sys.Class2.get$ret.4)
-($END)
-@$END
+@Class2.set
 0;JMP
-@256
-D=A
+(Sys.Class2.set$ret.3)
 @SP
+AM=M-1
+D=M
+@5
 M=D
-@Class2.Sys.init$ret.1
+@Sys.Class1.get$ret.4
 D=A
 @SP
 A=M
@@ -535,102 +530,38 @@ M=D
 D=M
 @LCL
 M=D
-@Sys.init
+@Class1.get
 0;JMP
-(Class2.Sys.init$ret.1)
-(Class2.set)
-@0
+(Sys.Class1.get$ret.4)
+@Sys.Class2.get$ret.5
 D=A
-@ARG
-A=D+M
-D=M
 @SP
 A=M
 M=D
 @SP
 M=M+1
-@SP
-AM=M-1
-D=M
-@Class2.0
-M=D
-@1
-D=A
-@ARG
-A=D+M
+@LCL
 D=M
 @SP
 A=M
 M=D
 @SP
 M=M+1
-@SP
-AM=M-1
+@ARG
 D=M
-@Class2.1
-M=D
-@0
-D=A
 @SP
 A=M
 M=D
 @SP
 M=M+1
-@LCL
-D=M
-@R13
-M=D
-@5
-A=D-A
-D=M
-@R14
-M=D
-@SP
-AM=M-1
-D=M
-@ARG
-A=M
-M=D
-@ARG
-D=M+1
-@SP
-M=D
-@R13
-D=M-1
-AM=D
-D=M
-@THAT
-M=D
-@R13
-D=M-1
-AM=D
-D=M
 @THIS
-M=D
-@R13
-D=M-1
-AM=D
-D=M
-@ARG
-M=D
-@R13
-D=M-1
-AM=D
-D=M
-@LCL
-M=D
-@R14
-A=M
-0;JMP
-(Class2.get)
-@Class2.0
 D=M
 @SP
 A=M
 M=D
 @SP
 M=M+1
-@Class2.1
+@THAT
 D=M
 @SP
 A=M
@@ -638,53 +569,18 @@ M=D
 @SP
 M=M+1
 @SP
-AM=M-1
 D=M
-A=A-1
-M=M-D
-@LCL
-D=M
-@R13
-M=D
 @5
-A=D-A
-D=M
-@R14
-M=D
-@SP
-AM=M-1
-D=M
+D=D-A
 @ARG
-A=M
 M=D
-@ARG
-D=M+1
 @SP
-M=D
-@R13
-D=M-1
-AM=D
-D=M
-@THAT
-M=D
-@R13
-D=M-1
-AM=D
-D=M
-@THIS
-M=D
-@R13
-D=M-1
-AM=D
-D=M
-@ARG
-M=D
-@R13
-D=M-1
-AM=D
 D=M
 @LCL
 M=D
-@R14
-A=M
+@Class2.get
+0;JMP
+(Sys.Class2.get$ret.5)
+(Sys.init$END)
+@Sys.init$END
 0;JMP